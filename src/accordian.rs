@@ -3,6 +3,8 @@
 
 use dioxus::prelude::*;
 
+use crate::context::{try_context, try_provide_context};
+
 #[derive(Props, Clone, PartialEq)]
 pub struct AccordianProps {
     name: String,
@@ -11,24 +13,196 @@ pub struct AccordianProps {
     children: Element,
 }
 
+/// A single collapsible item, kept for code written before `Accordion`.
+#[deprecated(note = "use `AccordionItem`, grouped in an `Accordion`")]
 #[component]
 pub fn Accordian(props: AccordianProps) -> Element {
+    rsx!(
+        AccordionItem {
+            value: props.title.clone(),
+            title: props.title,
+            name: props.name,
+            checked: props.checked,
+            {props.children}
+        }
+    )
+}
+
+/// Shared by an `Accordion` with its items
+#[derive(Clone)]
+struct AccordionContext {
+    name: String,
+    open: Signal<Option<String>>,
+    onchange: Option<EventHandler<Option<String>>>,
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct AccordionProps {
+    /// Radio group name shared by the items
+    name: String,
+    /// The `AccordionItem`s
+    children: Element,
+    /// Key of the item that starts open
+    open: Option<String>,
+    /// Called with the key of the open item, or `None` once all are closed
+    onchange: Option<EventHandler<Option<String>>>,
+    /// Optional ID for the accordion element
+    id: Option<String>,
+    /// Additional CSS classes to apply to the accordion
+    class: Option<String>,
+}
+
+/// A group of `AccordionItem`s where at most one is open, reporting which
+/// one through `onchange`.
+///
+/// ```text
+/// Accordion {
+///     name: "faq",
+///     onchange: move |key: Option<String>| track_open_question(key),
+///     AccordionItem { value: "shipping", title: "Shipping", "Ships in 2 days" }
+///     AccordionItem { value: "returns", title: "Returns", "30 day returns" }
+/// }
+/// ```
+#[component]
+pub fn Accordion(props: AccordionProps) -> Element {
+    let initial = props.open.clone();
+    let open = use_signal(|| initial);
+
+    // Provided on every render so a changed handler reaches the items
+    try_provide_context(AccordionContext {
+        name: props.name,
+        open,
+        onchange: props.onchange,
+    });
+
+    let mut classes = vec!["join join-vertical w-full".to_string()];
+    if let Some(class) = props.class.filter(|class| !class.is_empty()) {
+        classes.push(class);
+    }
+    let class_string = classes.join(" ");
+
     rsx!(
         div {
-            class: "collapse collapse-arrow bg-base-200",
+            class: "{class_string}",
+            id: props.id,
+            {props.children}
+        }
+    )
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct AccordionItemProps {
+    /// Key reported by the parent's `onchange` while this item is open. Named
+    /// `value` because `key` is reserved by `rsx!`
+    value: String,
+    /// Text shown in the item's header
+    title: String,
+    children: Element,
+    /// Additional CSS classes to apply to the item
+    class: Option<String>,
+    /// Radio group name for an item used outside an `Accordion`
+    name: Option<String>,
+    /// Whether an item used outside an `Accordion` starts open
+    checked: Option<bool>,
+}
+
+#[component]
+pub fn AccordionItem(props: AccordionItemProps) -> Element {
+    let context = try_context::<AccordionContext>();
+    let name = match &context {
+        Some(context) => Some(context.name.clone()),
+        None => props.name,
+    };
+    let is_open = match &context {
+        Some(context) => context.open.read().as_deref() == Some(props.value.as_str()),
+        None => props.checked.unwrap_or(false),
+    };
+
+    let mut classes = vec!["collapse collapse-arrow join-item bg-base-200".to_string()];
+    if let Some(class) = props.class.filter(|class| !class.is_empty()) {
+        classes.push(class);
+    }
+    let class_string = classes.join(" ");
+
+    // Clicking the open item again closes it, which a radio can't do alone
+    let mut listeners = vec![];
+    if let Some(AccordionContext { mut open, onchange, .. }) = context {
+        let value = props.value.clone();
+        listeners.push(dioxus_elements::events::onclick(move |_| {
+            let next = if is_open { None } else { Some(value.clone()) };
+            open.set(next.clone());
+            if let Some(handler) = onchange {
+                handler.call(next);
+            }
+        }));
+    }
+
+    rsx!(
+        div {
+            class: "{class_string}",
             input {
-                checked: props.checked,
                 "type": "radio",
-                name: props.name
+                name,
+                value: "{props.value}",
+                checked: is_open,
+                ..listeners,
             }
             div {
                 class: "collapse-title text-md font-medium",
                 "{props.title}"
             }
             div {
-                class: "collapse-content  bg-base-200",
-                {{props.children}}
+                class: "collapse-content",
+                {props.children}
             }
         }
     )
 }
+
+#[test]
+fn test_accordion_reports_open_item() {
+    use crate::test_utils::{fire, listener, rebuild, TestMouseData};
+
+    fn app() -> Element {
+        let mut reported = use_signal(|| None::<String>);
+        rsx!(
+            Accordion {
+                name: "faq",
+                onchange: move |key| reported.set(key),
+                AccordionItem { value: "shipping", title: "Shipping", "Ships in 2 days" }
+                AccordionItem { value: "returns", title: "Returns", "30 day returns" }
+            }
+            span { "Open: {reported().as_deref().unwrap_or(\"none\")}" }
+        )
+    }
+
+    let (mut dom, listeners) = rebuild(app);
+    let shipping = listener(&listeners, "click", 0);
+    let returns = listener(&listeners, "click", 1);
+    assert!(!dioxus_ssr::render(&dom).contains("checked=true"));
+
+    fire(&mut dom, "click", shipping, TestMouseData::default());
+    let result = dioxus_ssr::render(&dom);
+    assert!(result.contains("Open: shipping<"));
+    assert!(result.contains(r#"value="shipping" checked=true"#));
+
+    fire(&mut dom, "click", returns, TestMouseData::default());
+    let result = dioxus_ssr::render(&dom);
+    assert!(result.contains("Open: returns<"));
+    assert!(!result.contains(r#"value="shipping" checked=true"#));
+
+    fire(&mut dom, "click", returns, TestMouseData::default());
+    assert!(dioxus_ssr::render(&dom).contains("Open: none<"));
+}
+
+#[test]
+#[allow(deprecated)]
+fn test_accordian_forwards_to_accordion_item() {
+    let result = dioxus_ssr::render_element(rsx!(
+        Accordian { name: "faq", title: "Shipping", checked: true, "Ships in 2 days" }
+    ));
+    assert_eq!(
+        result,
+        r#"<div class="collapse collapse-arrow join-item bg-base-200"><input type="radio" name="faq" value="Shipping" checked=true/><div class="collapse-title text-md font-medium">Shipping</div><div class="collapse-content">Ships in 2 days</div></div>"#
+    );
+}
@@ -1,7 +1,28 @@
 #![allow(non_snake_case)]
 use dioxus::prelude::*;
 
+/// An Alert component for inline messages, with an optional icon and close
+/// button. `Toast` positions alerts at the edge of the screen.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```text
+/// use daisy_rsx::{Alert, AlertColor};
+///
+/// Alert {
+///     alert_color: AlertColor::Warn,
+///     show_icon: true,
+///     dismissible: true,
+///     onclose: move |_| show_alert.set(false),
+///     span { "Your trial ends tomorrow" }
+/// }
+/// ```
+
 #[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
 pub enum AlertColor {
     #[default]
     Default,
@@ -21,6 +42,16 @@ impl AlertColor {
             AlertColor::Success => "alert alert-success",
         }
     }
+
+    /// Outline icon path for the type, drawn in a 24x24 box
+    fn icon_path(&self) -> &'static str {
+        match self {
+            AlertColor::Default | AlertColor::Info => "M13 16h-1v-4h-1m1-4h.01M21 12a9 9 0 11-18 0 9 9 0 0118 0z",
+            AlertColor::Warn => "M12 9v2m0 4h.01m-6.938 4h13.856c1.54 0 2.502-1.667 1.732-3L13.732 4c-.77-1.333-2.694-1.333-3.464 0L3.34 16c-.77 1.333.192 3 1.732 3z",
+            AlertColor::Error => "M10 14l2-2m0 0l2-2m-2 2l-2-2m2 2l2 2m7-2a9 9 0 11-18 0 9 9 0 0118 0z",
+            AlertColor::Success => "M9 12l2 2 4-4m6 2a9 9 0 11-18 0 9 9 0 0118 0z",
+        }
+    }
 }
 
 #[derive(Props, Clone, PartialEq)]
@@ -28,6 +59,12 @@ pub struct AlertProps {
     children: Element,
     class: Option<String>,
     alert_color: Option<AlertColor>,
+    /// Show a close button after the content
+    dismissible: Option<bool>,
+    /// Called when the close button is clicked
+    onclose: Option<EventHandler<MouseEvent>>,
+    /// Show an icon for the alert color before the content
+    show_icon: Option<bool>,
 }
 
 #[component]
@@ -36,8 +73,98 @@ pub fn Alert(props: AlertProps) -> Element {
     let class = props.class.unwrap_or_default();
 
     let class = format!("{} {}", alert_color.to_string(), class);
+    let dismissible = props.dismissible.unwrap_or(false);
+    let show_icon = props.show_icon.unwrap_or(false);
+
+    let mut close_listeners = vec![];
+    if let Some(handler) = props.onclose {
+        close_listeners.push(dioxus_elements::events::onclick(move |event| handler.call(event)));
+    }
 
     rsx!(
-        div { class: "{class}", {props.children} }
+        div {
+            class: "{class}",
+            role: "alert",
+            if show_icon {
+                svg {
+                    xmlns: "http://www.w3.org/2000/svg",
+                    class: "h-6 w-6 shrink-0 stroke-current",
+                    fill: "none",
+                    view_box: "0 0 24 24",
+                    "aria-hidden": "true",
+                    path {
+                        stroke_linecap: "round",
+                        stroke_linejoin: "round",
+                        stroke_width: "2",
+                        d: alert_color.icon_path(),
+                    }
+                }
+            }
+            {props.children}
+            if dismissible {
+                button {
+                    class: "btn btn-ghost btn-sm btn-circle",
+                    r#type: "button",
+                    aria_label: "close",
+                    ..close_listeners,
+                    "✕"
+                }
+            }
+        }
     )
 }
+
+#[test]
+fn test_alert_plain() {
+    let props = AlertProps {
+        children: rsx!("Saved"),
+        class: None,
+        alert_color: Some(AlertColor::Success),
+        dismissible: None,
+        onclose: None,
+        show_icon: None,
+    };
+
+    let result = dioxus_ssr::render_element(Alert(props));
+    assert!(result.starts_with(r#"<div class="alert alert-success " role="alert">Saved"#));
+    assert!(!result.contains("<svg"));
+    assert!(!result.contains("<button"));
+}
+
+#[test]
+fn test_alert_icon() {
+    let props = AlertProps {
+        children: rsx!("Disk almost full"),
+        class: None,
+        alert_color: Some(AlertColor::Warn),
+        dismissible: None,
+        onclose: None,
+        show_icon: Some(true),
+    };
+
+    let result = dioxus_ssr::render_element(Alert(props));
+    assert!(result.contains(r#"class="h-6 w-6 shrink-0 stroke-current""#));
+    assert!(result.contains(r#"d="M12 9v2m0 4h.01"#));
+}
+
+#[test]
+fn test_alert_dismissible_close() {
+    use crate::test_utils::{fire, listener, rebuild, TestMouseData};
+
+    fn app() -> Element {
+        let mut closed = use_signal(|| 0);
+        rsx!(
+            Alert {
+                dismissible: true,
+                onclose: move |_| closed += 1,
+                "Closed {closed}"
+            }
+        )
+    }
+
+    let (mut dom, listeners) = rebuild(app);
+    assert!(dioxus_ssr::render(&dom).contains(r#"aria-label="close""#));
+
+    fire(&mut dom, "click", listener(&listeners, "click", 0), TestMouseData::default());
+    assert!(dioxus_ssr::render(&dom).contains("Closed 1"));
+}
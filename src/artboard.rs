@@ -23,6 +23,8 @@ use dioxus::prelude::*;
 
 /// Device type options for Artboard component
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
 pub enum ArtboardDevice {
     /// Phone device frame
     Phone,
@@ -47,6 +49,8 @@ impl Display for ArtboardDevice {
 
 /// Border radius options for Artboard component
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
 pub enum ArtboardBorderRadius {
     /// No border radius
     None,
@@ -74,6 +78,8 @@ impl Display for ArtboardBorderRadius {
 
 /// Shadow options for Artboard component
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
 pub enum ArtboardShadow {
     /// No shadow
     None,
@@ -98,6 +104,8 @@ impl Display for ArtboardShadow {
 
 /// Color scheme options for Artboard component
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
 pub enum ArtboardColorScheme {
     /// Neutral color
     Neutral,
@@ -119,6 +127,8 @@ impl Display for ArtboardColorScheme {
 
 /// Size options for Artboard component
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
 pub enum ArtboardSize {
     /// Small size
     Small,
@@ -156,6 +166,8 @@ pub struct ArtboardProps {
     color_scheme: Option<ArtboardColorScheme>,
     /// Size
     size: Option<ArtboardSize>,
+    /// Fixed width/height ratio such as `16/9`, reserving space before the media loads
+    aspect_ratio: Option<String>,
 }
 
 #[component]
@@ -198,11 +210,16 @@ pub fn Artboard(props: ArtboardProps) -> Element {
     }
 
     let class_string = classes.join(" ");
+    let style = props
+        .aspect_ratio
+        .filter(|ratio| !ratio.is_empty())
+        .map(|ratio| format!("aspect-ratio: {ratio}"));
 
     rsx!(
         div {
             class: "{class_string}",
             id: props.id,
+            style,
             {props.children}
         }
     )
@@ -255,6 +272,7 @@ fn test_artboard_basic() {
         shadow: None,
         color_scheme: None,
         size: None,
+        aspect_ratio: None,
     };
 
     let result = dioxus_ssr::render_element(Artboard(props));
@@ -276,6 +294,7 @@ fn test_artboard_phone() {
         shadow: None,
         color_scheme: None,
         size: None,
+        aspect_ratio: None,
     };
 
     let result = dioxus_ssr::render_element(Artboard(props));
@@ -297,6 +316,7 @@ fn test_artboard_tablet() {
         shadow: None,
         color_scheme: None,
         size: None,
+        aspect_ratio: None,
     };
 
     let result = dioxus_ssr::render_element(Artboard(props));
@@ -318,6 +338,7 @@ fn test_artboard_laptop() {
         shadow: None,
         color_scheme: None,
         size: None,
+        aspect_ratio: None,
     };
 
     let result = dioxus_ssr::render_element(Artboard(props));
@@ -339,6 +360,7 @@ fn test_artboard_desktop() {
         shadow: None,
         color_scheme: None,
         size: None,
+        aspect_ratio: None,
     };
 
     let result = dioxus_ssr::render_element(Artboard(props));
@@ -360,6 +382,7 @@ fn test_artboard_with_shadow() {
         shadow: Some(ArtboardShadow::Large),
         color_scheme: None,
         size: None,
+        aspect_ratio: None,
     };
 
     let result = dioxus_ssr::render_element(Artboard(props));
@@ -381,6 +404,7 @@ fn test_artboard_custom_class() {
         shadow: None,
         color_scheme: None,
         size: None,
+        aspect_ratio: None,
     };
 
     let result = dioxus_ssr::render_element(Artboard(props));
@@ -402,8 +426,31 @@ fn test_artboard_with_id() {
         shadow: None,
         color_scheme: None,
         size: None,
+        aspect_ratio: None,
     };
 
     let result = dioxus_ssr::render_element(Artboard(props));
     assert!(result.contains(r#"id="test-artboard""#));
 }
+
+#[test]
+fn test_artboard_aspect_ratio() {
+    let props = ArtboardProps {
+        children: rsx!(
+            ArtboardContent {
+                div { "Content" }
+            }
+        ),
+        id: None,
+        class: None,
+        device: Some(ArtboardDevice::Tablet),
+        border_radius: None,
+        shadow: None,
+        color_scheme: None,
+        size: None,
+        aspect_ratio: Some("4/3".to_string()),
+    };
+
+    let result = dioxus_ssr::render_element(Artboard(props));
+    assert!(result.contains(r#"<div class="artboard artboard-tablet" style="aspect-ratio: 4/3">"#));
+}
@@ -42,6 +42,8 @@ fn letter_colors(ch: char) -> (&'static str, &'static str) {
 }
 
 #[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
 pub enum AvatarType {
     Team,
     #[default]
@@ -49,6 +51,8 @@ pub enum AvatarType {
 }
 
 #[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
 pub enum AvatarSize {
     #[default]
     Small,
@@ -75,6 +79,8 @@ pub struct AvatarProps {
     name: Option<String>,
     _email: Option<String>,
     image_src: Option<String>,
+    /// Initials shown in a placeholder when there is no image
+    placeholder_text: Option<String>,
 }
 
 #[component]
@@ -105,6 +111,14 @@ pub fn Avatar(props: AvatarProps) -> Element {
                 }
             }
         )
+    } else if let Some(initials) = props.placeholder_text {
+        rsx!(
+            div { class: "avatar avatar-placeholder",
+                div { class: "bg-neutral text-neutral-content rounded-full {avatar_size.2}",
+                    span { {initials} }
+                }
+            }
+        )
     } else {
         match props.avatar_type {
             Some(AvatarType::User) => rsx!(
@@ -191,3 +205,19 @@ pub fn Avatar(props: AvatarProps) -> Element {
         }
     }
 }
+
+#[test]
+fn test_avatar_placeholder_text() {
+    let props = AvatarProps {
+        avatar_size: Some(AvatarSize::Medium),
+        avatar_type: None,
+        name: None,
+        _email: None,
+        image_src: None,
+        placeholder_text: Some("JD".to_string()),
+    };
+
+    let expected = r#"<div class="avatar avatar-placeholder"><div class="bg-neutral text-neutral-content rounded-full w-16 h-16"><span>JD</span></div></div>"#;
+    let result = dioxus_ssr::render_element(Avatar(props));
+    assert_eq!(result, expected);
+}
@@ -3,7 +3,11 @@ use std::fmt::Display;
 
 use dioxus::prelude::*;
 
+use crate::size::Size;
+
 #[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
 pub enum BadgeStyle {
     #[default]
     None,
@@ -26,6 +30,8 @@ impl Display for BadgeStyle {
 }
 
 #[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
 pub enum BadgeColor {
     #[default]
     Default,
@@ -55,7 +61,11 @@ impl Display for BadgeColor {
     }
 }
 
+/// Badge sizes, rendered as `badge-*`. Convertible to and from the shared
+/// `Size` scale, e.g. `badge_size: Size::Sm.into()`.
 #[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
 pub enum BadgeSize {
     #[default]
     Md,
@@ -67,12 +77,30 @@ pub enum BadgeSize {
 
 impl Display for BadgeSize {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            BadgeSize::Md => write!(f, "badge-md"),
-            BadgeSize::Xs => write!(f, "badge-xs"),
-            BadgeSize::Sm => write!(f, "badge-sm"),
-            BadgeSize::Lg => write!(f, "badge-lg"),
-            BadgeSize::Xl => write!(f, "badge-xl"),
+        write!(f, "{}", Size::from(*self).class("badge"))
+    }
+}
+
+impl From<Size> for BadgeSize {
+    fn from(size: Size) -> Self {
+        match size {
+            Size::Xs => BadgeSize::Xs,
+            Size::Sm => BadgeSize::Sm,
+            Size::Md => BadgeSize::Md,
+            Size::Lg => BadgeSize::Lg,
+            Size::Xl => BadgeSize::Xl,
+        }
+    }
+}
+
+impl From<BadgeSize> for Size {
+    fn from(size: BadgeSize) -> Self {
+        match size {
+            BadgeSize::Xs => Size::Xs,
+            BadgeSize::Sm => Size::Sm,
+            BadgeSize::Md => Size::Md,
+            BadgeSize::Lg => Size::Lg,
+            BadgeSize::Xl => Size::Xl,
         }
     }
 }
@@ -84,18 +112,46 @@ pub struct BadgeProps {
     badge_style: Option<BadgeStyle>,
     badge_color: Option<BadgeColor>,
     badge_size: Option<BadgeSize>,
+    /// Render an empty notification dot (extra small unless a size is given)
+    dot: Option<bool>,
+    /// Count to show instead of the children
+    count: Option<u32>,
+    /// Counts above this are shown as "{max}+"
+    max: Option<u32>,
+}
+
+/// Text for a notification count, capped at `max` (e.g. "99+").
+fn format_count(count: u32, max: Option<u32>) -> String {
+    match max {
+        Some(max) if count > max => format!("{}+", max),
+        _ => count.to_string(),
+    }
 }
 
 #[component]
 pub fn Badge(props: BadgeProps) -> Element {
+    let dot = props.dot.unwrap_or(false);
     let badge_style = props.badge_style.unwrap_or_default();
     let badge_color = props.badge_color.unwrap_or_default();
-    let badge_size = props.badge_size.unwrap_or_default();
+    let badge_size = props
+        .badge_size
+        .unwrap_or(if dot { BadgeSize::Xs } else { BadgeSize::default() });
     let class = props.class.unwrap_or_default();
 
-    rsx!(
-        span { class: "badge {badge_style} {badge_color} {badge_size} {class}", {props.children} }
-    )
+    if dot {
+        rsx!(
+            span { class: "badge {badge_style} {badge_color} {badge_size} {class}" }
+        )
+    } else if let Some(count) = props.count {
+        let count = format_count(count, props.max);
+        rsx!(
+            span { class: "badge {badge_style} {badge_color} {badge_size} {class}", "{count}" }
+        )
+    } else {
+        rsx!(
+            span { class: "badge {badge_style} {badge_color} {badge_size} {class}", {props.children} }
+        )
+    }
 }
 
 #[cfg(test)]
@@ -110,10 +166,109 @@ mod tests {
             badge_style: Some(BadgeStyle::Outline),
             badge_color: Some(BadgeColor::Primary),
             badge_size: Some(BadgeSize::Lg),
+            dot: None,
+            count: None,
+            max: None,
         };
         let expected =
             r#"<span class="badge badge-outline badge-primary badge-lg custom">Hello</span>"#;
         let result = dioxus_ssr::render_element(Badge(props));
         assert_eq!(result, expected);
     }
+
+    #[test]
+    fn test_badge_styles_and_sizes() {
+        let styles = [
+            (BadgeStyle::Outline, "badge-outline"),
+            (BadgeStyle::Soft, "badge-soft"),
+            (BadgeStyle::Dash, "badge-dash"),
+            (BadgeStyle::Ghost, "badge-ghost"),
+        ];
+        let sizes = [
+            (BadgeSize::Xs, "badge-xs"),
+            (BadgeSize::Sm, "badge-sm"),
+            (BadgeSize::Md, "badge-md"),
+            (BadgeSize::Lg, "badge-lg"),
+        ];
+
+        for (style, style_class) in styles {
+            for (size, size_class) in sizes {
+                let props = BadgeProps {
+                    children: rsx!("New"),
+                    class: None,
+                    badge_style: Some(style),
+                    badge_color: None,
+                    badge_size: Some(size),
+                    dot: None,
+                    count: None,
+                    max: None,
+                };
+                let result = dioxus_ssr::render_element(Badge(props));
+                assert!(result.contains(r#"class="badge "#));
+                assert!(result.contains(style_class));
+                assert!(result.contains(size_class));
+            }
+        }
+    }
+
+    #[test]
+    fn test_badge_size_display_and_shared_scale() {
+        assert_eq!(BadgeSize::Md.to_string(), "badge-md");
+        assert_eq!(BadgeSize::Xl.to_string(), "badge-xl");
+        assert_eq!(BadgeSize::from(Size::Sm), BadgeSize::Sm);
+        assert_eq!(Size::from(BadgeSize::Lg), Size::Lg);
+
+        let result = dioxus_ssr::render_element(rsx!(
+            Badge { badge_size: BadgeSize::from(Size::Sm), "Shared" }
+            Badge { badge_size: BadgeSize::Lg, "Own" }
+        ));
+        assert!(result.contains(r#"badge-sm ">Shared</span>"#));
+        assert!(result.contains(r#"badge-lg ">Own</span>"#));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_badge_size_serde() {
+        let json = serde_json::to_string(&BadgeSize::Lg).unwrap();
+        assert_eq!(json, r#""lg""#);
+
+        let parsed: BadgeSize = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, BadgeSize::Lg);
+    }
+
+    #[test]
+    fn test_badge_dot() {
+        let props = BadgeProps {
+            children: rsx!("Ignored"),
+            class: None,
+            badge_style: None,
+            badge_color: Some(BadgeColor::Error),
+            badge_size: None,
+            dot: Some(true),
+            count: None,
+            max: None,
+        };
+        let expected = r#"<span class="badge  badge-error badge-xs "></span>"#;
+        let result = dioxus_ssr::render_element(Badge(props));
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_badge_count_max() {
+        let props = BadgeProps {
+            children: rsx!(),
+            class: None,
+            badge_style: None,
+            badge_color: None,
+            badge_size: None,
+            dot: None,
+            count: Some(120),
+            max: Some(99),
+        };
+        let result = dioxus_ssr::render_element(Badge(props));
+        assert!(result.contains(">99+</span>"));
+        assert_eq!(format_count(42, Some(99)), "42");
+        assert_eq!(format_count(99, Some(99)), "99");
+        assert_eq!(format_count(100, None), "100");
+    }
 }
@@ -35,6 +35,71 @@ pub fn Breadcrumb(props: BreadcrumbProps) -> Element {
     )
 }
 
+/// Turns a path segment like `getting-started` into `Getting started`
+fn default_label(segment: &str) -> String {
+    let label = segment.replace(['-', '_'], " ");
+    let mut chars = label.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => label,
+    }
+}
+
+/// One item per segment of `path`, each linking to the path up to that
+/// segment, except the last which is the current page. Any query string or
+/// fragment is ignored.
+pub fn breadcrumb_items_from_path(path: &str, format_label: fn(&str) -> String) -> Vec<BreadcrumbItem> {
+    let path = path.split(['?', '#']).next().unwrap_or_default();
+    let segments: Vec<&str> = path.split('/').filter(|segment| !segment.is_empty()).collect();
+
+    let mut href = String::new();
+    segments
+        .iter()
+        .enumerate()
+        .map(|(index, segment)| {
+            href.push('/');
+            href.push_str(segment);
+            BreadcrumbItem {
+                text: format_label(segment),
+                href: (index + 1 < segments.len()).then(|| href.clone()),
+            }
+        })
+        .collect()
+}
+
+#[derive(Props, Clone)]
+pub struct BreadcrumbsFromPathProps {
+    /// The current path, e.g. `/docs/components/button`
+    path: String,
+    /// Turns a segment into its label (default: capitalized, with `-` and `_` as spaces)
+    format_label: Option<fn(&str) -> String>,
+    class: Option<String>,
+}
+
+impl PartialEq for BreadcrumbsFromPathProps {
+    fn eq(&self, other: &Self) -> bool {
+        let same_format = match (self.format_label, other.format_label) {
+            (Some(a), Some(b)) => std::ptr::fn_addr_eq(a, b),
+            (a, b) => a.is_none() && b.is_none(),
+        };
+        self.path == other.path && self.class == other.class && same_format
+    }
+}
+
+/// A `Breadcrumb` generated from a path, with one item per segment.
+///
+/// ```text
+/// BreadcrumbsFromPath { path: "/docs/components/button" }
+/// ```
+#[component]
+pub fn BreadcrumbsFromPath(props: BreadcrumbsFromPathProps) -> Element {
+    let items = breadcrumb_items_from_path(&props.path, props.format_label.unwrap_or(default_label));
+
+    rsx!(
+        Breadcrumb { items, class: props.class }
+    )
+}
+
 #[test]
 fn test_breadcrumb_basic() {
     let items = vec![
@@ -141,4 +206,34 @@ fn test_breadcrumb_only_text() {
     let expected = r#"<div class="breadcrumbs text-sm "><ul><li>Step 1</li><li>Step 2</li></ul></div>"#;
     let result = dioxus_ssr::render_element(Breadcrumb(props));
     assert_eq!(result, expected);
-}
\ No newline at end of file
+}
+#[test]
+fn test_breadcrumb_items_from_path() {
+    let items = breadcrumb_items_from_path("/docs/components/button-group/", default_label);
+    assert_eq!(
+        items,
+        vec![
+            BreadcrumbItem { text: "Docs".to_string(), href: Some("/docs".to_string()) },
+            BreadcrumbItem {
+                text: "Components".to_string(),
+                href: Some("/docs/components".to_string()),
+            },
+            BreadcrumbItem { text: "Button group".to_string(), href: None },
+        ]
+    );
+    assert!(breadcrumb_items_from_path("/", default_label).is_empty());
+    assert_eq!(breadcrumb_items_from_path("/docs?tab=api#top", default_label).len(), 1);
+}
+
+#[test]
+fn test_breadcrumbs_from_path() {
+    let props = BreadcrumbsFromPathProps {
+        path: "/docs/components/button".to_string(),
+        format_label: Some(|segment: &str| segment.to_uppercase()),
+        class: None,
+    };
+
+    let expected = r#"<div class="breadcrumbs text-sm "><ul><li><a href="/docs">DOCS</a></li><li><a href="/docs/components">COMPONENTS</a></li><li>BUTTON</li></ul></div>"#;
+    let result = dioxus_ssr::render_element(BreadcrumbsFromPath(props));
+    assert_eq!(result, expected);
+}
@@ -0,0 +1,59 @@
+use std::fmt::Display;
+
+/// Responsive breakpoints shared by components that emit prefixed utilities.
+///
+/// # Examples
+///
+/// ```text
+/// use daisy_rsx::Breakpoint;
+///
+/// assert_eq!(Breakpoint::Lg.to_string(), "lg");
+/// assert_eq!(Breakpoint::Xxl.to_string(), "2xl");
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum Breakpoint {
+    /// Small screens (640px)
+    Sm,
+    /// Medium screens (768px)
+    Md,
+    /// Large screens (1024px)
+    Lg,
+    /// Extra large screens (1280px)
+    Xl,
+    /// 2x extra large screens (1536px)
+    Xxl,
+}
+
+impl Display for Breakpoint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Breakpoint::Sm => write!(f, "sm"),
+            Breakpoint::Md => write!(f, "md"),
+            Breakpoint::Lg => write!(f, "lg"),
+            Breakpoint::Xl => write!(f, "xl"),
+            Breakpoint::Xxl => write!(f, "2xl"),
+        }
+    }
+}
+
+impl Breakpoint {
+    /// Prefixes `class` so it only applies from this breakpoint up, e.g.
+    /// `md:btn-lg`. Empty classes stay empty.
+    pub fn prefix(&self, class: impl Display) -> String {
+        let class = class.to_string();
+        if class.is_empty() {
+            class
+        } else {
+            format!("{}:{}", self, class)
+        }
+    }
+}
+
+#[test]
+fn test_breakpoint_prefix() {
+    assert_eq!(Breakpoint::Md.prefix("btn-lg"), "md:btn-lg");
+    assert_eq!(Breakpoint::Xxl.prefix("block"), "2xl:block");
+    assert_eq!(Breakpoint::Sm.prefix(""), "");
+}
@@ -2,6 +2,10 @@
 use std::fmt::Display;
 use dioxus::prelude::*;
 
+use crate::breakpoint::Breakpoint;
+use crate::class::ClassBuilder;
+use crate::loading::Loading;
+
 /// An enhanced button component that provides comprehensive styling options based on DaisyUI button component.
 ///
 /// # Examples
@@ -31,9 +35,36 @@ use dioxus::prelude::*;
 ///     "Save"
 /// }
 /// ```
+///
+/// With an icon after the label:
+///
+/// ```text
+/// use daisy_rsx::{ButtonUI, IconPosition};
+///
+/// ButtonUI {
+///     icon: rsx!(ArrowRightIcon {}),
+///     icon_position: IconPosition::End,
+///     "Next"
+/// }
+/// ```
+///
+/// Asking before a destructive action (needs the `web` feature, see `confirm`):
+///
+/// ```text
+/// use daisy_rsx::{ButtonUI, ButtonUIColorScheme};
+///
+/// ButtonUI {
+///     color_scheme: ButtonUIColorScheme::Error,
+///     confirm: "Delete this project?",
+///     onclick: move |_| delete_project(),
+///     "Delete"
+/// }
+/// ```
 
 /// Color scheme options for ButtonUI component
 #[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
 pub enum ButtonUIColorScheme {
     #[default]
     /// Neutral gray color scheme
@@ -77,6 +108,8 @@ impl Display for ButtonUIColorScheme {
 
 /// Size options for ButtonUI component
 #[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
 pub enum ButtonUISize {
     #[default]
     /// Default size (equivalent to Small)
@@ -108,6 +141,8 @@ impl Display for ButtonUISize {
 
 /// Shape options for ButtonUI component
 #[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
 pub enum ButtonUIShape {
     #[default]
     /// Default rectangular shape
@@ -130,6 +165,8 @@ impl Display for ButtonUIShape {
 
 /// Visual variant options for ButtonUI component
 #[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
 pub enum ButtonUIVariant {
     #[default]
     /// Default solid button style
@@ -161,6 +198,8 @@ impl Display for ButtonUIVariant {
 
 /// State options for ButtonUI component
 #[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
 pub enum ButtonUIState {
     #[default]
     /// Default state
@@ -187,6 +226,42 @@ impl Display for ButtonUIState {
     }
 }
 
+/// Side of the label that ButtonUI's `icon` goes on
+#[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum IconPosition {
+    #[default]
+    /// Before the label (default)
+    Start,
+    /// After the label
+    End,
+}
+
+/// HTML `type` options for ButtonUI component
+#[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum ButtonUIType {
+    #[default]
+    /// Plain button that does nothing inside a form (default)
+    Button,
+    /// Submits the enclosing form
+    Submit,
+    /// Resets the enclosing form
+    Reset,
+}
+
+impl Display for ButtonUIType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ButtonUIType::Button => write!(f, "button"),
+            ButtonUIType::Submit => write!(f, "submit"),
+            ButtonUIType::Reset => write!(f, "reset"),
+        }
+    }
+}
+
 #[derive(Props, Clone, PartialEq)]
 pub struct ButtonUIProps {
     /// The content to display inside the button
@@ -217,6 +292,58 @@ pub struct ButtonUIProps {
     prefix_icon: Option<String>,
     /// HTML string for icon to show after the button text
     suffix_icon: Option<String>,
+    /// Icon shown on the `icon_position` side of the button text, next to any prefix/suffix icon
+    icon: Option<Element>,
+    /// Which side `icon` goes on (default: `Start`)
+    icon_position: Option<IconPosition>,
+    /// Full width on small screens, back to its natural width from `sm` up
+    block_on_mobile: Option<bool>,
+    /// Text shown with a spinner in place of the children while loading
+    loading_text: Option<String>,
+    /// Click handler
+    onclick: Option<EventHandler<MouseEvent>>,
+    /// Message the user must confirm, in a browser `confirm()` dialog, before
+    /// `onclick` fires.
+    ///
+    /// **Needs the `web` feature.** Other builds have no dialog to show, so the
+    /// click counts as declined and `onclick` never fires while this is set.
+    /// Ask for confirmation in your own UI there, e.g. with a `Modal`.
+    confirm: Option<String>,
+    /// Double-click handler
+    ondblclick: Option<EventHandler<MouseEvent>>,
+    /// How long the button must be held before `onlongpress` fires, in milliseconds (defaults to 500)
+    long_press_ms: Option<u64>,
+    /// Handler fired when the button is held down for `long_press_ms`
+    onlongpress: Option<EventHandler<()>>,
+    /// Focus handler
+    onfocus: Option<EventHandler<FocusEvent>>,
+    /// Blur handler
+    onblur: Option<EventHandler<FocusEvent>>,
+    /// Sizes that apply from a breakpoint up, e.g. `[(Breakpoint::Md, ButtonUISize::Large)]` for `md:btn-lg`
+    responsive: Option<Vec<(Breakpoint, ButtonUISize)>>,
+    /// HTML `type` of the button (default: `button`); not used for links
+    button_type: Option<ButtonUIType>,
+}
+
+const LONG_PRESS_MS: u64 = 500;
+
+/// Whether a click should go ahead, asking `confirm` first when there is a message.
+fn confirmed(message: Option<&str>, confirm: impl FnOnce(&str) -> bool) -> bool {
+    message.is_none_or(confirm)
+}
+
+#[cfg(feature = "web")]
+fn window_confirm(message: &str) -> bool {
+    web_sys::window()
+        .and_then(|window| window.confirm_with_message(message).ok())
+        .unwrap_or(false)
+}
+
+// Without a browser there is nobody to ask, so the click counts as declined
+// rather than skipping the check the caller asked for
+#[cfg(not(feature = "web"))]
+fn window_confirm(_message: &str) -> bool {
+    false
 }
 
 #[component]
@@ -226,6 +353,7 @@ pub fn ButtonUI(props: ButtonUIProps) -> Element {
     let shape = props.shape.unwrap_or_default();
     let variant = props.variant.unwrap_or_default();
     let state = props.state.unwrap_or_default();
+    let button_type = props.button_type.unwrap_or_default();
     let class = props.class.unwrap_or_default();
     let disabled = props.disabled.filter(|&x| x);
     let loading = props.loading.filter(|&x| x);
@@ -235,44 +363,123 @@ pub fn ButtonUI(props: ButtonUIProps) -> Element {
     let final_state = if is_loading { ButtonUIState::Loading } else { state };
 
     // Build CSS classes
-    let mut classes = vec!["btn".to_string()];
-    
-    if !color_scheme.to_string().is_empty() {
-        classes.push(color_scheme.to_string());
-    }
-    if !size.to_string().is_empty() {
-        classes.push(size.to_string());
+    let mut classes = ClassBuilder::new("btn");
+    classes.push(color_scheme).push(size);
+    for (breakpoint, size) in props.responsive.unwrap_or_default() {
+        classes.push(breakpoint.prefix(size));
     }
-    if !shape.to_string().is_empty() {
-        classes.push(shape.to_string());
+    let class_string = classes
+        .push(shape)
+        .push(variant)
+        // The spinner element shows the loading state; the `loading` class
+        // would mask the whole button, hiding it and the loading text
+        .push_if(!is_loading, final_state)
+        // Links can't be disabled natively, so they need the class to look it
+        .push_if(
+            disabled.is_some() && props.href.is_some() && final_state != ButtonUIState::Disabled,
+            "btn-disabled",
+        )
+        .push_if(props.block_on_mobile.unwrap_or(false), "w-full sm:w-auto")
+        .push(class)
+        .build();
+
+    // While loading, a spinner goes in front of the label (or the loading
+    // text, when set) and the icons are dropped so they don't double up
+    let content = if is_loading {
+        let label = match props.loading_text {
+            Some(text) => rsx!("{text}"),
+            None => props.children,
+        };
+        rsx!(
+            Loading {}
+            {label}
+        )
+    } else {
+        props.children
+    };
+    let prefix_icon = props.prefix_icon.filter(|_| !is_loading);
+    let suffix_icon = props.suffix_icon.filter(|_| !is_loading);
+    let icon = props.icon.filter(|_| !is_loading);
+    let (start_icon, end_icon) = match props.icon_position.unwrap_or_default() {
+        IconPosition::Start => (icon, None),
+        IconPosition::End => (None, icon),
+    };
+
+    // Handlers are only attached when provided, and never to disabled or
+    // loading buttons so they can't fire
+    let inert = disabled.is_some()
+        || matches!(final_state, ButtonUIState::Disabled | ButtonUIState::Loading);
+    let mut listeners = vec![];
+    if let Some(handler) = props.onclick.filter(|_| !inert) {
+        let confirm = props.confirm;
+        listeners.push(dioxus_elements::events::onclick(move |event| {
+            if confirmed(confirm.as_deref(), window_confirm) {
+                handler.call(event);
+            }
+        }));
     }
-    if !variant.to_string().is_empty() {
-        classes.push(variant.to_string());
+
+    if let Some(handler) = props.ondblclick.filter(|_| !inert) {
+        listeners.push(dioxus_elements::events::ondoubleclick(move |event| handler.call(event)));
     }
-    if !final_state.to_string().is_empty() {
-        classes.push(final_state.to_string());
+
+    // Pending long-press timer, cancelled if the pointer is released early
+    let mut press_timer = use_signal(|| None::<dioxus::dioxus_core::Task>);
+    if let Some(handler) = props.onlongpress.filter(|_| !inert) {
+        let delay = std::time::Duration::from_millis(props.long_press_ms.unwrap_or(LONG_PRESS_MS));
+        let release = move |_: PointerEvent| {
+            if let Some(task) = press_timer.take() {
+                task.cancel();
+            }
+        };
+        listeners.push(dioxus_elements::events::onpointerdown(move |_| {
+            if let Some(task) = press_timer.take() {
+                task.cancel();
+            }
+            press_timer.set(Some(spawn(async move {
+                futures_timer::Delay::new(delay).await;
+                press_timer.set(None);
+                handler.call(());
+            })));
+        }));
+        listeners.push(dioxus_elements::events::onpointerup(release));
+        listeners.push(dioxus_elements::events::onpointerleave(release));
+        listeners.push(dioxus_elements::events::onpointercancel(release));
     }
-    
-    if !class.is_empty() {
-        classes.push(class);
+
+    if let Some(handler) = props.onfocus.filter(|_| !inert) {
+        listeners.push(dioxus_elements::events::onfocus(move |event| handler.call(event)));
     }
 
-    let class_string = classes.join(" ");
+    if let Some(handler) = props.onblur.filter(|_| !inert) {
+        listeners.push(dioxus_elements::events::onblur(move |event| handler.call(event)));
+    }
 
     // Render as link if href is provided
     if let Some(href) = props.href {
+        // A disabled link loses its href and leaves the tab order so it
+        // can't be followed by mouse or keyboard
+        let href = disabled.is_none().then_some(href);
         rsx!(
             a {
                 class: "{class_string}",
                 id: props.id,
-                href: "{href}",
+                href,
                 target: props.target,
+                tabindex: disabled.map(|_| "-1"),
                 aria_disabled: disabled.map(|_| "true"),
-                if let Some(icon) = props.prefix_icon {
+                ..listeners,
+                if let Some(icon) = prefix_icon {
                     span { class: "icon", dangerous_inner_html: "{icon}" }
                 }
-                {props.children}
-                if let Some(icon) = props.suffix_icon {
+                if let Some(icon) = start_icon {
+                    span { class: "icon", {icon} }
+                }
+                {content}
+                if let Some(icon) = end_icon {
+                    span { class: "icon", {icon} }
+                }
+                if let Some(icon) = suffix_icon {
                     span { class: "icon", dangerous_inner_html: "{icon}" }
                 }
             }
@@ -282,12 +489,20 @@ pub fn ButtonUI(props: ButtonUIProps) -> Element {
             button {
                 class: "{class_string}",
                 id: props.id,
+                r#type: "{button_type}",
                 disabled,
-                if let Some(icon) = props.prefix_icon {
+                ..listeners,
+                if let Some(icon) = prefix_icon {
                     span { class: "icon", dangerous_inner_html: "{icon}" }
                 }
-                {props.children}
-                if let Some(icon) = props.suffix_icon {
+                if let Some(icon) = start_icon {
+                    span { class: "icon", {icon} }
+                }
+                {content}
+                if let Some(icon) = end_icon {
+                    span { class: "icon", {icon} }
+                }
+                if let Some(icon) = suffix_icon {
                     span { class: "icon", dangerous_inner_html: "{icon}" }
                 }
             }
@@ -295,6 +510,14 @@ pub fn ButtonUI(props: ButtonUIProps) -> Element {
     }
 }
 
+// ButtonUI holds long-press state in a hook, so it has to render inside a runtime
+#[cfg(test)]
+fn render_button(props: ButtonUIProps) -> String {
+    let mut dom = VirtualDom::new_with_props(ButtonUI, props);
+    dom.rebuild_in_place();
+    dioxus_ssr::render(&dom)
+}
+
 #[test]
 fn test_button_ui_basic() {
     let props = ButtonUIProps {
@@ -312,13 +535,62 @@ fn test_button_ui_basic() {
         loading: None,
         prefix_icon: None,
         suffix_icon: None,
+        loading_text: None,
+        onclick: None,
+        confirm: None,
+        ondblclick: None,
+        long_press_ms: None,
+        onlongpress: None,
+        onfocus: None,
+        onblur: None,
+        responsive: None,
+        button_type: None,
+        icon: None,
+        icon_position: None,
+        block_on_mobile: None,
     };
 
-    let result = dioxus_ssr::render_element(ButtonUI(props));
+    let result = render_button(props);
     assert!(result.contains(r#"<button class="btn btn-neutral""#));
     assert!(result.contains(">Test Button</button>"));
 }
 
+#[test]
+fn test_button_ui_class_dedup() {
+    let props = ButtonUIProps {
+        children: rsx!("Save"),
+        id: None,
+        class: Some("btn btn-neutral w-full".to_string()),
+        disabled: None,
+        href: None,
+        target: None,
+        color_scheme: None,
+        size: None,
+        shape: None,
+        variant: None,
+        state: None,
+        loading: None,
+        prefix_icon: None,
+        suffix_icon: None,
+        loading_text: None,
+        onclick: None,
+        confirm: None,
+        ondblclick: None,
+        long_press_ms: None,
+        onlongpress: None,
+        onfocus: None,
+        onblur: None,
+        responsive: None,
+        button_type: None,
+        icon: None,
+        icon_position: None,
+        block_on_mobile: None,
+    };
+
+    let result = render_button(props);
+    assert!(result.contains(r#"<button class="btn btn-neutral w-full""#));
+}
+
 #[test]
 fn test_button_ui_with_all_props() {
     let props = ButtonUIProps {
@@ -336,9 +608,22 @@ fn test_button_ui_with_all_props() {
         loading: None,
         prefix_icon: Some("<svg>...</svg>".to_string()),
         suffix_icon: Some("<svg>...</svg>".to_string()),
+        loading_text: None,
+        onclick: None,
+        confirm: None,
+        ondblclick: None,
+        long_press_ms: None,
+        onlongpress: None,
+        onfocus: None,
+        onblur: None,
+        responsive: None,
+        button_type: None,
+        icon: None,
+        icon_position: None,
+        block_on_mobile: None,
     };
 
-    let result = dioxus_ssr::render_element(ButtonUI(props));
+    let result = render_button(props);
     assert!(result.contains(r#"<a class="btn btn-primary btn-lg btn-circle btn-outline btn-active custom-class""#));
     assert!(result.contains(r#"id="test-button""#));
     assert!(result.contains(r#"href="https://example.com""#));
@@ -364,11 +649,61 @@ fn test_button_ui_loading_state() {
         loading: Some(true),
         prefix_icon: None,
         suffix_icon: None,
+        loading_text: None,
+        onclick: None,
+        confirm: None,
+        ondblclick: None,
+        long_press_ms: None,
+        onlongpress: None,
+        onfocus: None,
+        onblur: None,
+        responsive: None,
+        button_type: None,
+        icon: None,
+        icon_position: None,
+        block_on_mobile: None,
+    };
+
+    let result = render_button(props);
+    assert!(result.contains(r#"<button class="btn btn-neutral" "#));
+    assert!(result.contains(r#"<span class="loading loading-spinner" aria-label="Loading"></span>Loading Button</button>"#));
+}
+
+#[test]
+fn test_button_ui_loading_spinner_replaces_icons() {
+    let props = ButtonUIProps {
+        children: rsx!("Upload"),
+        id: None,
+        class: None,
+        disabled: None,
+        href: None,
+        target: None,
+        color_scheme: None,
+        size: None,
+        shape: None,
+        variant: None,
+        state: Some(ButtonUIState::Loading),
+        loading: None,
+        prefix_icon: Some("<svg>...</svg>".to_string()),
+        suffix_icon: Some("<svg>...</svg>".to_string()),
+        loading_text: None,
+        onclick: None,
+        confirm: None,
+        ondblclick: None,
+        long_press_ms: None,
+        onlongpress: None,
+        onfocus: None,
+        onblur: None,
+        responsive: None,
+        button_type: None,
+        icon: None,
+        icon_position: None,
+        block_on_mobile: None,
     };
 
-    let result = dioxus_ssr::render_element(ButtonUI(props));
-    assert!(result.contains(r#"class="btn btn-neutral loading""#));
-    assert!(result.contains(">Loading Button</button>"));
+    let result = render_button(props);
+    assert!(result.contains(r#"<span class="loading loading-spinner" aria-label="Loading"></span>Upload</button>"#));
+    assert!(!result.contains(r#"class="icon""#));
 }
 
 #[test]
@@ -402,9 +737,22 @@ fn test_all_button_ui_color_schemes() {
             loading: None,
             prefix_icon: None,
             suffix_icon: None,
+            loading_text: None,
+            onclick: None,
+            confirm: None,
+            ondblclick: None,
+            long_press_ms: None,
+            onlongpress: None,
+            onfocus: None,
+            onblur: None,
+            responsive: None,
+            button_type: None,
+            icon: None,
+            icon_position: None,
+            block_on_mobile: None,
         };
 
-        let result = dioxus_ssr::render_element(ButtonUI(props));
+        let result = render_button(props);
         assert!(result.contains(expected_class),
                 "Expected '{}' to contain '{}', but got: {}",
                 result, expected_class, result);
@@ -438,9 +786,22 @@ fn test_all_button_ui_sizes() {
             loading: None,
             prefix_icon: None,
             suffix_icon: None,
+            loading_text: None,
+            onclick: None,
+            confirm: None,
+            ondblclick: None,
+            long_press_ms: None,
+            onlongpress: None,
+            onfocus: None,
+            onblur: None,
+            responsive: None,
+            button_type: None,
+            icon: None,
+            icon_position: None,
+            block_on_mobile: None,
         };
 
-        let result = dioxus_ssr::render_element(ButtonUI(props));
+        let result = render_button(props);
         if expected_class.is_empty() {
             // Default size should not add any size class, but other classes might be present
             assert!(result.contains("btn btn-neutral"), "Expected basic button classes, but got: {}", result);
@@ -476,9 +837,22 @@ fn test_all_button_ui_shapes() {
             loading: None,
             prefix_icon: None,
             suffix_icon: None,
+            loading_text: None,
+            onclick: None,
+            confirm: None,
+            ondblclick: None,
+            long_press_ms: None,
+            onlongpress: None,
+            onfocus: None,
+            onblur: None,
+            responsive: None,
+            button_type: None,
+            icon: None,
+            icon_position: None,
+            block_on_mobile: None,
         };
 
-        let result = dioxus_ssr::render_element(ButtonUI(props));
+        let result = render_button(props);
         if expected_class.is_empty() {
             assert!(!result.contains("btn-circle") && !result.contains("btn-square"),
                     "Expected no shape class, but got: {}", result);
@@ -517,9 +891,22 @@ fn test_all_button_ui_variants() {
             loading: None,
             prefix_icon: None,
             suffix_icon: None,
+            loading_text: None,
+            onclick: None,
+            confirm: None,
+            ondblclick: None,
+            long_press_ms: None,
+            onlongpress: None,
+            onfocus: None,
+            onblur: None,
+            responsive: None,
+            button_type: None,
+            icon: None,
+            icon_position: None,
+            block_on_mobile: None,
         };
 
-        let result = dioxus_ssr::render_element(ButtonUI(props));
+        let result = render_button(props);
         if expected_class.is_empty() {
             assert!(!result.contains("btn-outline") && !result.contains("btn-soft") &&
                     !result.contains("btn-wide") && !result.contains("btn-block") && !result.contains("glass"),
@@ -538,7 +925,7 @@ fn test_all_button_ui_states() {
         (ButtonUIState::None, ""),
         (ButtonUIState::Active, "btn-active"),
         (ButtonUIState::Disabled, "btn-disabled"),
-        (ButtonUIState::Loading, "loading"),
+        (ButtonUIState::Loading, r#"<span class="loading loading-spinner""#),
         (ButtonUIState::Focus, "btn-focus"),
     ];
 
@@ -558,9 +945,22 @@ fn test_all_button_ui_states() {
             loading: None,
             prefix_icon: None,
             suffix_icon: None,
+            loading_text: None,
+            onclick: None,
+            confirm: None,
+            ondblclick: None,
+            long_press_ms: None,
+            onlongpress: None,
+            onfocus: None,
+            onblur: None,
+            responsive: None,
+            button_type: None,
+            icon: None,
+            icon_position: None,
+            block_on_mobile: None,
         };
 
-        let result = dioxus_ssr::render_element(ButtonUI(props));
+        let result = render_button(props);
         if expected_class.is_empty() {
             assert!(!result.contains("btn-active") && !result.contains("btn-disabled") &&
                     !result.contains("loading") && !result.contains("btn-focus"),
@@ -571,4 +971,395 @@ fn test_all_button_ui_states() {
                     result, expected_class, result);
         }
     }
-}
\ No newline at end of file
+}
+#[test]
+fn test_button_ui_loading_text() {
+    let props = ButtonUIProps {
+        children: rsx!("Save"),
+        id: None,
+        class: None,
+        disabled: None,
+        href: None,
+        target: None,
+        color_scheme: None,
+        size: None,
+        shape: None,
+        variant: None,
+        state: None,
+        loading: Some(true),
+        prefix_icon: None,
+        suffix_icon: None,
+        loading_text: Some("Saving...".to_string()),
+        onclick: None,
+        confirm: None,
+        ondblclick: None,
+        long_press_ms: None,
+        onlongpress: None,
+        onfocus: None,
+        onblur: None,
+        responsive: None,
+        button_type: None,
+        icon: None,
+        icon_position: None,
+        block_on_mobile: None,
+    };
+
+    let result = render_button(props);
+    assert!(result.contains(r#"<span class="loading loading-spinner" aria-label="Loading"></span>Saving...</button>"#));
+    assert!(!result.contains("Save<"));
+}
+
+#[test]
+fn test_button_ui_confirm_declined_skips_onclick() {
+    assert!(!confirmed(Some("Delete this project?"), |_| false));
+    assert!(confirmed(Some("Delete this project?"), |_| true));
+    assert!(confirmed(None, |_| false));
+}
+
+// With `web` on, the confirmation is a browser dialog
+#[cfg(not(feature = "web"))]
+#[test]
+fn test_button_ui_confirm_without_browser_skips_onclick() {
+    use crate::test_utils::{fire, listener, rebuild, TestMouseData};
+
+    fn app() -> Element {
+        let mut clicks = use_signal(|| 0);
+        rsx!(
+            ButtonUI {
+                confirm: "Delete this project?",
+                onclick: move |_| clicks += 1,
+                "Clicked {clicks}"
+            }
+        )
+    }
+
+    let (mut dom, listeners) = rebuild(app);
+    fire(&mut dom, "click", listener(&listeners, "click", 0), TestMouseData::default());
+    assert!(dioxus_ssr::render(&dom).contains("Clicked 0"));
+}
+
+/// Replaces `window.confirm` with a function that always answers `answer`.
+#[cfg(all(feature = "web", target_arch = "wasm32"))]
+fn stub_confirm(answer: bool) {
+    let confirm = js_sys::Function::new_no_args(&format!("return {answer};"));
+    let window = web_sys::window().unwrap();
+    js_sys::Reflect::set(&window, &"confirm".into(), &confirm).unwrap();
+}
+
+#[cfg(all(feature = "web", target_arch = "wasm32"))]
+#[wasm_bindgen_test::wasm_bindgen_test]
+async fn test_button_ui_confirm_in_browser() {
+    use crate::test_utils::{mount, settle};
+    use wasm_bindgen::JsCast;
+
+    fn app() -> Element {
+        let mut clicks = use_signal(|| 0);
+        rsx!(
+            ButtonUI {
+                id: "confirm-button",
+                confirm: "Delete this project?",
+                onclick: move |_| clicks += 1,
+                "Clicked {clicks}"
+            }
+        )
+    }
+
+    let root = mount(app).await;
+    let document = web_sys::window().unwrap().document().unwrap();
+    let button: web_sys::HtmlElement = document
+        .get_element_by_id("confirm-button")
+        .unwrap()
+        .unchecked_into();
+
+    stub_confirm(false);
+    button.click();
+    settle().await;
+    assert!(root.text_content().unwrap().contains("Clicked 0"));
+
+    stub_confirm(true);
+    button.click();
+    settle().await;
+    assert!(root.text_content().unwrap().contains("Clicked 1"));
+}
+
+#[test]
+fn test_button_ui_onclick() {
+    use crate::test_utils::{fire, listener, rebuild, TestMouseData};
+
+    fn app() -> Element {
+        let mut clicks = use_signal(|| 0);
+        rsx!(
+            ButtonUI { onclick: move |_| clicks += 1, "Clicked {clicks}" }
+        )
+    }
+
+    let (mut dom, listeners) = rebuild(app);
+    fire(&mut dom, "click", listener(&listeners, "click", 0), TestMouseData::default());
+    assert!(dioxus_ssr::render(&dom).contains("Clicked 1"));
+}
+
+#[test]
+fn test_button_ui_ondblclick() {
+    use crate::test_utils::{fire, listener, rebuild, TestMouseData};
+
+    fn app() -> Element {
+        let mut clicks = use_signal(|| 0);
+        rsx!(
+            ButtonUI { ondblclick: move |_| clicks += 1, "Double clicked {clicks}" }
+        )
+    }
+
+    let (mut dom, listeners) = rebuild(app);
+    fire(&mut dom, "dblclick", listener(&listeners, "dblclick", 0), TestMouseData::default());
+    assert!(dioxus_ssr::render(&dom).contains("Double clicked 1"));
+}
+
+#[test]
+fn test_button_ui_long_press() {
+    use crate::test_utils::{block_on, fire, listener, rebuild, TestMouseData};
+
+    fn app() -> Element {
+        let mut pressed = use_signal(|| false);
+        rsx!(
+            ButtonUI { long_press_ms: 10, onlongpress: move |_| pressed.set(true), "Pressed {pressed}" }
+        )
+    }
+
+    let (mut dom, listeners) = rebuild(app);
+    fire(&mut dom, "pointerdown", listener(&listeners, "pointerdown", 0), TestMouseData::default());
+    assert!(dioxus_ssr::render(&dom).contains("Pressed false"));
+
+    // Let the long-press timer elapse
+    block_on(dom.wait_for_work());
+    dom.render_immediate_to_vec();
+    assert!(dioxus_ssr::render(&dom).contains("Pressed true"));
+}
+
+#[test]
+fn test_button_ui_disabled_omits_handlers() {
+    use crate::test_utils::rebuild;
+
+    fn enabled() -> Element {
+        rsx!(
+            ButtonUI { onclick: |_| {}, onfocus: |_| {}, onblur: |_| {}, "Save" }
+        )
+    }
+
+    fn disabled() -> Element {
+        rsx!(
+            ButtonUI { state: ButtonUIState::Disabled, onclick: |_| {}, onfocus: |_| {}, "Save" }
+            ButtonUI { loading: true, onclick: |_| {}, "Save" }
+        )
+    }
+
+    let (dom, listeners) = rebuild(enabled);
+    assert!(dioxus_ssr::render(&dom).starts_with("<button"));
+    let names: Vec<&str> = listeners.iter().map(|(name, _)| name.as_str()).collect();
+    assert_eq!(names, ["click", "focus", "blur"]);
+
+    let (_, listeners) = rebuild(disabled);
+    assert!(listeners.is_empty());
+}
+
+#[test]
+fn test_button_ui_responsive_sizes() {
+    let props = ButtonUIProps {
+        children: rsx!("Buy now"),
+        id: None,
+        class: None,
+        disabled: None,
+        href: None,
+        target: None,
+        color_scheme: None,
+        size: Some(ButtonUISize::Small),
+        shape: None,
+        variant: None,
+        state: None,
+        loading: None,
+        prefix_icon: None,
+        suffix_icon: None,
+        loading_text: None,
+        onclick: None,
+        confirm: None,
+        ondblclick: None,
+        long_press_ms: None,
+        onlongpress: None,
+        onfocus: None,
+        onblur: None,
+        responsive: Some(vec![
+            (Breakpoint::Md, ButtonUISize::Medium),
+            (Breakpoint::Lg, ButtonUISize::Large),
+            (Breakpoint::Xl, ButtonUISize::Default),
+        ]),
+        button_type: None,
+        icon: None,
+        icon_position: None,
+        block_on_mobile: None,
+    };
+
+    let result = render_button(props);
+    assert!(result.contains(r#"class="btn btn-neutral btn-sm md:btn-md lg:btn-lg""#));
+}
+
+#[test]
+fn test_button_ui_type() {
+    fn props(href: Option<String>, button_type: Option<ButtonUIType>) -> ButtonUIProps {
+        ButtonUIProps {
+            children: rsx!("Send"),
+            id: None,
+            class: None,
+            disabled: None,
+            href,
+            target: None,
+            color_scheme: None,
+            size: None,
+            shape: None,
+            variant: None,
+            state: None,
+            loading: None,
+            prefix_icon: None,
+            suffix_icon: None,
+            loading_text: None,
+            onclick: None,
+            confirm: None,
+            ondblclick: None,
+            long_press_ms: None,
+            onlongpress: None,
+            onfocus: None,
+            onblur: None,
+            responsive: None,
+            button_type,
+            icon: None,
+            icon_position: None,
+            block_on_mobile: None,
+        }
+    }
+
+    assert!(render_button(props(None, None)).contains(r#"type="button""#));
+    assert!(render_button(props(None, Some(ButtonUIType::Submit))).contains(r#"type="submit""#));
+    assert!(!render_button(props(Some("/send".to_string()), Some(ButtonUIType::Submit))).contains("type="));
+}
+
+#[test]
+fn test_button_ui_disabled_link() {
+    let props = ButtonUIProps {
+        children: rsx!("Download"),
+        id: None,
+        class: None,
+        disabled: Some(true),
+        href: Some("/download".to_string()),
+        target: None,
+        color_scheme: None,
+        size: None,
+        shape: None,
+        variant: None,
+        state: None,
+        loading: None,
+        prefix_icon: None,
+        suffix_icon: None,
+        loading_text: None,
+        onclick: None,
+        confirm: None,
+        ondblclick: None,
+        long_press_ms: None,
+        onlongpress: None,
+        onfocus: None,
+        onblur: None,
+        responsive: None,
+        button_type: None,
+        icon: None,
+        icon_position: None,
+        block_on_mobile: None,
+    };
+
+    let result = render_button(props);
+    assert!(result.starts_with(r#"<a class="btn btn-neutral btn-disabled""#));
+    assert!(!result.contains("href="));
+    assert!(result.contains(r#"tabindex="-1""#));
+    assert!(result.contains(r#"aria-disabled="true""#));
+}
+
+#[test]
+fn test_button_ui_icon_position() {
+    let props = |icon_position: Option<IconPosition>| ButtonUIProps {
+        children: rsx!("Next"),
+        id: None,
+        class: None,
+        disabled: None,
+        href: None,
+        target: None,
+        color_scheme: None,
+        size: None,
+        shape: None,
+        variant: None,
+        state: None,
+        loading: None,
+        prefix_icon: None,
+        suffix_icon: None,
+        loading_text: None,
+        onclick: None,
+        confirm: None,
+        ondblclick: None,
+        long_press_ms: None,
+        onlongpress: None,
+        onfocus: None,
+        onblur: None,
+        responsive: None,
+        button_type: None,
+        icon: Some(rsx!(svg { class: "arrow" })),
+        icon_position,
+        block_on_mobile: None,
+    };
+
+    let start = render_button(props(None));
+    assert!(start.contains(r#"<span class="icon"><svg class="arrow"></svg></span>Next</button>"#));
+
+    let end = render_button(props(Some(IconPosition::End)));
+    assert!(end.contains(r#">Next<span class="icon"><svg class="arrow"></svg></span></button>"#));
+}
+
+#[test]
+fn test_button_ui_block_on_mobile() {
+    let props = ButtonUIProps {
+        children: rsx!("Get started"),
+        id: None,
+        class: None,
+        disabled: None,
+        href: None,
+        target: None,
+        color_scheme: Some(ButtonUIColorScheme::Primary),
+        size: None,
+        shape: None,
+        variant: None,
+        state: None,
+        loading: None,
+        prefix_icon: None,
+        suffix_icon: None,
+        loading_text: None,
+        onclick: None,
+        confirm: None,
+        ondblclick: None,
+        long_press_ms: None,
+        onlongpress: None,
+        onfocus: None,
+        onblur: None,
+        responsive: None,
+        button_type: None,
+        icon: None,
+        icon_position: None,
+        block_on_mobile: Some(true),
+    };
+
+    let result = render_button(props);
+    assert!(result.contains(r#"class="btn btn-primary w-full sm:w-auto""#));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_button_ui_color_scheme_serde() {
+    let json = serde_json::to_string(&ButtonUIColorScheme::Primary).unwrap();
+    assert_eq!(json, r#""primary""#);
+
+    let parsed: ButtonUIColorScheme = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed, ButtonUIColorScheme::Primary);
+}
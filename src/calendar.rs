@@ -31,6 +31,8 @@ use dioxus::prelude::*;
 
 /// Color scheme options for Calendar component
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
 pub enum CalendarColorScheme {
     /// Primary color
     Primary,
@@ -46,6 +48,8 @@ pub enum CalendarColorScheme {
     Warning,
     /// Error color
     Error,
+    /// Neutral color
+    Neutral,
 }
 
 impl Display for CalendarColorScheme {
@@ -58,12 +62,15 @@ impl Display for CalendarColorScheme {
             CalendarColorScheme::Success => write!(f, "calendar-success"),
             CalendarColorScheme::Warning => write!(f, "calendar-warning"),
             CalendarColorScheme::Error => write!(f, "calendar-error"),
+            CalendarColorScheme::Neutral => write!(f, "calendar-neutral"),
         }
     }
 }
 
 /// Size options for Calendar component
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
 pub enum CalendarSize {
     /// Small size
     Small,
@@ -421,3 +428,8 @@ fn test_calendar_with_id() {
     let result = dioxus_ssr::render_element(Calendar(props));
     assert!(result.contains(r#"id="test-calendar""#));
 }
+
+#[test]
+fn test_calendar_neutral_color_scheme() {
+    assert_eq!(CalendarColorScheme::Neutral.to_string(), "calendar-neutral");
+}
@@ -2,6 +2,8 @@
 
 use dioxus::prelude::*;
 
+use crate::density::current_density;
+
 #[derive(Props, Clone, PartialEq)]
 pub struct CardProps {
     class: Option<String>,
@@ -18,7 +20,10 @@ pub fn Card(props: CardProps) -> Element {
         "".to_string()
     };
 
-    let class = format!("card {}", class);
+    let class = match current_density().class("card-sm") {
+        Some(compact) => format!("card {} {}", compact, class),
+        None => format!("card {}", class),
+    };
 
     rsx!(
         div {
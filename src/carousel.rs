@@ -2,6 +2,9 @@
 use std::fmt::Display;
 use dioxus::prelude::*;
 
+use crate::context::{try_context, try_provide_context};
+use crate::motion::{motion_preference, reduced_motion, MotionPreference};
+
 /// A Carousel component for image/content carousels.
 ///
 /// # Examples
@@ -22,6 +25,8 @@ use dioxus::prelude::*;
 
 /// Color scheme options for Carousel component
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
 pub enum CarouselColorScheme {
     /// Neutral color
     Neutral,
@@ -29,6 +34,16 @@ pub enum CarouselColorScheme {
     Primary,
     /// Secondary color
     Secondary,
+    /// Accent color
+    Accent,
+    /// Info color
+    Info,
+    /// Success color
+    Success,
+    /// Warning color
+    Warning,
+    /// Error color
+    Error,
 }
 
 impl Display for CarouselColorScheme {
@@ -37,12 +52,19 @@ impl Display for CarouselColorScheme {
             CarouselColorScheme::Neutral => write!(f, "carousel-neutral"),
             CarouselColorScheme::Primary => write!(f, "carousel-primary"),
             CarouselColorScheme::Secondary => write!(f, "carousel-secondary"),
+            CarouselColorScheme::Accent => write!(f, "carousel-accent"),
+            CarouselColorScheme::Info => write!(f, "carousel-info"),
+            CarouselColorScheme::Success => write!(f, "carousel-success"),
+            CarouselColorScheme::Warning => write!(f, "carousel-warning"),
+            CarouselColorScheme::Error => write!(f, "carousel-error"),
         }
     }
 }
 
 /// Size options for Carousel component
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
 pub enum CarouselSize {
     /// Small size
     Small,
@@ -62,6 +84,51 @@ impl Display for CarouselSize {
     }
 }
 
+/// Scroll-snap alignment options for Carousel component
+#[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum CarouselAlignment {
+    #[default]
+    /// Snap items to the start (default)
+    Start,
+    /// Snap items to the center
+    Center,
+    /// Snap items to the end
+    End,
+}
+
+impl Display for CarouselAlignment {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CarouselAlignment::Start => write!(f, "carousel-start"),
+            CarouselAlignment::Center => write!(f, "carousel-center"),
+            CarouselAlignment::End => write!(f, "carousel-end"),
+        }
+    }
+}
+
+/// Orientation options for Carousel component
+#[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum CarouselOrientation {
+    #[default]
+    /// Horizontal orientation (default)
+    Horizontal,
+    /// Vertical orientation
+    Vertical,
+}
+
+impl Display for CarouselOrientation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CarouselOrientation::Horizontal => write!(f, "carousel-horizontal"),
+            CarouselOrientation::Vertical => write!(f, "carousel-vertical"),
+        }
+    }
+}
+
 #[derive(Props, Clone, PartialEq)]
 pub struct CarouselProps {
     /// The content to display inside carousel (CarouselItem children)
@@ -74,10 +141,14 @@ pub struct CarouselProps {
     auto_play: Option<bool>,
     /// Auto-play interval in milliseconds
     interval: Option<u32>,
-    /// Show navigation buttons
+    /// Show previous/next buttons linking to the adjacent items, which need
+    /// an `index` (or matching `id`) to be found. With `state` they sit over
+    /// the carousel and follow it; without, each item carries its own pair
     show_nav: Option<bool>,
-    /// Show dot indicators
+    /// Show a row of buttons linking to each item, found the same way as for `show_nav`
     show_indicators: Option<bool>,
+    /// Number of items, for the nav and indicators (default: the `state`'s count)
+    item_count: Option<usize>,
     /// Infinite loop
     infinite: Option<bool>,
     /// Pause on hover
@@ -86,6 +157,15 @@ pub struct CarouselProps {
     color_scheme: Option<CarouselColorScheme>,
     /// Size of carousel
     size: Option<CarouselSize>,
+    /// Where items snap to while scrolling
+    alignment: Option<CarouselAlignment>,
+    /// Scroll direction of the carousel
+    orientation: Option<CarouselOrientation>,
+    /// State from `use_carousel`, driven by the keyboard handler
+    state: Option<UseCarousel>,
+    /// Move between items with ArrowLeft/ArrowRight while focused (needs
+    /// `state`, and items with an `index` to scroll to)
+    keyboard: Option<bool>,
 }
 
 #[component]
@@ -110,8 +190,16 @@ pub fn Carousel(props: CarouselProps) -> Element {
     if let Some(s) = size {
         classes.push(s.to_string());
     }
+
+    if let Some(alignment) = props.alignment {
+        classes.push(alignment.to_string());
+    }
+
+    if let Some(orientation) = props.orientation {
+        classes.push(orientation.to_string());
+    }
     
-    if auto_play.is_some() {
+    if auto_play.is_some() && !reduced_motion() {
         classes.push("carousel-auto".to_string());
     }
     
@@ -129,16 +217,226 @@ pub fn Carousel(props: CarouselProps) -> Element {
 
     let class_string = classes.join(" ");
 
+    let state = props.state;
+    let item_count = props.item_count.or(state.map(|state| state.item_count())).unwrap_or(0);
+    let active = state.map(|state| state.active()).unwrap_or(0);
+    let wrap = state.map(|state| state.wrap).unwrap_or(infinite.is_some());
+
+    // Without state nothing tracks the visible item, so each item links to
+    // its own neighbours instead of one pair of buttons for the carousel
+    let item_nav = (show_nav.is_some() && state.is_none() && item_count > 0)
+        .then_some(ItemNav { item_count, wrap });
+    let show_nav = show_nav.filter(|_| state.is_some());
+
+    // Provided on every render so items pick up a changed id
+    try_provide_context(CarouselContext {
+        id: props.id.clone(),
+        nav: item_nav,
+    });
+    let carousel_id = props.id.clone();
+
+    // Moving the state scrolls the active item into view, found by the id
+    // its `index` gives it
+    #[cfg(feature = "web")]
+    let scroll = state.map(|state| {
+        let slide = slide_id(carousel_id.as_deref(), state.active());
+        rsx!(ScrollToSlide { slide })
+    });
+    #[cfg(not(feature = "web"))]
+    let scroll: Option<Element> = None;
+
+    // Keyboard navigation makes the carousel focusable and drives its state
+    let carousel = if let Some(mut state) = state.filter(|_| props.keyboard.unwrap_or(false)) {
+        rsx!(
+            div {
+                class: "{class_string}",
+                id: props.id,
+                "data-interval": "{interval}",
+                tabindex: "0",
+                onkeydown: move |event: KeyboardEvent| {
+                    match event.key() {
+                        Key::ArrowRight => state.next(),
+                        Key::ArrowLeft => state.prev(),
+                        _ => return,
+                    }
+                    event.prevent_default();
+                },
+                {props.children}
+                {scroll}
+            }
+        )
+    } else {
+        rsx!(
+            div {
+                class: "{class_string}",
+                id: props.id,
+                "data-interval": "{interval}",
+                {props.children}
+                {scroll}
+            }
+        )
+    };
+
+    if item_count == 0 || (show_nav.is_none() && show_indicators.is_none()) {
+        return carousel;
+    }
+
+    let slide_href = |index: usize| format!("#{}", slide_id(carousel_id.as_deref(), index));
+    let prev_href = slide_href(carousel_index(active, -1, item_count, wrap));
+    let next_href = slide_href(carousel_index(active, 1, item_count, wrap));
+
+    // With state, the buttons also move the active item so the next click
+    // targets the right slide
+    let mut prev_listeners = vec![];
+    let mut next_listeners = vec![];
+    if let Some(mut state) = state {
+        prev_listeners.push(dioxus_elements::events::onclick(move |_| state.prev()));
+        next_listeners.push(dioxus_elements::events::onclick(move |_| state.next()));
+    }
+    let indicator_listeners = move |index: usize| {
+        let mut listeners = vec![];
+        if let Some(mut state) = state {
+            listeners.push(dioxus_elements::events::onclick(move |_| state.goto(index)));
+        }
+        listeners
+    };
+
     rsx!(
         div {
-            class: "{class_string}",
-            id: props.id,
-            "data-interval": "{interval}",
-            {props.children}
+            class: "relative",
+            {carousel}
+            if show_nav.is_some() {
+                div {
+                    class: "absolute left-5 right-5 top-1/2 flex -translate-y-1/2 justify-between",
+                    a {
+                        class: "carousel-prev btn btn-circle",
+                        href: "{prev_href}",
+                        aria_label: "Previous slide",
+                        ..prev_listeners,
+                        "❮"
+                    }
+                    a {
+                        class: "carousel-next btn btn-circle",
+                        href: "{next_href}",
+                        aria_label: "Next slide",
+                        ..next_listeners,
+                        "❯"
+                    }
+                }
+            }
+            if show_indicators.is_some() {
+                div {
+                    class: "flex w-full justify-center gap-2 py-2",
+                    for index in 0..item_count {
+                        a {
+                            key: "{index}",
+                            class: if index == active { "btn btn-xs btn-active" } else { "btn btn-xs" },
+                            href: slide_href(index),
+                            aria_label: "Go to slide {index + 1}",
+                            aria_current: (index == active).then_some("true"),
+                            ..indicator_listeners(index),
+                            "{index + 1}"
+                        }
+                    }
+                }
+            }
         }
     )
 }
 
+#[cfg(feature = "web")]
+#[derive(Props, Clone, PartialEq)]
+struct ScrollToSlideProps {
+    /// Id of the item to bring into view
+    slide: String,
+}
+
+/// Scrolls `slide` into view whenever it changes, but not on mount so the
+/// page doesn't jump to the carousel on load.
+#[cfg(feature = "web")]
+#[component]
+fn ScrollToSlide(props: ScrollToSlideProps) -> Element {
+    let mounted = use_hook(|| std::rc::Rc::new(std::cell::Cell::new(false)));
+    let smooth = !reduced_motion();
+    use_effect(use_reactive(&props.slide, move |slide| {
+        if mounted.replace(true) {
+            dom::scroll_into_view(&slide, smooth);
+        }
+    }));
+
+    rsx!()
+}
+
+#[cfg(feature = "web")]
+mod dom {
+    /// Scrolls the element with `id` into view within its carousel, without
+    /// moving the page more than needed.
+    pub(super) fn scroll_into_view(id: &str, smooth: bool) {
+        let element = web_sys::window()
+            .and_then(|window| window.document())
+            .and_then(|document| document.get_element_by_id(id));
+        if let Some(element) = element {
+            let options = web_sys::ScrollIntoViewOptions::new();
+            options.set_behavior(if smooth {
+                web_sys::ScrollBehavior::Smooth
+            } else {
+                web_sys::ScrollBehavior::Auto
+            });
+            options.set_block(web_sys::ScrollLogicalPosition::Nearest);
+            options.set_inline(web_sys::ScrollLogicalPosition::Nearest);
+            element.scroll_into_view_with_scroll_into_view_options(&options);
+        }
+    }
+}
+
+/// The id the nav and indicators link to for the item at `index`: `slide1`,
+/// `slide2`, ... or `gallery-slide1` inside a carousel with id `gallery`.
+pub(crate) fn slide_id(carousel_id: Option<&str>, index: usize) -> String {
+    match carousel_id.filter(|id| !id.is_empty()) {
+        Some(id) => format!("{id}-slide{}", index + 1),
+        None => format!("slide{}", index + 1),
+    }
+}
+
+/// Width options for CarouselItem, for showing several items at once
+#[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum CarouselItemWidth {
+    #[default]
+    /// One item per view
+    Full,
+    /// Two items per view
+    Half,
+    /// Three items per view
+    Third,
+}
+
+impl Display for CarouselItemWidth {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CarouselItemWidth::Full => write!(f, "w-full"),
+            CarouselItemWidth::Half => write!(f, "w-1/2"),
+            CarouselItemWidth::Third => write!(f, "w-1/3"),
+        }
+    }
+}
+
+/// The carousel's id, shared with its items so they can derive slide ids
+#[derive(Clone, Debug, PartialEq)]
+struct CarouselContext {
+    id: Option<String>,
+    /// Set when each item renders its own previous/next buttons
+    nav: Option<ItemNav>,
+}
+
+/// What an item needs to link to its neighbours
+#[derive(Copy, Clone, Debug, PartialEq)]
+struct ItemNav {
+    item_count: usize,
+    wrap: bool,
+}
+
 #[derive(Props, Clone, PartialEq)]
 pub struct CarouselItemProps {
     /// The content to display inside carousel item
@@ -149,6 +447,14 @@ pub struct CarouselItemProps {
     class: Option<String>,
     /// Whether this item is active
     active: Option<bool>,
+    /// Fixed width/height ratio such as `16/9`, reserving space before the media loads
+    aspect_ratio: Option<String>,
+    /// Position in the carousel (0-based). Without an `id`, the item gets the
+    /// id the parent's nav and indicators link to: `slide1`, or
+    /// `gallery-slide1` inside a carousel with id `gallery`
+    index: Option<usize>,
+    /// Share of the carousel's width the item takes up
+    width: Option<CarouselItemWidth>,
 }
 
 #[component]
@@ -156,28 +462,247 @@ pub fn CarouselItem(props: CarouselItemProps) -> Element {
     let class = props.class.unwrap_or_default();
     let active = props.active.filter(|&x| x);
 
+    // Inside a carousel that asked for per-item nav, link to the neighbours
+    let context = try_context::<CarouselContext>();
+    let carousel_id = context.as_ref().and_then(|context| context.id.clone());
+    let nav = context
+        .and_then(|context| context.nav)
+        .zip(props.index)
+        .map(|(nav, index)| {
+            let href = |delta| {
+                let target = carousel_index(index, delta, nav.item_count, nav.wrap);
+                format!("#{}", slide_id(carousel_id.as_deref(), target))
+            };
+            (href(-1), href(1))
+        });
+
     // Build CSS classes
     let mut classes = vec!["carousel-item".to_string()];
     
     if active.is_some() {
         classes.push("carousel-item-active".to_string());
     }
+
+    if let Some(width) = props.width {
+        classes.push(width.to_string());
+    }
+
+    if nav.is_some() {
+        classes.push("relative".to_string());
+    }
     
     if !class.is_empty() {
         classes.push(class);
     }
 
     let class_string = classes.join(" ");
+    let id = props
+        .id
+        .or_else(|| props.index.map(|index| slide_id(carousel_id.as_deref(), index)));
+    let style = props
+        .aspect_ratio
+        .filter(|ratio| !ratio.is_empty())
+        .map(|ratio| format!("aspect-ratio: {ratio}"));
 
     rsx!(
         div {
             class: "{class_string}",
-            id: props.id,
+            id,
+            style,
             {props.children}
+            if let Some((prev_href, next_href)) = nav {
+                div {
+                    class: "absolute left-5 right-5 top-1/2 flex -translate-y-1/2 justify-between",
+                    a {
+                        class: "carousel-prev btn btn-circle",
+                        href: "{prev_href}",
+                        aria_label: "Previous slide",
+                        "❮"
+                    }
+                    a {
+                        class: "carousel-next btn btn-circle",
+                        href: "{next_href}",
+                        aria_label: "Next slide",
+                        "❯"
+                    }
+                }
+            }
+        }
+    )
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct CarouselThumbnailsProps {
+    /// Image sources, one per carousel item
+    images: Vec<String>,
+    /// Optional ID for the thumbnail strip
+    id: Option<String>,
+    /// Additional CSS classes to apply to the thumbnail strip
+    class: Option<String>,
+    /// State from `use_carousel`, moved to the clicked item and used for the highlight
+    state: Option<UseCarousel>,
+    /// Index of the highlighted thumbnail when there is no `state`
+    active: Option<usize>,
+    /// Called with the index of the clicked thumbnail
+    onselect: Option<EventHandler<usize>>,
+}
+
+/// A strip of thumbnails that navigates a carousel.
+///
+/// ```text
+/// let carousel = use_carousel(images.len());
+///
+/// Carousel { state: carousel, ... }
+/// CarouselThumbnails { images: images.clone(), state: carousel }
+/// ```
+#[component]
+pub fn CarouselThumbnails(props: CarouselThumbnailsProps) -> Element {
+    let class = props.class.unwrap_or_default();
+    let state = props.state;
+    let onselect = props.onselect;
+    let active = state.map(|state| state.active()).or(props.active);
+
+    // Build CSS classes
+    let mut classes = vec!["flex justify-center gap-2 py-2".to_string()];
+
+    if !class.is_empty() {
+        classes.push(class);
+    }
+
+    let class_string = classes.join(" ");
+
+    rsx!(
+        div {
+            class: "{class_string}",
+            id: props.id,
+            for (index, src) in props.images.into_iter().enumerate() {
+                button {
+                    key: "{index}",
+                    r#type: "button",
+                    class: if active == Some(index) {
+                        "w-16 rounded-box overflow-hidden ring-2 ring-primary"
+                    } else {
+                        "w-16 rounded-box overflow-hidden opacity-60 hover:opacity-100"
+                    },
+                    aria_label: "Show slide {index + 1}",
+                    aria_current: (active == Some(index)).then_some("true"),
+                    onclick: move |_| {
+                        if let Some(mut state) = state {
+                            state.goto(index);
+                        }
+                        if let Some(handler) = onselect {
+                            handler.call(index);
+                        }
+                    },
+                    img { src: "{src}", alt: "" }
+                }
+            }
         }
     )
 }
 
+/// Carousel state returned by [`use_carousel`], for driving a `Carousel`
+/// from buttons, keyboard handlers or an auto-play timer.
+#[derive(Clone, Copy, PartialEq)]
+pub struct UseCarousel {
+    active: Signal<usize>,
+    resets: Signal<u64>,
+    item_count: usize,
+    wrap: bool,
+}
+
+impl UseCarousel {
+    /// Wrap from the last item to the first (and back) instead of stopping
+    pub fn wrap(mut self, wrap: bool) -> Self {
+        self.wrap = wrap;
+        self
+    }
+
+    /// Index of the active item
+    pub fn active(&self) -> usize {
+        (self.active)()
+    }
+
+    /// Number of items in the carousel
+    pub fn item_count(&self) -> usize {
+        self.item_count
+    }
+
+    /// Show the next item
+    pub fn next(&mut self) {
+        self.advance();
+        self.resets += 1;
+    }
+
+    /// Show the previous item
+    pub fn prev(&mut self) {
+        let index = carousel_index((self.active)(), -1, self.item_count, self.wrap);
+        self.active.set(index);
+        self.resets += 1;
+    }
+
+    /// Show the item at `index`, clamped to the last item
+    pub fn goto(&mut self, index: usize) {
+        self.active.set(index.min(self.item_count.saturating_sub(1)));
+        self.resets += 1;
+    }
+
+    // Moves forward without counting as manual navigation, for the auto-play timer
+    fn advance(&mut self) {
+        let current = *self.active.peek();
+        let index = carousel_index(current, 1, self.item_count, self.wrap);
+        self.active.set(index);
+    }
+}
+
+/// Index reached by moving `delta` items from `current`, either wrapping
+/// around or stopping at the ends.
+fn carousel_index(current: usize, delta: isize, item_count: usize, wrap: bool) -> usize {
+    if item_count == 0 {
+        return 0;
+    }
+    let target = current as isize + delta;
+    if wrap {
+        target.rem_euclid(item_count as isize) as usize
+    } else {
+        target.clamp(0, item_count as isize - 1) as usize
+    }
+}
+
+/// Tracks the active item of a carousel with `item_count` items, starting
+/// on the first one.
+pub fn use_carousel(item_count: usize) -> UseCarousel {
+    let active = use_signal(|| 0);
+    let resets = use_signal(|| 0);
+    UseCarousel {
+        active,
+        resets,
+        item_count,
+        wrap: false,
+    }
+}
+
+/// Advances `carousel` every `interval_ms`. Manual navigation through
+/// `next`, `prev` or `goto` restarts the interval. Under a reduced
+/// `MotionProvider` the carousel stays put, and picks up again once the
+/// preference changes back.
+pub fn use_carousel_auto_play(carousel: UseCarousel, interval_ms: u64) {
+    let preference = motion_preference();
+    use_resource(move || async move {
+        // Reading the preference restarts or stops this task when it changes
+        if preference.is_some_and(|preference| preference() == MotionPreference::Reduced) {
+            return;
+        }
+        let mut carousel = carousel;
+        // Reading the reset counter restarts this task on manual navigation
+        let _ = (carousel.resets)();
+        loop {
+            futures_timer::Delay::new(std::time::Duration::from_millis(interval_ms)).await;
+            carousel.advance();
+        }
+    });
+}
+
 #[test]
 fn test_carousel_basic() {
     let props = CarouselProps {
@@ -196,6 +721,11 @@ fn test_carousel_basic() {
         pause_on_hover: None,
         color_scheme: None,
         size: None,
+        state: None,
+        keyboard: None,
+        item_count: None,
+        alignment: None,
+        orientation: None,
     };
 
     let result = dioxus_ssr::render_element(Carousel(props));
@@ -209,6 +739,9 @@ fn test_carousel_item() {
         id: None,
         class: None,
         active: None,
+        aspect_ratio: None,
+        index: None,
+        width: None,
     };
 
     let result = dioxus_ssr::render_element(CarouselItem(props));
@@ -222,6 +755,9 @@ fn test_carousel_item_active() {
         id: None,
         class: None,
         active: Some(true),
+        aspect_ratio: None,
+        index: None,
+        width: None,
     };
 
     let result = dioxus_ssr::render_element(CarouselItem(props));
@@ -242,6 +778,11 @@ fn test_carousel_auto_play() {
         pause_on_hover: None,
         color_scheme: None,
         size: None,
+        state: None,
+        keyboard: None,
+        item_count: None,
+        alignment: None,
+        orientation: None,
     };
 
     let result = dioxus_ssr::render_element(Carousel(props));
@@ -250,23 +791,34 @@ fn test_carousel_auto_play() {
 
 #[test]
 fn test_carousel_with_nav() {
-    let props = CarouselProps {
-        children: rsx!(CarouselItem { children: rsx!(img { src: "/slide.jpg" }) }),
-        id: None,
-        class: None,
-        auto_play: None,
-        interval: None,
-        show_nav: Some(true),
-        show_indicators: None,
-        infinite: None,
-        pause_on_hover: None,
-        color_scheme: None,
-        size: None,
-    };
+    let result = dioxus_ssr::render_element(rsx!(
+        Carousel {
+            show_nav: true,
+            item_count: 3,
+            CarouselItem { index: 0, "Slide 1" }
+            CarouselItem { index: 1, "Slide 2" }
+            CarouselItem { index: 2, "Slide 3" }
+        }
+    ));
+    // Without state, each item links to its own neighbours
+    assert!(result.starts_with(r#"<div class="carousel""#));
+    assert!(result.contains(r#"<div class="carousel-item relative" id="slide2">Slide 2<div class="absolute"#));
+    assert!(result.contains(r##"<a class="carousel-prev btn btn-circle" href="#slide1" aria-label="Previous slide">"##));
+    assert!(result.contains(r##"<a class="carousel-next btn btn-circle" href="#slide3" aria-label="Next slide">"##));
+    assert_eq!(result.matches("carousel-prev").count(), 3);
+    // The ends stop there unless the carousel is infinite
+    assert!(result.contains(r##"<div class="carousel-item relative" id="slide3">Slide 3<div class="absolute left-5 right-5 top-1/2 flex -translate-y-1/2 justify-between"><a class="carousel-prev btn btn-circle" href="#slide2" aria-label="Previous slide">❮</a><a class="carousel-next btn btn-circle" href="#slide3""##));
 
-    let result = dioxus_ssr::render_element(Carousel(props));
-    // show_nav is a prop that can be used by CSS/JS, not rendered as element
-    assert!(result.contains("carousel"));
+    let result = dioxus_ssr::render_element(rsx!(
+        Carousel {
+            show_nav: true,
+            infinite: true,
+            item_count: 2,
+            CarouselItem { index: 0, "Slide 1" }
+            CarouselItem { index: 1, "Slide 2" }
+        }
+    ));
+    assert!(result.contains(r##"id="slide1">Slide 1<div class="absolute left-5 right-5 top-1/2 flex -translate-y-1/2 justify-between"><a class="carousel-prev btn btn-circle" href="#slide2""##));
 }
 
 #[test]
@@ -283,11 +835,17 @@ fn test_carousel_with_indicators() {
         pause_on_hover: None,
         color_scheme: None,
         size: None,
+        state: None,
+        keyboard: None,
+        item_count: Some(3),
+        alignment: None,
+        orientation: None,
     };
 
     let result = dioxus_ssr::render_element(Carousel(props));
-    // show_indicators is a prop that can be used by CSS/JS, not rendered as element
-    assert!(result.contains("carousel"));
+    assert_eq!(result.matches(r#"class="btn btn-xs"#).count(), 3);
+    assert!(result.contains(r##"<a class="btn btn-xs btn-active" href="#slide1" aria-label="Go to slide 1" aria-current="true">1</a>"##));
+    assert!(result.contains(r##"<a class="btn btn-xs" href="#slide3" aria-label="Go to slide 3">3</a>"##));
 }
 
 #[test]
@@ -304,6 +862,11 @@ fn test_carousel_infinite() {
         pause_on_hover: None,
         color_scheme: None,
         size: None,
+        state: None,
+        keyboard: None,
+        item_count: None,
+        alignment: None,
+        orientation: None,
     };
 
     let result = dioxus_ssr::render_element(Carousel(props));
@@ -324,6 +887,11 @@ fn test_carousel_pause_on_hover() {
         pause_on_hover: Some(true),
         color_scheme: None,
         size: None,
+        state: None,
+        keyboard: None,
+        item_count: None,
+        alignment: None,
+        orientation: None,
     };
 
     let result = dioxus_ssr::render_element(Carousel(props));
@@ -344,6 +912,11 @@ fn test_carousel_with_color_scheme() {
         pause_on_hover: None,
         color_scheme: Some(CarouselColorScheme::Primary),
         size: None,
+        state: None,
+        keyboard: None,
+        item_count: None,
+        alignment: None,
+        orientation: None,
     };
 
     let result = dioxus_ssr::render_element(Carousel(props));
@@ -364,6 +937,11 @@ fn test_carousel_with_size() {
         pause_on_hover: None,
         color_scheme: None,
         size: Some(CarouselSize::Large),
+        state: None,
+        keyboard: None,
+        item_count: None,
+        alignment: None,
+        orientation: None,
     };
 
     let result = dioxus_ssr::render_element(Carousel(props));
@@ -384,6 +962,11 @@ fn test_carousel_custom_class() {
         pause_on_hover: None,
         color_scheme: None,
         size: None,
+        state: None,
+        keyboard: None,
+        item_count: None,
+        alignment: None,
+        orientation: None,
     };
 
     let result = dioxus_ssr::render_element(Carousel(props));
@@ -404,8 +987,425 @@ fn test_carousel_with_id() {
         pause_on_hover: None,
         color_scheme: None,
         size: None,
+        state: None,
+        keyboard: None,
+        item_count: None,
+        alignment: None,
+        orientation: None,
     };
 
     let result = dioxus_ssr::render_element(Carousel(props));
     assert!(result.contains(r#"id="test-carousel""#));
 }
+
+#[test]
+fn test_carousel_alignment() {
+    for (alignment, class) in [
+        (CarouselAlignment::Start, "carousel carousel-start"),
+        (CarouselAlignment::Center, "carousel carousel-center"),
+        (CarouselAlignment::End, "carousel carousel-end"),
+    ] {
+        let props = CarouselProps {
+            children: rsx!(CarouselItem { children: rsx!(img { src: "/slide.jpg" }) }),
+            id: None,
+            class: None,
+            auto_play: None,
+            interval: None,
+            show_nav: None,
+            show_indicators: None,
+            infinite: None,
+            pause_on_hover: None,
+            color_scheme: None,
+            size: None,
+            state: None,
+            keyboard: None,
+            item_count: None,
+            alignment: Some(alignment),
+            orientation: None,
+        };
+
+        let result = dioxus_ssr::render_element(Carousel(props));
+        assert!(result.contains(&format!(r#"class="{class}""#)));
+    }
+}
+
+#[test]
+fn test_carousel_vertical() {
+    let props = CarouselProps {
+        children: rsx!(CarouselItem { children: rsx!(img { src: "/slide.jpg" }) }),
+        id: None,
+        class: Some("h-96".to_string()),
+        auto_play: None,
+        interval: None,
+        show_nav: None,
+        show_indicators: None,
+        infinite: None,
+        pause_on_hover: None,
+        color_scheme: None,
+        size: None,
+        state: None,
+        keyboard: None,
+        item_count: None,
+        alignment: Some(CarouselAlignment::Center),
+        orientation: Some(CarouselOrientation::Vertical),
+    };
+
+    let result = dioxus_ssr::render_element(Carousel(props));
+    assert!(result.contains(r#"class="carousel carousel-center carousel-vertical h-96""#));
+}
+
+#[test]
+fn test_carousel_index_wraps() {
+    assert_eq!(carousel_index(2, 1, 3, true), 0);
+    assert_eq!(carousel_index(0, -1, 3, true), 2);
+    assert_eq!(carousel_index(2, 1, 3, false), 2);
+    assert_eq!(carousel_index(0, -1, 3, false), 0);
+    assert_eq!(carousel_index(0, 1, 0, true), 0);
+}
+
+#[test]
+fn test_use_carousel_wrap_and_timer_reset() {
+    use crate::test_utils::{fire, listener, rebuild, run_for, TestMouseData};
+
+    fn app() -> Element {
+        let mut carousel = use_carousel(3).wrap(true);
+        use_carousel_auto_play(carousel, 200);
+        let resets = carousel.resets;
+        rsx!(
+            button { onclick: move |_| carousel.prev(), "Prev" }
+            button { onclick: move |_| carousel.next(), "Next" }
+            button { onclick: move |_| carousel.advance(), "Tick" }
+            "Slide {carousel.active()} reset {resets}"
+        )
+    }
+
+    let (mut dom, listeners) = rebuild(app);
+    let prev = listener(&listeners, "click", 0);
+    let next = listener(&listeners, "click", 1);
+    let tick = listener(&listeners, "click", 2);
+
+    fire(&mut dom, "click", prev, TestMouseData::default());
+    assert!(dioxus_ssr::render(&dom).contains("Slide 2 reset 1"));
+
+    fire(&mut dom, "click", next, TestMouseData::default());
+    assert!(dioxus_ssr::render(&dom).contains("Slide 0 reset 2"));
+
+    // Timer ticks advance without restarting the interval
+    fire(&mut dom, "click", tick, TestMouseData::default());
+    assert!(dioxus_ssr::render(&dom).contains("Slide 1 reset 2"));
+
+    // Manual navigation part way through an interval pushes the next
+    // auto-advance back to a full interval after the click
+    run_for(&mut dom, 120);
+    assert!(dioxus_ssr::render(&dom).contains("Slide 1 reset 2"));
+    fire(&mut dom, "click", next, TestMouseData::default());
+    assert!(dioxus_ssr::render(&dom).contains("Slide 2 reset 3"));
+
+    run_for(&mut dom, 120);
+    assert!(dioxus_ssr::render(&dom).contains("Slide 2 reset 3"));
+
+    run_for(&mut dom, 160);
+    assert!(dioxus_ssr::render(&dom).contains("Slide 0 reset 3"));
+}
+
+#[test]
+fn test_carousel_auto_play_advances_and_wraps() {
+    use crate::test_utils::{rebuild, run_for};
+
+    fn app() -> Element {
+        let carousel = use_carousel(3).wrap(true);
+        use_carousel_auto_play(carousel, 20);
+        rsx!("Slide {carousel.active()}")
+    }
+
+    let (mut dom, _) = rebuild(app);
+    assert!(dioxus_ssr::render(&dom).contains("Slide 0"));
+
+    // Wait for each tick in turn, the last one wrapping back to the start
+    for expected in ["Slide 1", "Slide 2", "Slide 0"] {
+        let mut waited = 0;
+        while !dioxus_ssr::render(&dom).contains(expected) {
+            assert!(waited < 1000, "auto-play never reached {expected}");
+            run_for(&mut dom, 1);
+            waited += 1;
+        }
+    }
+}
+
+#[test]
+fn test_carousel_keyboard_navigation() {
+    use crate::test_utils::{fire, listener, rebuild, TestKeyboardData};
+
+    fn app() -> Element {
+        let carousel = use_carousel(3);
+        rsx!(
+            Carousel {
+                state: carousel,
+                keyboard: true,
+                CarouselItem { "Slide 1" }
+                CarouselItem { "Slide 2" }
+                CarouselItem { "Slide 3" }
+            }
+            "Active {carousel.active()}"
+        )
+    }
+
+    let (mut dom, listeners) = rebuild(app);
+    let carousel = listener(&listeners, "keydown", 0);
+    assert!(dioxus_ssr::render(&dom).contains(r#"tabindex="0""#));
+
+    fire(&mut dom, "keydown", carousel, TestKeyboardData::new(Key::ArrowRight));
+    fire(&mut dom, "keydown", carousel, TestKeyboardData::new(Key::ArrowRight));
+    assert!(dioxus_ssr::render(&dom).contains("Active 2"));
+
+    fire(&mut dom, "keydown", carousel, TestKeyboardData::new(Key::ArrowLeft));
+    assert!(dioxus_ssr::render(&dom).contains("Active 1"));
+
+    fire(&mut dom, "keydown", carousel, TestKeyboardData::new(Key::Enter));
+    assert!(dioxus_ssr::render(&dom).contains("Active 1"));
+}
+
+#[test]
+fn test_carousel_thumbnails_select() {
+    use crate::test_utils::{fire, listener, rebuild, TestMouseData};
+
+    fn app() -> Element {
+        let carousel = use_carousel(3);
+        let mut selected = use_signal(|| None::<usize>);
+        rsx!(
+            CarouselThumbnails {
+                images: vec!["/1.jpg".to_string(), "/2.jpg".to_string(), "/3.jpg".to_string()],
+                state: carousel,
+                onselect: move |index| selected.set(Some(index)),
+            }
+            "Active {carousel.active()} selected {selected():?}"
+        )
+    }
+
+    let (mut dom, listeners) = rebuild(app);
+    assert!(dioxus_ssr::render(&dom).contains(
+        r#"class="w-16 rounded-box overflow-hidden ring-2 ring-primary" aria-label="Show slide 1""#
+    ));
+
+    fire(&mut dom, "click", listener(&listeners, "click", 2), TestMouseData::default());
+    let result = dioxus_ssr::render(&dom);
+    assert!(result.contains("Active 2 selected Some(2)"));
+    assert!(result.contains(
+        r#"class="w-16 rounded-box overflow-hidden ring-2 ring-primary" aria-label="Show slide 3" aria-current="true""#
+    ));
+    assert!(result.contains(
+        r#"class="w-16 rounded-box overflow-hidden opacity-60 hover:opacity-100" aria-label="Show slide 1""#
+    ));
+}
+
+#[test]
+fn test_carousel_item_aspect_ratio() {
+    let props = CarouselItemProps {
+        children: rsx!(img { src: "/slide.jpg", alt: "Slide" }),
+        id: None,
+        class: None,
+        active: None,
+        aspect_ratio: Some("16/9".to_string()),
+        index: None,
+        width: None,
+    };
+
+    let result = dioxus_ssr::render_element(CarouselItem(props));
+    assert!(result.contains(r#"<div class="carousel-item" style="aspect-ratio: 16/9">"#));
+}
+
+#[test]
+fn test_carousel_nav_follows_state() {
+    use crate::test_utils::{fire, listener, rebuild, TestMouseData};
+
+    fn app() -> Element {
+        let carousel = use_carousel(3);
+        rsx!(
+            Carousel {
+                id: "gallery",
+                state: carousel,
+                show_nav: true,
+                show_indicators: true,
+                CarouselItem { "Slide 1" }
+                CarouselItem { "Slide 2" }
+                CarouselItem { "Slide 3" }
+            }
+        )
+    }
+
+    let (mut dom, listeners) = rebuild(app);
+    let next = listener(&listeners, "click", 1);
+    assert!(dioxus_ssr::render(&dom).contains(r##"href="#gallery-slide2" aria-label="Next slide""##));
+
+    fire(&mut dom, "click", next, TestMouseData::default());
+    let result = dioxus_ssr::render(&dom);
+    assert!(result.contains(r##"href="#gallery-slide1" aria-label="Previous slide""##));
+    assert!(result.contains(r##"href="#gallery-slide3" aria-label="Next slide""##));
+    assert!(result.contains(r##"class="btn btn-xs btn-active" href="#gallery-slide2""##));
+}
+
+#[test]
+fn test_carousel_item_width() {
+    for (width, class) in [
+        (CarouselItemWidth::Full, "carousel-item w-full"),
+        (CarouselItemWidth::Half, "carousel-item w-1/2"),
+        (CarouselItemWidth::Third, "carousel-item w-1/3"),
+    ] {
+        let props = CarouselItemProps {
+            children: rsx!(img { src: "/slide.jpg", alt: "Slide" }),
+            id: None,
+            class: None,
+            active: None,
+            aspect_ratio: None,
+            index: None,
+            width: Some(width),
+        };
+
+        let result = dioxus_ssr::render_element(CarouselItem(props));
+        assert!(result.contains(&format!(r#"class="{class}""#)));
+    }
+}
+
+#[test]
+fn test_carousel_item_slide_id() {
+    let props = CarouselItemProps {
+        children: rsx!(img { src: "/slide.jpg", alt: "Slide" }),
+        id: None,
+        class: None,
+        active: None,
+        aspect_ratio: None,
+        index: Some(1),
+        width: None,
+    };
+    let result = dioxus_ssr::render_element(CarouselItem(props));
+    assert!(result.contains(r#"id="slide2""#));
+
+    let result = dioxus_ssr::render_element(rsx!(
+        Carousel {
+            id: "gallery",
+            show_indicators: true,
+            item_count: 2,
+            CarouselItem { index: 0, "Slide 1" }
+            CarouselItem { index: 1, id: "custom", "Slide 2" }
+        }
+    ));
+    assert!(result.contains(r#"<div class="carousel-item" id="gallery-slide1">"#));
+    assert!(result.contains(r#"<div class="carousel-item" id="custom">"#));
+    assert!(result.contains(r##"href="#gallery-slide2""##));
+}
+
+#[test]
+fn test_carousel_status_color_schemes() {
+    let schemes = [
+        (CarouselColorScheme::Accent, "carousel-accent"),
+        (CarouselColorScheme::Info, "carousel-info"),
+        (CarouselColorScheme::Success, "carousel-success"),
+        (CarouselColorScheme::Warning, "carousel-warning"),
+        (CarouselColorScheme::Error, "carousel-error"),
+    ];
+
+    for (scheme, class) in schemes {
+        assert_eq!(scheme.to_string(), class);
+    }
+}
+
+#[test]
+fn test_carousel_auto_play_skips_reduced_motion() {
+    use crate::motion::{MotionPreference, MotionProvider};
+    use crate::test_utils::{rebuild, run_for};
+
+    fn Slides() -> Element {
+        let carousel = use_carousel(3).wrap(true);
+        use_carousel_auto_play(carousel, 5);
+        rsx!(span { "Active: {carousel.active()}" })
+    }
+
+    fn app() -> Element {
+        rsx!(
+            MotionProvider { preference: MotionPreference::Reduced, Slides {} }
+        )
+    }
+
+    let (mut dom, _) = rebuild(app);
+    run_for(&mut dom, 30);
+    assert!(dioxus_ssr::render(&dom).contains("Active: 0</span>"));
+}
+
+#[test]
+fn test_carousel_auto_play_follows_motion_preference() {
+    use crate::motion::MotionProvider;
+    use crate::test_utils::{fire, listener, rebuild, run_for, TestMouseData};
+
+    fn Slides() -> Element {
+        let carousel = use_carousel(3).wrap(true);
+        use_carousel_auto_play(carousel, 20);
+        rsx!(span { "Active: {carousel.active()}" })
+    }
+
+    fn app() -> Element {
+        let mut reduced = use_signal(|| true);
+        let preference = if reduced() { MotionPreference::Reduced } else { MotionPreference::Full };
+        rsx!(
+            button { onclick: move |_| reduced.toggle(), "Toggle motion" }
+            MotionProvider { preference, Slides {} }
+        )
+    }
+
+    let (mut dom, listeners) = rebuild(app);
+    let toggle = listener(&listeners, "click", 0);
+    run_for(&mut dom, 60);
+    assert!(dioxus_ssr::render(&dom).contains("Active: 0</span>"));
+
+    // Turning motion back on starts the timer
+    fire(&mut dom, "click", toggle, TestMouseData::default());
+    run_for(&mut dom, 30);
+    let result = dioxus_ssr::render(&dom);
+    assert!(!result.contains("Active: 0</span>"));
+
+    // Reducing motion again stops it where it is
+    fire(&mut dom, "click", toggle, TestMouseData::default());
+    run_for(&mut dom, 10);
+    let stopped = dioxus_ssr::render(&dom);
+    run_for(&mut dom, 60);
+    assert_eq!(dioxus_ssr::render(&dom), stopped);
+}
+
+#[cfg(all(feature = "web", target_arch = "wasm32"))]
+#[wasm_bindgen_test::wasm_bindgen_test]
+async fn test_carousel_keyboard_scrolls_active_slide() {
+    use crate::motion::{MotionPreference, MotionProvider};
+    use crate::test_utils::{dispatch_key, mount, settle};
+
+    fn app() -> Element {
+        let carousel = use_carousel(3);
+        rsx!(
+            // No daisyUI stylesheet in the test page, so lay the slides out in a row here
+            style { ".carousel {{ display: flex; overflow-x: auto; width: 100px; }} .carousel-item {{ flex: none; width: 100px; }}" }
+            MotionProvider {
+                preference: MotionPreference::Reduced,
+                Carousel {
+                    id: "gallery",
+                    state: carousel,
+                    keyboard: true,
+                    CarouselItem { index: 0, "Slide 1" }
+                    CarouselItem { index: 1, "Slide 2" }
+                    CarouselItem { index: 2, "Slide 3" }
+                }
+            }
+        )
+    }
+
+    let _root = mount(app).await;
+    let document = web_sys::window().unwrap().document().unwrap();
+    let carousel = document.get_element_by_id("gallery").unwrap();
+    assert_eq!(carousel.scroll_left(), 0);
+
+    dispatch_key(&carousel, "ArrowRight", false);
+    dispatch_key(&carousel, "ArrowRight", false);
+    settle().await;
+    assert_eq!(carousel.scroll_left(), 200);
+
+    dispatch_key(&carousel, "ArrowLeft", false);
+    settle().await;
+    assert_eq!(carousel.scroll_left(), 100);
+}
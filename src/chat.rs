@@ -23,6 +23,8 @@ use dioxus::prelude::*;
 
 /// Color options for ChatBubble component
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
 pub enum ChatBubbleColor {
     /// Primary color
     Primary,
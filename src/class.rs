@@ -0,0 +1,80 @@
+use std::fmt::Display;
+
+/// Assembles a component's `class` attribute. Empty classes are skipped and
+/// repeated tokens are kept once, so a user passing `class: "btn"` to a
+/// button doesn't end up with `btn btn`.
+///
+/// ```text
+/// let class_string = ClassBuilder::new("table")
+///     .push_opt(size)
+///     .push_if(zebra, "table-zebra")
+///     .push(class)
+///     .build();
+/// ```
+#[derive(Clone, Debug, Default)]
+pub(crate) struct ClassBuilder {
+    classes: Vec<String>,
+}
+
+impl ClassBuilder {
+    /// Starts with the component's base class, e.g. `btn`
+    pub(crate) fn new(base: impl Display) -> Self {
+        let mut builder = Self::default();
+        builder.push(base);
+        builder
+    }
+
+    /// Adds a class, which may hold several space-separated tokens
+    pub(crate) fn push(&mut self, class: impl Display) -> &mut Self {
+        self.classes.push(class.to_string());
+        self
+    }
+
+    /// Adds `class` when `condition` holds
+    pub(crate) fn push_if(&mut self, condition: bool, class: impl Display) -> &mut Self {
+        if condition {
+            self.push(class);
+        }
+        self
+    }
+
+    /// Adds the class when there is one
+    pub(crate) fn push_opt(&mut self, class: Option<impl Display>) -> &mut Self {
+        if let Some(class) = class {
+            self.push(class);
+        }
+        self
+    }
+
+    /// The classes joined by spaces, without empty or repeated tokens
+    pub(crate) fn build(&self) -> String {
+        let mut tokens: Vec<&str> = vec![];
+        for token in self.classes.iter().flat_map(|class| class.split_whitespace()) {
+            if !tokens.contains(&token) {
+                tokens.push(token);
+            }
+        }
+        tokens.join(" ")
+    }
+}
+
+#[test]
+fn test_class_builder_skips_empty() {
+    let class_string = ClassBuilder::new("progress")
+        .push("")
+        .push_if(false, "progress-indeterminate")
+        .push_if(true, "progress-lg")
+        .push_opt(None::<String>)
+        .push_opt(Some("w-full"))
+        .build();
+    assert_eq!(class_string, "progress progress-lg w-full");
+}
+
+#[test]
+fn test_class_builder_dedups_tokens() {
+    let class_string = ClassBuilder::new("btn")
+        .push("btn-primary")
+        .push("btn mt-2 btn-primary")
+        .build();
+    assert_eq!(class_string, "btn btn-primary mt-2");
+}
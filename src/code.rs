@@ -19,6 +19,8 @@ use dioxus::prelude::*;
 
 /// Type options for Code component
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
 pub enum CodeType {
     /// Inline code
     Inline,
@@ -35,6 +37,60 @@ impl Display for CodeType {
     }
 }
 
+/// Color scheme options for inline Code
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum CodeColorScheme {
+    /// Neutral color
+    Neutral,
+    /// Primary color
+    Primary,
+    /// Secondary color
+    Secondary,
+    /// Accent color
+    Accent,
+    /// Info color
+    Info,
+    /// Success color
+    Success,
+    /// Warning color
+    Warning,
+    /// Error color
+    Error,
+}
+
+impl Display for CodeColorScheme {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CodeColorScheme::Neutral => write!(f, "text-neutral"),
+            CodeColorScheme::Primary => write!(f, "text-primary"),
+            CodeColorScheme::Secondary => write!(f, "text-secondary"),
+            CodeColorScheme::Accent => write!(f, "text-accent"),
+            CodeColorScheme::Info => write!(f, "text-info"),
+            CodeColorScheme::Success => write!(f, "text-success"),
+            CodeColorScheme::Warning => write!(f, "text-warning"),
+            CodeColorScheme::Error => write!(f, "text-error"),
+        }
+    }
+}
+
+impl CodeColorScheme {
+    /// Badge color class used when inline code is rendered as a badge
+    fn badge_class(&self) -> &'static str {
+        match self {
+            CodeColorScheme::Neutral => "badge-neutral",
+            CodeColorScheme::Primary => "badge-primary",
+            CodeColorScheme::Secondary => "badge-secondary",
+            CodeColorScheme::Accent => "badge-accent",
+            CodeColorScheme::Info => "badge-info",
+            CodeColorScheme::Success => "badge-success",
+            CodeColorScheme::Warning => "badge-warning",
+            CodeColorScheme::Error => "badge-error",
+        }
+    }
+}
+
 #[derive(Props, Clone, PartialEq)]
 pub struct CodeProps {
     /// The content to display inside code
@@ -45,6 +101,18 @@ pub struct CodeProps {
     class: Option<String>,
     /// Type of code (inline or block)
     r#type: Option<CodeType>,
+    /// Filename shown in a header bar above block code
+    filename: Option<String>,
+    /// Color scheme for inline code
+    color_scheme: Option<CodeColorScheme>,
+    /// Whether inline code is rendered as a badge
+    badge_style: Option<bool>,
+    /// Lines of block code, rendered as one row per line ahead of any children
+    lines: Option<Vec<String>>,
+    /// Whether to prefix each line of block code with its line number
+    line_numbers: Option<bool>,
+    /// Line numbers (1-indexed) of block code to highlight
+    highlight_lines: Option<Vec<usize>>,
 }
 
 #[component]
@@ -54,7 +122,20 @@ pub fn Code(props: CodeProps) -> Element {
 
     if code_type == CodeType::Inline {
         // Inline code - use code element
+        let badge_style = props.badge_style.filter(|&x| x);
         let mut classes = vec![];
+
+        if badge_style.is_some() {
+            classes.push("badge".to_string());
+        }
+
+        if let Some(color) = props.color_scheme {
+            if badge_style.is_some() {
+                classes.push(color.badge_class().to_string());
+            } else {
+                classes.push(color.to_string());
+            }
+        }
         
         if !class.is_empty() {
             classes.push(class);
@@ -79,13 +160,48 @@ pub fn Code(props: CodeProps) -> Element {
 
         let class_string = classes.join(" ");
 
-        rsx!(
-            pre {
-                class: "{class_string}",
-                id: props.id,
-                {props.children}
+        let header = props.filename.map(|filename| rsx!(
+            div {
+                class: "code-filename bg-base-300 rounded-t-box px-4 py-2 font-mono text-sm",
+                "{filename}"
             }
-        )
+        ));
+
+        if let Some(lines) = props.lines {
+            // Line based block code - mockup-code expects one pre per line
+            let line_numbers = props.line_numbers.filter(|&x| x);
+            let highlight_lines = props.highlight_lines.unwrap_or_default();
+
+            rsx!(
+                {header}
+                div {
+                    class: "{class_string}",
+                    id: props.id,
+                    {lines.iter().enumerate().map(|(i, line)| {
+                        let number = i + 1;
+                        let prefix = line_numbers.map(|_| number.to_string());
+                        let highlight = highlight_lines.contains(&number).then_some("bg-warning/20");
+                        rsx!(
+                            pre {
+                                class: highlight,
+                                "data-prefix": prefix,
+                                code { "{line}" }
+                            }
+                        )
+                    })}
+                    {props.children}
+                }
+            )
+        } else {
+            rsx!(
+                {header}
+                pre {
+                    class: "{class_string}",
+                    id: props.id,
+                    {props.children}
+                }
+            )
+        }
     }
 }
 
@@ -96,6 +212,12 @@ fn test_code_inline() {
         id: None,
         class: None,
         r#type: Some(CodeType::Inline),
+        filename: None,
+        color_scheme: None,
+        badge_style: None,
+        lines: None,
+        line_numbers: None,
+        highlight_lines: None,
     };
 
     let result = dioxus_ssr::render_element(Code(props));
@@ -109,6 +231,12 @@ fn test_code_block() {
         id: None,
         class: None,
         r#type: Some(CodeType::Block),
+        filename: None,
+        color_scheme: None,
+        badge_style: None,
+        lines: None,
+        line_numbers: None,
+        highlight_lines: None,
     };
 
     let result = dioxus_ssr::render_element(Code(props));
@@ -122,6 +250,12 @@ fn test_code_custom_class() {
         id: None,
         class: Some("custom-class".to_string()),
         r#type: Some(CodeType::Inline),
+        filename: None,
+        color_scheme: None,
+        badge_style: None,
+        lines: None,
+        line_numbers: None,
+        highlight_lines: None,
     };
 
     let result = dioxus_ssr::render_element(Code(props));
@@ -135,6 +269,12 @@ fn test_code_with_id() {
         id: Some("test-code".to_string()),
         class: None,
         r#type: Some(CodeType::Inline),
+        filename: None,
+        color_scheme: None,
+        badge_style: None,
+        lines: None,
+        line_numbers: None,
+        highlight_lines: None,
     };
 
     let result = dioxus_ssr::render_element(Code(props));
@@ -148,8 +288,99 @@ fn test_code_block_with_custom_class() {
         id: None,
         class: Some("custom-class".to_string()),
         r#type: Some(CodeType::Block),
+        filename: None,
+        color_scheme: None,
+        badge_style: None,
+        lines: None,
+        line_numbers: None,
+        highlight_lines: None,
     };
 
     let result = dioxus_ssr::render_element(Code(props));
     assert!(result.contains(r#"class="mockup-code custom-class""#));
 }
+
+#[test]
+fn test_code_block_with_filename() {
+    let props = CodeProps {
+        children: rsx!("const x = 1;"),
+        id: None,
+        class: None,
+        r#type: Some(CodeType::Block),
+        filename: Some("main.rs".to_string()),
+        color_scheme: None,
+        badge_style: None,
+        lines: None,
+        line_numbers: None,
+        highlight_lines: None,
+    };
+
+    let result = dioxus_ssr::render_element(Code(props));
+    let header = result.find(">main.rs</div>").expect("filename header should render");
+    let code = result.find(r#"<pre class="mockup-code""#).expect("code block should render");
+    assert!(header < code);
+}
+
+#[test]
+fn test_code_inline_color_scheme() {
+    let props = CodeProps {
+        children: rsx!("cargo build"),
+        id: None,
+        class: None,
+        r#type: Some(CodeType::Inline),
+        filename: None,
+        color_scheme: Some(CodeColorScheme::Primary),
+        badge_style: None,
+        lines: None,
+        line_numbers: None,
+        highlight_lines: None,
+    };
+
+    let result = dioxus_ssr::render_element(Code(props));
+    assert!(result.contains(r#"<code class="text-primary">"#));
+}
+
+#[test]
+fn test_code_inline_badge_style() {
+    let props = CodeProps {
+        children: rsx!("cargo build"),
+        id: None,
+        class: None,
+        r#type: Some(CodeType::Inline),
+        filename: None,
+        color_scheme: Some(CodeColorScheme::Accent),
+        badge_style: Some(true),
+        lines: None,
+        line_numbers: None,
+        highlight_lines: None,
+    };
+
+    let result = dioxus_ssr::render_element(Code(props));
+    assert!(result.contains(r#"<code class="badge badge-accent">"#));
+}
+
+#[test]
+fn test_code_block_highlight_lines() {
+    let props = CodeProps {
+        children: rsx!(),
+        id: None,
+        class: None,
+        r#type: Some(CodeType::Block),
+        filename: None,
+        color_scheme: None,
+        badge_style: None,
+        lines: Some(vec![
+            "cargo new app".to_string(),
+            "cd app".to_string(),
+            "cargo run".to_string(),
+        ]),
+        line_numbers: Some(true),
+        highlight_lines: Some(vec![2]),
+    };
+
+    let result = dioxus_ssr::render_element(Code(props));
+    assert!(result.contains(r#"<div class="mockup-code">"#));
+    assert!(result.contains(r#"<pre data-prefix="1"><code>cargo new app</code></pre>"#));
+    assert!(result.contains(r#"<pre class="bg-warning/20" data-prefix="2"><code>cd app</code></pre>"#));
+    assert!(result.contains(r#"<pre data-prefix="3"><code>cargo run</code></pre>"#));
+}
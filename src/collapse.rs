@@ -59,6 +59,10 @@ pub struct CollapseTitleProps {
     id: Option<String>,
     /// Additional CSS classes to apply to collapse title
     class: Option<String>,
+    /// Whether the collapse is open (emitted as aria-expanded)
+    open: Option<bool>,
+    /// ID of the CollapseContent this title controls (emitted as aria-controls)
+    content_id: Option<String>,
 }
 
 #[component]
@@ -66,6 +70,7 @@ pub fn CollapseTitle(props: CollapseTitleProps) -> Element {
     let class = props.class.unwrap_or_default();
 
     // Build CSS classes
+    let expanded = props.open.map(|open| open.to_string());
     let mut classes = vec!["collapse-title".to_string()];
     
     if !class.is_empty() {
@@ -78,6 +83,8 @@ pub fn CollapseTitle(props: CollapseTitleProps) -> Element {
         div {
             class: "{class_string}",
             id: props.id,
+            "aria-expanded": expanded,
+            "aria-controls": props.content_id,
             {props.children}
         }
     )
@@ -136,6 +143,8 @@ fn test_collapse_title() {
         children: rsx!("Title"),
         id: None,
         class: None,
+        open: None,
+        content_id: None,
     };
 
     let result = dioxus_ssr::render_element(CollapseTitle(props));
@@ -190,6 +199,8 @@ fn test_collapse_title_with_id() {
         children: rsx!("Title"),
         id: Some("test-title".to_string()),
         class: None,
+        open: None,
+        content_id: None,
     };
 
     let result = dioxus_ssr::render_element(CollapseTitle(props));
@@ -207,3 +218,35 @@ fn test_collapse_content_with_id() {
     let result = dioxus_ssr::render_element(CollapseContent(props));
     assert!(result.contains(r#"id="test-content""#));
 }
+
+#[test]
+fn test_collapse_title_aria() {
+    let content = CollapseContentProps {
+        children: rsx!("Content"),
+        id: Some("faq-1".to_string()),
+        class: None,
+    };
+    let content_result = dioxus_ssr::render_element(CollapseContent(content));
+    assert!(content_result.contains(r#"id="faq-1""#));
+
+    let open = CollapseTitleProps {
+        children: rsx!("Title"),
+        id: None,
+        class: None,
+        open: Some(true),
+        content_id: Some("faq-1".to_string()),
+    };
+    let result = dioxus_ssr::render_element(CollapseTitle(open));
+    assert!(result.contains(r#"aria-expanded="true""#));
+    assert!(result.contains(r#"aria-controls="faq-1""#));
+
+    let closed = CollapseTitleProps {
+        children: rsx!("Title"),
+        id: None,
+        class: None,
+        open: Some(false),
+        content_id: Some("faq-1".to_string()),
+    };
+    let result = dioxus_ssr::render_element(CollapseTitle(closed));
+    assert!(result.contains(r#"aria-expanded="false""#));
+}
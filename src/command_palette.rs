@@ -0,0 +1,412 @@
+#![allow(non_snake_case)]
+use dioxus::prelude::*;
+
+use crate::input::{Input, InputSize, InputType};
+use crate::menu::{Menu, MenuItem};
+use crate::modal::Modal;
+
+/// A CommandPalette component: a searchable list of commands in a modal,
+/// opened with a keyboard shortcut.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```text
+/// use daisy_rsx::{CommandPalette, PaletteCommand};
+///
+/// CommandPalette {
+///     id: "command-palette",
+///     shortcut: "Ctrl+K",
+///     commands: vec![
+///         PaletteCommand::new("new-file", "New file"),
+///         PaletteCommand::new("settings", "Open settings"),
+///     ],
+///     onselect: move |id: String| run_command(&id),
+/// }
+/// ```
+
+/// A single entry in a CommandPalette
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PaletteCommand {
+    /// Identifier passed to `onselect`
+    pub id: String,
+    /// Text shown in the list and matched against the search query
+    pub label: String,
+}
+
+impl PaletteCommand {
+    pub fn new(id: impl Into<String>, label: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            label: label.into(),
+        }
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct CommandPaletteProps {
+    /// ID of the underlying modal
+    id: String,
+    /// Commands to choose from
+    commands: Vec<PaletteCommand>,
+    /// Called with the id of the chosen command
+    onselect: EventHandler<String>,
+    /// Shortcut that opens the palette, e.g. `Ctrl+Shift+P` (default: `Ctrl+K`, Cmd+K on macOS).
+    /// Listening for it needs the `web` feature
+    shortcut: Option<String>,
+    /// Placeholder text for the search input
+    placeholder: Option<String>,
+    /// Open state, for opening the palette from elsewhere
+    open: Option<Signal<bool>>,
+    /// Additional CSS classes to apply to the modal
+    class: Option<String>,
+}
+
+/// Whether a key press matches a shortcut like `Ctrl+K`. `Ctrl` also accepts
+/// the Meta (Cmd) key so the same shortcut works on macOS.
+#[cfg(any(feature = "web", test))]
+fn shortcut_matches(shortcut: &str, key: &str, modifiers: Modifiers) -> bool {
+    let mut parts: Vec<&str> = shortcut.split('+').map(str::trim).collect();
+    let Some(target) = parts.pop() else {
+        return false;
+    };
+    let (mut ctrl, mut shift, mut alt) = (false, false, false);
+    for part in parts {
+        match part.to_ascii_lowercase().as_str() {
+            "ctrl" | "control" | "cmd" | "meta" => ctrl = true,
+            "shift" => shift = true,
+            "alt" | "option" => alt = true,
+            _ => return false,
+        }
+    }
+    key.eq_ignore_ascii_case(target)
+        && ctrl == (modifiers.ctrl() || modifiers.meta())
+        && shift == modifiers.shift()
+        && alt == modifiers.alt()
+}
+
+/// Commands whose label contains `query`, ignoring case.
+fn filter_commands(commands: &[PaletteCommand], query: &str) -> Vec<PaletteCommand> {
+    let query = query.trim().to_lowercase();
+    commands
+        .iter()
+        .filter(|command| command.label.to_lowercase().contains(&query))
+        .cloned()
+        .collect()
+}
+
+#[component]
+pub fn CommandPalette(props: CommandPaletteProps) -> Element {
+    let placeholder = props.placeholder.unwrap_or_else(|| "Type a command...".to_string());
+    let onselect = props.onselect;
+
+    let local_open = use_signal(|| false);
+    let mut open = props.open.unwrap_or(local_open);
+    let mut query = use_signal(String::new);
+    let mut highlighted = use_signal(|| 0usize);
+
+    // Re-registered whenever the shortcut prop changes
+    #[cfg(feature = "web")]
+    {
+        let shortcut = props.shortcut.unwrap_or_else(|| "Ctrl+K".to_string());
+        let listener = use_hook(|| std::rc::Rc::new(dom::ShortcutSlot::default()));
+        listener.listen(shortcut, move || open.set(true));
+    }
+
+    let mut reset = move || {
+        query.set(String::new());
+        highlighted.set(0);
+    };
+    let mut select = move |id: String| {
+        open.set(false);
+        reset();
+        onselect.call(id);
+    };
+
+    let filtered = filter_commands(&props.commands, &query());
+    let ids: Vec<String> = filtered.iter().map(|command| command.id.clone()).collect();
+    let onkeydown = move |event: KeyboardEvent| match event.key() {
+        Key::ArrowDown => {
+            event.prevent_default();
+            highlighted.set((highlighted() + 1).min(ids.len().saturating_sub(1)));
+        }
+        Key::ArrowUp => {
+            event.prevent_default();
+            highlighted.set(highlighted().saturating_sub(1));
+        }
+        Key::Enter => {
+            event.prevent_default();
+            if let Some(id) = ids.get(highlighted()) {
+                select(id.clone());
+            }
+        }
+        _ => {}
+    };
+
+    rsx!(
+        Modal {
+            trigger_id: props.id,
+            class: props.class,
+            open,
+            onclose: move |_| reset(),
+            div {
+                class: "modal-box p-0",
+                div {
+                    class: "p-4",
+                    Input {
+                        input_type: InputType::Search,
+                        input_size: InputSize::Medium,
+                        name: "command",
+                        class: "w-full",
+                        placeholder: placeholder.clone(),
+                        aria_label: placeholder,
+                        value: query(),
+                        autofocus: true,
+                        oninput: move |value| {
+                            query.set(value);
+                            highlighted.set(0);
+                        },
+                        onkeydown,
+                    }
+                }
+                Menu {
+                    class: "w-full",
+                    if filtered.is_empty() {
+                        MenuItem { class: "disabled", "No matching commands" }
+                    }
+                    for (index, command) in filtered.into_iter().enumerate() {
+                        MenuItem {
+                            key: "{command.id}",
+                            active: index == highlighted(),
+                            a {
+                                onclick: move |_| select(command.id.clone()),
+                                "{command.label}"
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    )
+}
+
+#[cfg(feature = "web")]
+mod dom {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use dioxus::dioxus_core::Runtime;
+    use dioxus::prelude::*;
+    use wasm_bindgen::closure::Closure;
+    use wasm_bindgen::JsCast;
+
+    /// Document-level keydown listener, removed again when dropped.
+    pub(super) struct ShortcutListener {
+        closure: Closure<dyn FnMut(web_sys::KeyboardEvent)>,
+    }
+
+    impl ShortcutListener {
+        pub(super) fn new(shortcut: String, mut onmatch: impl FnMut() + 'static) -> Self {
+            // The browser calls back outside of Dioxus, so re-enter the
+            // component's scope before touching its signals
+            let runtime: Rc<Runtime> = Runtime::current();
+            let scope = runtime.current_scope_id();
+            let closure = Closure::<dyn FnMut(web_sys::KeyboardEvent)>::new(
+                move |event: web_sys::KeyboardEvent| {
+                    let mut modifiers = Modifiers::empty();
+                    modifiers.set(Modifiers::CONTROL, event.ctrl_key());
+                    modifiers.set(Modifiers::META, event.meta_key());
+                    modifiers.set(Modifiers::SHIFT, event.shift_key());
+                    modifiers.set(Modifiers::ALT, event.alt_key());
+                    if super::shortcut_matches(&shortcut, &event.key(), modifiers) {
+                        event.prevent_default();
+                        runtime.in_scope(scope, &mut onmatch);
+                    }
+                },
+            );
+            if let Some(document) = web_sys::window().and_then(|window| window.document()) {
+                let _ = document
+                    .add_event_listener_with_callback("keydown", closure.as_ref().unchecked_ref());
+            }
+            Self { closure }
+        }
+    }
+
+    /// The listener for the current shortcut, replaced when the shortcut changes.
+    #[derive(Default)]
+    pub(super) struct ShortcutSlot(RefCell<Option<(String, ShortcutListener)>>);
+
+    impl ShortcutSlot {
+        pub(super) fn listen(&self, shortcut: String, onmatch: impl FnMut() + 'static) {
+            let mut current = self.0.borrow_mut();
+            if current.as_ref().is_some_and(|(listening, _)| *listening == shortcut) {
+                return;
+            }
+            // Dropping the old listener removes it from the document
+            current.take();
+            let listener = ShortcutListener::new(shortcut.clone(), onmatch);
+            *current = Some((shortcut, listener));
+        }
+    }
+
+    impl Drop for ShortcutListener {
+        fn drop(&mut self) {
+            if let Some(document) = web_sys::window().and_then(|window| window.document()) {
+                let _ = document.remove_event_listener_with_callback(
+                    "keydown",
+                    self.closure.as_ref().unchecked_ref(),
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_command_palette_shortcut_matches() {
+    assert!(shortcut_matches("Ctrl+K", "k", Modifiers::CONTROL));
+    assert!(shortcut_matches("Ctrl+K", "k", Modifiers::META));
+    assert!(shortcut_matches("Ctrl+Shift+P", "P", Modifiers::CONTROL | Modifiers::SHIFT));
+    assert!(!shortcut_matches("Ctrl+K", "k", Modifiers::empty()));
+    assert!(!shortcut_matches("Ctrl+K", "k", Modifiers::CONTROL | Modifiers::SHIFT));
+    assert!(!shortcut_matches("Ctrl+K", "j", Modifiers::CONTROL));
+}
+
+#[test]
+fn test_command_palette_filter() {
+    let commands = vec![
+        PaletteCommand::new("new-file", "New file"),
+        PaletteCommand::new("settings", "Open settings"),
+    ];
+
+    let filtered = filter_commands(&commands, "SET");
+    assert_eq!(filtered, vec![PaletteCommand::new("settings", "Open settings")]);
+    assert_eq!(filter_commands(&commands, "").len(), 2);
+}
+
+// With `web` on, mounting registers a document listener, which needs a browser
+#[cfg(not(feature = "web"))]
+#[test]
+fn test_command_palette_select_filtered_command() {
+    use crate::test_utils::{fire, listener, rebuild, TestFormData, TestKeyboardData};
+
+    fn app() -> Element {
+        let open = use_signal(|| true);
+        let mut selected = use_signal(String::new);
+        rsx!(
+            CommandPalette {
+                id: "palette",
+                open,
+                commands: vec![
+                    PaletteCommand::new("new-file", "New file"),
+                    PaletteCommand::new("settings", "Open settings"),
+                    PaletteCommand::new("theme", "Toggle theme"),
+                ],
+                onselect: move |id| selected.set(id),
+            }
+            "Selected: {selected}"
+        )
+    }
+
+    let (mut dom, listeners) = rebuild(app);
+    assert!(dioxus_ssr::render(&dom).contains("modal modal-open"));
+
+    fire(
+        &mut dom,
+        "input",
+        listener(&listeners, "input", 0),
+        TestFormData { value: "sett".to_string() },
+    );
+    let result = dioxus_ssr::render(&dom);
+    assert!(result.contains("Open settings"));
+    assert!(!result.contains("New file"));
+
+    // The first keydown listener belongs to the modal itself
+    fire(
+        &mut dom,
+        "keydown",
+        listener(&listeners, "keydown", 1),
+        TestKeyboardData::new(Key::Enter),
+    );
+    let result = dioxus_ssr::render(&dom);
+    assert!(result.contains("Selected: settings"));
+    assert!(!result.contains("modal-open"));
+}
+
+#[cfg(all(feature = "web", target_arch = "wasm32"))]
+#[wasm_bindgen_test::wasm_bindgen_test]
+async fn test_command_palette_opens_on_shortcut() {
+    use crate::test_utils::{dispatch_input, dispatch_key, mount, settle};
+    use wasm_bindgen::JsCast;
+
+    fn app() -> Element {
+        let mut selected = use_signal(String::new);
+        rsx!(
+            CommandPalette {
+                id: "shortcut-palette",
+                commands: vec![
+                    PaletteCommand::new("new-file", "New file"),
+                    PaletteCommand::new("settings", "Open settings"),
+                ],
+                onselect: move |id| selected.set(id),
+            }
+            span { "Selected: {selected}" }
+        )
+    }
+
+    let root = mount(app).await;
+    let document = web_sys::window().unwrap().document().unwrap();
+    let dialog = document.get_element_by_id("shortcut-palette").unwrap();
+    assert!(!dialog.class_name().contains("modal-open"));
+
+    dispatch_key(&document, "k", true);
+    settle().await;
+    assert!(dialog.class_name().contains("modal-open"));
+
+    let search: web_sys::HtmlInputElement =
+        dialog.query_selector("input").unwrap().unwrap().dyn_into().unwrap();
+    search.set_value("sett");
+    dispatch_input(&search);
+    settle().await;
+    dispatch_key(&search, "Enter", false);
+    settle().await;
+
+    assert!(root.text_content().unwrap().contains("Selected: settings"));
+    assert!(!dialog.class_name().contains("modal-open"));
+}
+
+#[cfg(all(feature = "web", target_arch = "wasm32"))]
+#[wasm_bindgen_test::wasm_bindgen_test]
+async fn test_command_palette_follows_shortcut_prop() {
+    use crate::test_utils::{dispatch_key, mount, settle};
+    use wasm_bindgen::JsCast;
+
+    fn app() -> Element {
+        let mut shortcut = use_signal(|| "Ctrl+K".to_string());
+        rsx!(
+            button { id: "rebind", onclick: move |_| shortcut.set("Ctrl+J".to_string()), "Rebind" }
+            CommandPalette {
+                id: "rebound-palette",
+                shortcut: shortcut(),
+                commands: vec![PaletteCommand::new("settings", "Open settings")],
+                onselect: move |_| {},
+            }
+        )
+    }
+
+    let _root = mount(app).await;
+    let document = web_sys::window().unwrap().document().unwrap();
+    let dialog = document.get_element_by_id("rebound-palette").unwrap();
+    let rebind = document.get_element_by_id("rebind").unwrap();
+    rebind.unchecked_ref::<web_sys::HtmlElement>().click();
+    settle().await;
+
+    // The old shortcut no longer opens the palette, the new one does
+    dispatch_key(&document, "k", true);
+    settle().await;
+    assert!(!dialog.class_name().contains("modal-open"));
+
+    dispatch_key(&document, "j", true);
+    settle().await;
+    assert!(dialog.class_name().contains("modal-open"));
+}
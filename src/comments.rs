@@ -31,6 +31,8 @@ use dioxus::prelude::*;
 
 /// Color scheme options for Comments component
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
 pub enum CommentsColorScheme {
     /// Neutral color
     Neutral,
@@ -38,6 +40,16 @@ pub enum CommentsColorScheme {
     Primary,
     /// Secondary color
     Secondary,
+    /// Accent color
+    Accent,
+    /// Info color
+    Info,
+    /// Success color
+    Success,
+    /// Warning color
+    Warning,
+    /// Error color
+    Error,
 }
 
 impl Display for CommentsColorScheme {
@@ -46,12 +58,19 @@ impl Display for CommentsColorScheme {
             CommentsColorScheme::Neutral => write!(f, "chat-neutral"),
             CommentsColorScheme::Primary => write!(f, "chat-primary"),
             CommentsColorScheme::Secondary => write!(f, "chat-secondary"),
+            CommentsColorScheme::Accent => write!(f, "chat-accent"),
+            CommentsColorScheme::Info => write!(f, "chat-info"),
+            CommentsColorScheme::Success => write!(f, "chat-success"),
+            CommentsColorScheme::Warning => write!(f, "chat-warning"),
+            CommentsColorScheme::Error => write!(f, "chat-error"),
         }
     }
 }
 
 /// Size options for Comments component
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
 pub enum CommentsSize {
     /// Small size
     Small,
@@ -381,3 +400,18 @@ fn test_comments_custom_class() {
     let result = dioxus_ssr::render_element(Comments(props));
     assert!(result.contains("chat") && result.contains("custom-class"));
 }
+
+#[test]
+fn test_comments_status_color_schemes() {
+    let schemes = [
+        (CommentsColorScheme::Accent, "chat-accent"),
+        (CommentsColorScheme::Info, "chat-info"),
+        (CommentsColorScheme::Success, "chat-success"),
+        (CommentsColorScheme::Warning, "chat-warning"),
+        (CommentsColorScheme::Error, "chat-error"),
+    ];
+
+    for (scheme, class) in schemes {
+        assert_eq!(scheme.to_string(), class);
+    }
+}
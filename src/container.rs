@@ -0,0 +1,93 @@
+#![allow(non_snake_case)]
+use dioxus::prelude::*;
+
+/// A Container component for centering page content with a max width.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```text
+/// use daisy_rsx::Container;
+///
+/// Container {
+///     max_width: "5xl".to_string(),
+///     padded: true,
+///     children: rsx!(
+///         div { "Page content" }
+///     )
+/// }
+/// ```
+
+#[derive(Props, Clone, PartialEq)]
+pub struct ContainerProps {
+    /// The content to display inside the container
+    children: Element,
+    /// Optional ID for container element
+    id: Option<String>,
+    /// Additional CSS classes to apply to container
+    class: Option<String>,
+    /// Maximum width, from `sm` to `7xl` (emitted as `max-w-*`)
+    max_width: Option<String>,
+    /// Whether to add horizontal padding
+    padded: Option<bool>,
+}
+
+#[component]
+pub fn Container(props: ContainerProps) -> Element {
+    let class = props.class.unwrap_or_default();
+    let padded = props.padded.filter(|&x| x);
+
+    // Build CSS classes
+    let mut classes = vec!["container".to_string(), "mx-auto".to_string()];
+
+    if let Some(max_width) = props.max_width.filter(|w| !w.is_empty()) {
+        classes.push(format!("max-w-{}", max_width));
+    }
+
+    if padded.is_some() {
+        classes.push("px-4".to_string());
+    }
+
+    if !class.is_empty() {
+        classes.push(class);
+    }
+
+    let class_string = classes.join(" ");
+
+    rsx!(
+        div {
+            class: "{class_string}",
+            id: props.id,
+            {props.children}
+        }
+    )
+}
+
+#[test]
+fn test_container_basic() {
+    let props = ContainerProps {
+        children: rsx!(div { "Content" }),
+        id: None,
+        class: None,
+        max_width: None,
+        padded: None,
+    };
+
+    let result = dioxus_ssr::render_element(Container(props));
+    assert!(result.contains(r#"class="container mx-auto""#));
+}
+
+#[test]
+fn test_container_max_width_padded() {
+    let props = ContainerProps {
+        children: rsx!(div { "Content" }),
+        id: None,
+        class: None,
+        max_width: Some("7xl".to_string()),
+        padded: Some(true),
+    };
+
+    let result = dioxus_ssr::render_element(Container(props));
+    assert!(result.contains(r#"class="container mx-auto max-w-7xl px-4""#));
+}
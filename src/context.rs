@@ -0,0 +1,18 @@
+use dioxus::dioxus_core::Runtime;
+use dioxus::prelude::*;
+
+/// Reads a context value provided by an ancestor, if any. Components are also
+/// rendered directly outside a runtime (e.g. in SSR tests), where there is no
+/// context to read, so that case yields `None` instead of panicking.
+pub(crate) fn try_context<T: Clone + 'static>() -> Option<T> {
+    Runtime::try_current()?;
+    try_consume_context::<T>()
+}
+
+/// Provides a context value to descendants. Like [`try_context`], this is a
+/// no-op outside a runtime, where there are no descendants to read it.
+pub(crate) fn try_provide_context<T: Clone + 'static>(value: T) {
+    if Runtime::try_current().is_some() {
+        provide_context(value);
+    }
+}
@@ -0,0 +1,24 @@
+/// Wraps `url` in a CSS `url('...')` value, escaping the characters that
+/// would otherwise end the string or the declaration early.
+pub(crate) fn css_url(url: &str) -> String {
+    let mut escaped = String::with_capacity(url.len());
+    for c in url.chars() {
+        match c {
+            '\\' | '\'' | '"' | '(' | ')' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            '\n' => escaped.push_str("\\a "),
+            '\r' => {}
+            _ => escaped.push(c),
+        }
+    }
+    format!("url('{}')", escaped)
+}
+
+#[test]
+fn test_css_url_escapes_quotes() {
+    assert_eq!(css_url("/hero-bg.jpg"), "url('/hero-bg.jpg')");
+    assert_eq!(css_url("/it's.jpg"), r"url('/it\'s.jpg')");
+    assert_eq!(css_url("x');color:red"), r"url('x\'\);color:red')");
+}
@@ -0,0 +1,92 @@
+#![allow(non_snake_case)]
+use dioxus::prelude::*;
+
+use crate::context::try_context;
+
+/// A DensityProvider sets how tightly `Menu`, `Table` and `Card` lay out their
+/// content for everything inside it.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```text
+/// use daisy_rsx::{Density, DensityProvider, Menu, MenuItem};
+///
+/// DensityProvider {
+///     density: Density::Compact,
+///     Menu {
+///         MenuItem { href: "/home", "Home" }
+///     }
+/// }
+/// ```
+
+/// Spacing options shared by Menu, Table and Card
+#[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum Density {
+    #[default]
+    /// Default spacing
+    Comfortable,
+    /// Smaller sizes and padding
+    Compact,
+}
+
+impl Density {
+    /// The size class for a component, e.g. `table-xs`, when compact
+    pub(crate) fn class(&self, compact: &str) -> Option<String> {
+        match self {
+            Density::Comfortable => None,
+            Density::Compact => Some(compact.to_string()),
+        }
+    }
+}
+
+/// The density set by the nearest `DensityProvider`, or the default when
+/// there is none or the component is rendered outside a runtime.
+pub(crate) fn current_density() -> Density {
+    try_context::<Density>().unwrap_or_default()
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct DensityProviderProps {
+    /// The content that picks up the density
+    children: Element,
+    /// Density for menus, tables and cards inside this provider
+    density: Density,
+}
+
+#[component]
+pub fn DensityProvider(props: DensityProviderProps) -> Element {
+    // Provided on every render so a changed density reaches the children
+    provide_context(props.density);
+
+    rsx!({ props.children })
+}
+
+#[test]
+fn test_density_compact_children() {
+    use crate::menu::{Menu, MenuItem};
+    use crate::table::Table;
+
+    let result = dioxus_ssr::render_element(rsx!(
+        DensityProvider {
+            density: Density::Compact,
+            Menu { MenuItem { "Home" } }
+            Table { tbody { tr { td { "Data" } } } }
+        }
+    ));
+    assert!(result.contains(r#"class="menu menu-vertical menu-sm""#));
+    assert!(result.contains(r#"class="table table-xs""#));
+}
+
+#[test]
+fn test_density_defaults_to_comfortable() {
+    use crate::table::Table;
+
+    let result = dioxus_ssr::render_element(rsx!(
+        Table { tbody { tr { td { "Data" } } } }
+    ));
+    assert!(result.contains(r#"class="table""#));
+}
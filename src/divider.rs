@@ -16,9 +16,20 @@ use dioxus::prelude::*;
 ///     children: rsx!("Or")
 /// }
 /// ```
+///
+/// Dashed line:
+///
+/// ```text
+/// Divider {
+///     style: DividerStyle::Dashed,
+///     children: rsx!()
+/// }
+/// ```
 
 /// Orientation options for Divider component
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
 pub enum DividerOrientation {
     /// Horizontal orientation
     Horizontal,
@@ -35,6 +46,42 @@ impl Display for DividerOrientation {
     }
 }
 
+/// Line style options for Divider component
+#[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum DividerStyle {
+    #[default]
+    /// Solid line (default)
+    Solid,
+    /// Dashed line
+    Dashed,
+    /// Dotted line
+    Dotted,
+}
+
+impl DividerStyle {
+    /// daisyUI draws the line as a filled `::before`/`::after`, so a dashed or
+    /// dotted line swaps that fill for a border. `vertical` is for lines that
+    /// run top to bottom, i.e. with `divider-horizontal`.
+    fn class(&self, vertical: bool) -> Option<String> {
+        let border_style = match self {
+            DividerStyle::Solid => return None,
+            DividerStyle::Dashed => "border-dashed",
+            DividerStyle::Dotted => "border-dotted",
+        };
+        let (size, border) = if vertical { ("w-0", "border-l-2") } else { ("h-0", "border-t-2") };
+        let classes: Vec<String> = ["before", "after"]
+            .iter()
+            .flat_map(|side| {
+                [size, "bg-transparent", border, border_style, "border-base-content/10"]
+                    .map(|class| format!("{side}:{class}"))
+            })
+            .collect();
+        Some(classes.join(" "))
+    }
+}
+
 #[derive(Props, Clone, PartialEq)]
 pub struct DividerProps {
     /// The content to display inside divider (optional text)
@@ -45,6 +92,8 @@ pub struct DividerProps {
     class: Option<String>,
     /// Orientation of divider (horizontal or vertical)
     orientation: Option<DividerOrientation>,
+    /// Line style of divider (solid, dashed or dotted)
+    style: Option<DividerStyle>,
 }
 
 #[component]
@@ -58,6 +107,11 @@ pub fn Divider(props: DividerProps) -> Element {
     if let Some(orient) = orientation {
         classes.push(orient.to_string());
     }
+
+    let vertical = orientation == Some(DividerOrientation::Horizontal);
+    if let Some(style) = props.style.and_then(|style| style.class(vertical)) {
+        classes.push(style);
+    }
     
     if !class.is_empty() {
         classes.push(class);
@@ -74,6 +128,48 @@ pub fn Divider(props: DividerProps) -> Element {
     )
 }
 
+#[derive(Props, Clone, PartialEq)]
+pub struct DividerIconProps {
+    /// The icon (and optional text) centered on the divider line
+    children: Element,
+    /// Optional ID for divider element
+    id: Option<String>,
+    /// Additional CSS classes to apply to divider
+    class: Option<String>,
+    /// Orientation of divider (horizontal or vertical)
+    orientation: Option<DividerOrientation>,
+}
+
+#[component]
+pub fn DividerIcon(props: DividerIconProps) -> Element {
+    let class = props.class.unwrap_or_default();
+    let orientation = props.orientation;
+
+    // Build CSS classes
+    let mut classes = vec!["divider".to_string()];
+
+    if let Some(orient) = orientation {
+        classes.push(orient.to_string());
+    }
+
+    if !class.is_empty() {
+        classes.push(class);
+    }
+
+    let class_string = classes.join(" ");
+
+    rsx!(
+        div {
+            class: "{class_string}",
+            id: props.id,
+            span {
+                class: "flex shrink-0 items-center justify-center gap-2",
+                {props.children}
+            }
+        }
+    )
+}
+
 #[test]
 fn test_divider_basic() {
     let props = DividerProps {
@@ -81,6 +177,7 @@ fn test_divider_basic() {
         id: None,
         class: None,
         orientation: None,
+        style: None,
     };
 
     let result = dioxus_ssr::render_element(Divider(props));
@@ -94,6 +191,7 @@ fn test_divider_horizontal() {
         id: None,
         class: None,
         orientation: Some(DividerOrientation::Horizontal),
+        style: None,
     };
 
     let result = dioxus_ssr::render_element(Divider(props));
@@ -107,6 +205,7 @@ fn test_divider_vertical() {
         id: None,
         class: None,
         orientation: Some(DividerOrientation::Vertical),
+        style: None,
     };
 
     let result = dioxus_ssr::render_element(Divider(props));
@@ -120,6 +219,7 @@ fn test_divider_custom_class() {
         id: None,
         class: Some("custom-class".to_string()),
         orientation: None,
+        style: None,
     };
 
     let result = dioxus_ssr::render_element(Divider(props));
@@ -133,6 +233,7 @@ fn test_divider_with_id() {
         id: Some("test-divider".to_string()),
         class: None,
         orientation: None,
+        style: None,
     };
 
     let result = dioxus_ssr::render_element(Divider(props));
@@ -146,8 +247,52 @@ fn test_divider_empty() {
         id: None,
         class: None,
         orientation: None,
+        style: None,
     };
 
     let result = dioxus_ssr::render_element(Divider(props));
     assert!(result.contains(r#"class="divider""#));
 }
+
+#[test]
+fn test_divider_styles() {
+    let props = |style, orientation| DividerProps {
+        children: rsx!(),
+        id: None,
+        class: None,
+        orientation,
+        style: Some(style),
+    };
+
+    let solid = dioxus_ssr::render_element(Divider(props(DividerStyle::Solid, None)));
+    assert!(solid.contains(r#"class="divider""#));
+
+    // The line's fill is swapped for a top border on both pseudo-elements
+    let dashed = dioxus_ssr::render_element(Divider(props(DividerStyle::Dashed, None)));
+    assert!(dashed.contains(
+        "before:h-0 before:bg-transparent before:border-t-2 before:border-dashed before:border-base-content/10 \
+         after:h-0 after:bg-transparent after:border-t-2 after:border-dashed after:border-base-content/10"
+    ));
+
+    // divider-horizontal lines run top to bottom, so they use a left border
+    let dotted = dioxus_ssr::render_element(Divider(props(
+        DividerStyle::Dotted,
+        Some(DividerOrientation::Horizontal),
+    )));
+    assert!(dotted.contains("divider-horizontal before:w-0 before:bg-transparent before:border-l-2 before:border-dotted"));
+    assert!(dotted.contains("after:border-l-2 after:border-dotted"));
+    assert!(!dotted.contains("border-t-2"));
+}
+
+#[test]
+fn test_divider_icon() {
+    let props = DividerIconProps {
+        children: rsx!(svg { class: "h-4 w-4" } "or"),
+        id: None,
+        class: None,
+        orientation: None,
+    };
+
+    let result = dioxus_ssr::render_element(DividerIcon(props));
+    assert!(result.contains(r#"<div class="divider"><span class="flex shrink-0 items-center justify-center gap-2"><svg class="h-4 w-4"></svg>or</span></div>"#));
+}
@@ -4,6 +4,8 @@ use std::fmt::Display;
 use dioxus::prelude::*;
 
 #[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
 pub enum Direction {
     #[default]
     None,
@@ -34,14 +36,17 @@ pub struct DropDownProps {
     direction: Option<Direction>,
     prefix_image_src: Option<String>,
     suffix_image_src: Option<String>,
+    /// Open the dropdown on hover as well as on focus
+    hover: Option<bool>,
 }
 
 #[component]
 pub fn DropDown(props: DropDownProps) -> Element {
     let direction = props.direction.unwrap_or_default();
+    let hover = if props.hover.unwrap_or(false) { "dropdown-hover" } else { "" };
 
     rsx!(
-        div { class: "dropdown {props.class.clone().unwrap_or_default()} {direction}",
+        div { class: "dropdown {props.class.clone().unwrap_or_default()} {direction} {hover}",
             label {
                 tabindex: "0",
                 class: "btn btn-default btn-sm m-1 w-full flex flex-nowrap justify-between",
@@ -103,3 +108,20 @@ pub fn DropDownLink(props: DropDownLinkProps) -> Element {
         )
     }
 }
+
+#[test]
+fn test_drop_down_hover() {
+    let props = DropDownProps {
+        children: rsx!(DropDownLink { href: "/profile", children: rsx!("Profile") }),
+        carat: None,
+        button_text: "Account".to_string(),
+        class: None,
+        direction: Some(Direction::Bottom),
+        prefix_image_src: None,
+        suffix_image_src: None,
+        hover: Some(true),
+    };
+
+    let result = dioxus_ssr::render_element(DropDown(props));
+    assert!(result.contains(r#"class="dropdown  dropdown-bottom dropdown-hover""#));
+}
@@ -1,9 +1,14 @@
 #![allow(non_snake_case)]
 use std::fmt::Display;
 
+use dioxus::html::FileData;
 use dioxus::prelude::*;
 
+use crate::progress::{Progress, ProgressColorScheme};
+
 #[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
 pub enum FileInputStyle {
     #[default]
     Default,
@@ -20,6 +25,8 @@ impl Display for FileInputStyle {
 }
 
 #[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
 pub enum FileInputColor {
     #[default]
     Default,
@@ -50,6 +57,8 @@ impl Display for FileInputColor {
 }
 
 #[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
 pub enum FileInputSize {
     #[default]
     Md,
@@ -83,6 +92,7 @@ pub struct FileInputProps {
     file_input_style: Option<FileInputStyle>,
     file_input_color: Option<FileInputColor>,
     file_input_size: Option<FileInputSize>,
+    onchange: Option<EventHandler<FormEvent>>,
 }
 
 #[component]
@@ -93,6 +103,11 @@ pub fn FileInput(props: FileInputProps) -> Element {
     let class = props.class.unwrap_or_default();
     let disabled = props.disabled.filter(|&d| d);
 
+    let mut listeners = vec![];
+    if let Some(handler) = props.onchange {
+        listeners.push(dioxus_elements::events::onchange(move |event| handler.call(event)));
+    }
+
     rsx!(
         input {
             "type": "file",
@@ -103,6 +118,56 @@ pub fn FileInput(props: FileInputProps) -> Element {
             required: props.required,
             disabled,
             class: "file-input {class} {style} {color} {size}",
+            ..listeners,
+        }
+    )
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct FileUploadProps {
+    class: Option<String>,
+    id: Option<String>,
+    name: Option<String>,
+    accept: Option<String>,
+    multiple: Option<bool>,
+    disabled: Option<bool>,
+    file_input_color: Option<FileInputColor>,
+    /// Upload completion (0-100); the progress bar is hidden while this is None
+    progress: Option<f64>,
+    progress_color: Option<ProgressColorScheme>,
+    /// Called with the chosen files
+    onchange: Option<EventHandler<Vec<FileData>>>,
+}
+
+/// A `FileInput` with a `Progress` bar underneath for the upload.
+#[component]
+pub fn FileUpload(props: FileUploadProps) -> Element {
+    let class = props.class.unwrap_or_default();
+    let onchange = props.onchange;
+
+    rsx!(
+        div {
+            class: "flex flex-col gap-2 {class}",
+            FileInput {
+                id: props.id,
+                name: props.name,
+                accept: props.accept,
+                multiple: props.multiple,
+                disabled: props.disabled,
+                file_input_color: props.file_input_color,
+                onchange: move |event: FormEvent| {
+                    if let Some(handler) = onchange {
+                        handler.call(event.files());
+                    }
+                },
+            }
+            if let Some(progress) = props.progress {
+                Progress {
+                    class: "w-full",
+                    value: progress,
+                    color_scheme: props.progress_color,
+                }
+            }
         }
     )
 }
@@ -124,6 +189,7 @@ mod tests {
             file_input_style: Some(FileInputStyle::Ghost),
             file_input_color: Some(FileInputColor::Primary),
             file_input_size: Some(FileInputSize::Lg),
+            onchange: None,
         };
 
         let result = dioxus_ssr::render_element(FileInput(props));
@@ -147,9 +213,39 @@ mod tests {
             file_input_style: None,
             file_input_color: None,
             file_input_size: None,
+            onchange: None,
         };
 
         let result = dioxus_ssr::render_element(FileInput(props));
         assert!(result.contains("file-input-md"));
     }
+
+    #[test]
+    fn test_file_upload_progress() {
+        use crate::test_utils::{fire, listener, rebuild, TestFormData};
+
+        fn app() -> Element {
+            let mut progress = use_signal(|| None::<f64>);
+            let mut selected = use_signal(|| None::<usize>);
+            rsx!(
+                FileUpload {
+                    progress: progress(),
+                    onchange: move |files: Vec<FileData>| {
+                        selected.set(Some(files.len()));
+                        progress.set(Some(40.0));
+                    },
+                }
+                "Selected: {selected():?}"
+            )
+        }
+
+        let (mut dom, listeners) = rebuild(app);
+        assert!(!dioxus_ssr::render(&dom).contains("<progress"));
+
+        fire(&mut dom, "change", listener(&listeners, "change", 0), TestFormData::default());
+        let result = dioxus_ssr::render(&dom);
+        assert!(result.contains("Selected: Some(0)"));
+        assert!(result.contains(r#"class="progress progress-primary w-full""#));
+        assert!(result.contains(r#"value="40""#));
+    }
 }
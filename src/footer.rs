@@ -2,6 +2,9 @@
 use std::fmt::Display;
 use dioxus::prelude::*;
 
+use crate::button_ui::{ButtonUI, ButtonUIType};
+use crate::input::{Input, InputType};
+
 /// A Footer component for website footers with links, social icons, and branding.
 ///
 /// # Examples
@@ -24,6 +27,8 @@ use dioxus::prelude::*;
 
 /// Color scheme options for Footer component
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
 pub enum FooterColorScheme {
     /// Neutral color
     Neutral,
@@ -45,6 +50,8 @@ impl Display for FooterColorScheme {
 
 /// Size options for Footer component
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
 pub enum FooterSize {
     /// Small size
     Small,
@@ -243,6 +250,129 @@ pub fn FooterCopyright(props: FooterCopyrightProps) -> Element {
     )
 }
 
+#[derive(Props, Clone, PartialEq)]
+pub struct FooterBackToTopProps {
+    /// Link text (default: "Back to top")
+    label: Option<String>,
+    /// Optional ID for the link element
+    id: Option<String>,
+    /// Additional CSS classes to apply to the link
+    class: Option<String>,
+}
+
+/// Scrolls the window back to the top, returning whether it did. With the
+/// `web` feature the link scrolls directly instead of jumping to `#top`.
+#[cfg(feature = "web")]
+fn scroll_to_top() -> bool {
+    match web_sys::window() {
+        Some(window) => {
+            window.scroll_to_with_x_and_y(0.0, 0.0);
+            true
+        }
+        None => false,
+    }
+}
+
+// Without a browser the `#top` anchor does the work
+#[cfg(not(feature = "web"))]
+fn scroll_to_top() -> bool {
+    false
+}
+
+/// A ghost button linking back to the top of the page. Without the `web`
+/// feature it relies on an element with `id="top"`.
+#[component]
+pub fn FooterBackToTop(props: FooterBackToTopProps) -> Element {
+    let class = props.class.unwrap_or_default();
+    let label = props.label.unwrap_or_else(|| "Back to top".to_string());
+
+    // Build CSS classes
+    let mut classes = vec!["btn".to_string(), "btn-ghost".to_string(), "btn-sm".to_string()];
+
+    if !class.is_empty() {
+        classes.push(class);
+    }
+
+    let class_string = classes.join(" ");
+
+    rsx!(
+        a {
+            class: "{class_string}",
+            id: props.id,
+            href: "#top",
+            onclick: move |event: MouseEvent| {
+                if scroll_to_top() {
+                    event.prevent_default();
+                }
+            },
+            "↑ {label}"
+        }
+    )
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct FooterFormProps {
+    /// Called with the entered email address when the form is submitted
+    onsubmit: EventHandler<String>,
+    /// Placeholder for the email input (default: "you@example.com")
+    placeholder: Option<String>,
+    /// Submit button text (default: "Subscribe")
+    button_label: Option<String>,
+    /// Optional ID for the form element
+    id: Option<String>,
+    /// Additional CSS classes to apply to the form
+    class: Option<String>,
+}
+
+/// A newsletter signup: an email `Input` and submit `ButtonUI` joined together.
+///
+/// ```text
+/// FooterSection { title: "Newsletter",
+///     FooterForm { onsubmit: move |email: String| subscribe(email) }
+/// }
+/// ```
+#[component]
+pub fn FooterForm(props: FooterFormProps) -> Element {
+    let class = props.class.unwrap_or_default();
+    let placeholder = props.placeholder.unwrap_or_else(|| "you@example.com".to_string());
+    let button_label = props.button_label.unwrap_or_else(|| "Subscribe".to_string());
+    let onsubmit = props.onsubmit;
+    let mut email = use_signal(String::new);
+
+    // Build CSS classes
+    let mut classes = vec!["join".to_string()];
+
+    if !class.is_empty() {
+        classes.push(class);
+    }
+
+    let class_string = classes.join(" ");
+
+    rsx!(
+        form {
+            class: "{class_string}",
+            id: props.id,
+            onsubmit: move |event: FormEvent| {
+                event.prevent_default();
+                onsubmit.call(email());
+            },
+            Input {
+                input_type: InputType::Email,
+                name: "email",
+                class: "join-item",
+                placeholder,
+                required: true,
+                oninput: move |value| email.set(value),
+            }
+            ButtonUI {
+                button_type: ButtonUIType::Submit,
+                class: "join-item",
+                "{button_label}"
+            }
+        }
+    )
+}
+
 #[test]
 fn test_footer_basic() {
     let props = FooterProps {
@@ -346,3 +476,36 @@ fn test_footer_custom_class() {
     let result = dioxus_ssr::render_element(Footer(props));
     assert!(result.contains("footer") && result.contains("custom-class"));
 }
+
+#[test]
+fn test_footer_back_to_top() {
+    let result = dioxus_ssr::render_element(rsx!(
+        FooterBackToTop { class: "mt-4" }
+    ));
+    assert!(result.contains(r##"<a class="btn btn-ghost btn-sm mt-4" href="#top">↑ Back to top</a>"##));
+}
+
+#[test]
+fn test_footer_form_submits_email() {
+    use crate::test_utils::{fire, listener, rebuild, TestFormData};
+
+    fn app() -> Element {
+        let mut subscribed = use_signal(String::new);
+        rsx!(
+            FooterForm { onsubmit: move |email| subscribed.set(email) }
+            "Subscribed: {subscribed}"
+        )
+    }
+
+    let (mut dom, listeners) = rebuild(app);
+    assert!(dioxus_ssr::render(&dom).contains(r#"class="input input-bordered input-sm join-item""#));
+
+    fire(
+        &mut dom,
+        "input",
+        listener(&listeners, "input", 0),
+        TestFormData { value: "ada@example.com".to_string() },
+    );
+    fire(&mut dom, "submit", listener(&listeners, "submit", 0), TestFormData::default());
+    assert!(dioxus_ssr::render(&dom).contains("Subscribed: ada@example.com"));
+}
@@ -2,6 +2,8 @@
 use std::fmt::Display;
 use dioxus::prelude::*;
 
+use crate::css::css_url;
+
 /// A Hero component for hero sections on landing pages.
 ///
 /// # Examples
@@ -27,6 +29,8 @@ use dioxus::prelude::*;
 
 /// Color scheme options for Hero component
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
 pub enum HeroColorScheme {
     /// Primary color
     Primary,
@@ -51,6 +55,8 @@ impl Display for HeroColorScheme {
 
 /// Size options for Hero component
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
 pub enum HeroSize {
     /// Small size
     Small,
@@ -75,6 +81,8 @@ impl Display for HeroSize {
 
 /// Alignment options for Hero component
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
 pub enum HeroAlign {
     /// Left alignment
     Left,
@@ -96,6 +104,8 @@ impl Display for HeroAlign {
 
 /// Title level options for HeroTitle
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
 pub enum HeroTitleLevel {
     /// H1 heading
     H1,
@@ -127,6 +137,8 @@ pub struct HeroProps {
     align: Option<HeroAlign>,
     /// Overlay opacity (0.0 to 1.0)
     overlay_opacity: Option<f32>,
+    /// Center the content vertically and horizontally (default: true)
+    center: Option<bool>,
 }
 
 #[component]
@@ -147,6 +159,10 @@ pub fn Hero(props: HeroProps) -> Element {
     if let Some(s) = size {
         classes.push(s.to_string());
     }
+
+    if props.center.unwrap_or(true) {
+        classes.push("place-items-center".to_string());
+    }
     
     if !class.is_empty() {
         classes.push(class);
@@ -157,7 +173,7 @@ pub fn Hero(props: HeroProps) -> Element {
     // Build background style
     let mut background_style = String::new();
     if let Some(bg_image) = &props.background_image {
-        background_style.push_str(&format!("background-image: url('{}');", bg_image));
+        background_style.push_str(&format!("background-image: {};", css_url(bg_image)));
     }
     if let Some(bg_color) = &props.background_color {
         if !background_style.is_empty() {
@@ -354,6 +370,7 @@ fn test_hero_basic() {
         size: None,
         align: None,
         overlay_opacity: None,
+        center: None,
     };
 
     let result = dioxus_ssr::render_element(Hero(props));
@@ -377,6 +394,7 @@ fn test_hero_with_background() {
         size: None,
         align: None,
         overlay_opacity: None,
+        center: None,
     };
 
     let result = dioxus_ssr::render_element(Hero(props));
@@ -397,6 +415,7 @@ fn test_hero_with_color_scheme() {
         size: None,
         align: None,
         overlay_opacity: None,
+        center: None,
     };
 
     let result = dioxus_ssr::render_element(Hero(props));
@@ -416,6 +435,7 @@ fn test_hero_with_size() {
         size: Some(HeroSize::Large),
         align: None,
         overlay_opacity: None,
+        center: None,
     };
 
     let result = dioxus_ssr::render_element(Hero(props));
@@ -435,9 +455,47 @@ fn test_hero_centered() {
         size: None,
         align: Some(HeroAlign::Center),
         overlay_opacity: None,
+        center: None,
     };
 
     let result = dioxus_ssr::render_element(Hero(props));
     // align is a prop that can be used by CSS/JS, not rendered as class on hero element
     assert!(result.contains("hero"));
 }
+
+#[test]
+fn test_hero_center() {
+    let props = HeroProps {
+        children: rsx!(HeroContent { HeroTitle { children: rsx!("Title") } }),
+        id: None,
+        class: Some("min-h-screen".to_string()),
+        background_image: None,
+        background_color: None,
+        overlay: None,
+        color_scheme: None,
+        size: None,
+        align: None,
+        overlay_opacity: None,
+        center: None,
+    };
+
+    let result = dioxus_ssr::render_element(Hero(props));
+    assert!(result.starts_with(r#"<div class="hero place-items-center min-h-screen">"#));
+
+    let props = HeroProps {
+        children: rsx!(HeroContent { HeroTitle { children: rsx!("Title") } }),
+        id: None,
+        class: None,
+        background_image: None,
+        background_color: None,
+        overlay: None,
+        color_scheme: None,
+        size: None,
+        align: None,
+        overlay_opacity: None,
+        center: Some(false),
+    };
+
+    let result = dioxus_ssr::render_element(Hero(props));
+    assert!(result.starts_with(r#"<div class="hero">"#));
+}
@@ -3,13 +3,18 @@ use std::fmt::Display;
 
 use dioxus::prelude::*;
 
+use crate::button_ui::ButtonUI;
+
 #[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
 pub enum InputType {
     #[default]
     Text,
     Number,
     Email,
     Password,
+    Search,
 }
 
 impl Display for InputType {
@@ -19,11 +24,14 @@ impl Display for InputType {
             InputType::Number => write!(f, "number"),
             InputType::Email => write!(f, "email"),
             InputType::Password => write!(f, "password"),
+            InputType::Search => write!(f, "search"),
         }
     }
 }
 
 #[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
 pub enum InputSize {
     #[default]
     Default,
@@ -60,6 +68,20 @@ pub struct InputProps {
     pub required: Option<bool>,
     pub disabled: Option<bool>,
     pub readonly: Option<bool>,
+    /// Show a button that clears the input while it has a value
+    pub clearable: Option<bool>,
+    /// Called after the clear button empties the input
+    pub onclear: Option<EventHandler<()>>,
+    /// Called with the new value on every edit
+    pub oninput: Option<EventHandler<String>>,
+    /// Key handler, e.g. for moving through suggestions below the input
+    pub onkeydown: Option<EventHandler<KeyboardEvent>>,
+    /// Accessible name when there is no visible label
+    pub aria_label: Option<String>,
+    /// Focus the input when it is rendered
+    pub autofocus: Option<bool>,
+    /// Additional CSS classes to apply to the input, e.g. `join-item`
+    pub class: Option<String>,
 }
 
 #[component]
@@ -67,6 +89,20 @@ pub fn Input(props: InputProps) -> Element {
     let input_type = props.input_type.unwrap_or_default();
     let input_size = props.input_size.unwrap_or_default();
 
+    let mut classes = vec!["input".to_string(), "input-bordered".to_string(), input_size.to_string()];
+    if let Some(class) = props.class.filter(|class| !class.is_empty()) {
+        classes.push(class);
+    }
+    let class_string = classes.join(" ");
+
+    let mut listeners = vec![];
+    if let Some(handler) = props.oninput {
+        listeners.push(dioxus_elements::events::oninput(move |event: FormEvent| handler.call(event.value())));
+    }
+    if let Some(handler) = props.onkeydown {
+        listeners.push(dioxus_elements::events::onkeydown(move |event| handler.call(event)));
+    }
+
     rsx!(
         match (props.label, props.required) {
             (Some(l), Some(_)) => rsx! {
@@ -77,17 +113,40 @@ pub fn Input(props: InputProps) -> Element {
             },
             (None, _) => rsx! {},
         }
-        input {
-            id: props.id,
-            class: "input input-bordered {input_size}",
-            value: props.value,
-            required: props.required,
-            disabled: props.disabled,
-            readonly: props.readonly,
-            name: "{props.name}",
-            placeholder: props.placeholder,
-            step: props.step,
-            "type": "{input_type}",
+        if props.clearable.unwrap_or(false) {
+            ClearableInput {
+                id: props.id,
+                class: class_string,
+                input_type: "{input_type}",
+                name: props.name,
+                placeholder: props.placeholder,
+                step: props.step,
+                value: props.value,
+                required: props.required,
+                disabled: props.disabled,
+                readonly: props.readonly,
+                oninput: props.oninput,
+                onkeydown: props.onkeydown,
+                aria_label: props.aria_label,
+                autofocus: props.autofocus,
+                onclear: props.onclear,
+            }
+        } else {
+            input {
+                id: props.id,
+                class: "{class_string}",
+                value: props.value,
+                required: props.required,
+                disabled: props.disabled,
+                readonly: props.readonly,
+                name: "{props.name}",
+                placeholder: props.placeholder,
+                step: props.step,
+                "type": "{input_type}",
+                aria_label: props.aria_label,
+                autofocus: props.autofocus,
+                ..listeners,
+            }
         }
         if let Some(l) = props.help_text {
             label {
@@ -96,3 +155,494 @@ pub fn Input(props: InputProps) -> Element {
         }
     )
 }
+
+#[derive(Props, Clone, PartialEq)]
+pub struct PasswordInputProps {
+    pub name: String,
+    pub id: Option<String>,
+    pub label_class: Option<String>,
+    pub value: Option<String>,
+    pub label: Option<String>,
+    pub placeholder: Option<String>,
+    pub input_size: Option<InputSize>,
+    pub required: Option<bool>,
+    pub disabled: Option<bool>,
+}
+
+/// A password `Input` with a button that shows or hides what was typed.
+#[component]
+pub fn PasswordInput(props: PasswordInputProps) -> Element {
+    let mut visible = use_signal(|| false);
+    let (input_type, toggle_label) = if visible() {
+        (InputType::Text, "Hide password")
+    } else {
+        (InputType::Password, "Show password")
+    };
+
+    // The label sits outside the relative wrapper so the button centres on the input
+    rsx!(
+        match (props.label, props.required) {
+            (Some(l), Some(_)) => rsx! {
+                label { class: props.label_class, "{l} *" }
+            },
+            (Some(l), None) => rsx! {
+                label { class: props.label_class, "{l}" }
+            },
+            (None, _) => rsx! {},
+        }
+        div {
+            class: "relative",
+            Input {
+                input_type,
+                input_size: props.input_size,
+                name: props.name,
+                id: props.id,
+                value: props.value,
+                placeholder: props.placeholder,
+                required: props.required,
+                disabled: props.disabled,
+                class: "pr-14",
+            }
+            button {
+                class: "btn btn-ghost btn-xs absolute right-1 top-1/2 -translate-y-1/2",
+                r#type: "button",
+                aria_label: toggle_label,
+                aria_pressed: "{visible}",
+                disabled: props.disabled,
+                onclick: move |_| visible.toggle(),
+                if visible() { "Hide" } else { "Show" }
+            }
+        }
+    )
+}
+
+/// How long a CopyField shows "Copied!" or "Copy failed" after a click
+const COPIED_MS: u64 = 2000;
+
+#[derive(Props, Clone, PartialEq)]
+pub struct CopyFieldProps {
+    /// The text to show and copy, e.g. an API key
+    pub value: String,
+    pub name: Option<String>,
+    pub id: Option<String>,
+    pub label: Option<String>,
+    pub label_class: Option<String>,
+    /// Called with the value on every copy. Without the `web` feature this is
+    /// where the host copies it, as there is no Clipboard API to call
+    pub oncopy: Option<EventHandler<String>>,
+}
+
+/// What the CopyField button shows after a click
+#[derive(Copy, Clone, PartialEq)]
+enum CopyStatus {
+    Idle,
+    Copied,
+    Failed,
+}
+
+/// Writes `text` to the clipboard, returning whether the browser accepted it.
+/// `None` means there is no Clipboard API to try.
+#[cfg(feature = "web")]
+async fn copy_to_clipboard(text: &str) -> Option<bool> {
+    let Some(window) = web_sys::window() else {
+        return Some(false);
+    };
+    let written = window.navigator().clipboard().write_text(text);
+    Some(wasm_bindgen_futures::JsFuture::from(written).await.is_ok())
+}
+
+#[cfg(not(feature = "web"))]
+async fn copy_to_clipboard(_text: &str) -> Option<bool> {
+    None
+}
+
+/// A read-only `Input` with a copy button, briefly showing "Copied!" after a
+/// click, or "Copy failed" when the browser refuses the write.
+#[component]
+pub fn CopyField(props: CopyFieldProps) -> Element {
+    let mut status = use_signal(|| CopyStatus::Idle);
+    let value = props.value.clone();
+    let oncopy = props.oncopy;
+
+    rsx!(
+        if let Some(l) = props.label {
+            label { class: props.label_class, "{l}" }
+        }
+        div {
+            class: "join w-full",
+            Input {
+                name: props.name.unwrap_or_default(),
+                id: props.id,
+                class: "join-item w-full",
+                value: props.value,
+                readonly: true,
+            }
+            ButtonUI {
+                class: "join-item",
+                onclick: move |_| {
+                    let value = value.clone();
+                    if let Some(handler) = oncopy {
+                        handler.call(value.clone());
+                    }
+                    spawn(async move {
+                        let next = match copy_to_clipboard(&value).await {
+                            Some(true) => CopyStatus::Copied,
+                            Some(false) => CopyStatus::Failed,
+                            None if oncopy.is_some() => CopyStatus::Copied,
+                            None => return,
+                        };
+                        status.set(next);
+                        futures_timer::Delay::new(std::time::Duration::from_millis(COPIED_MS)).await;
+                        status.set(CopyStatus::Idle);
+                    });
+                },
+                match status() {
+                    CopyStatus::Idle => "Copy",
+                    CopyStatus::Copied => "Copied!",
+                    CopyStatus::Failed => "Copy failed",
+                }
+            }
+        }
+    )
+}
+
+/// How long a SearchInput waits after the last keystroke by default
+const SEARCH_DEBOUNCE_MS: u64 = 300;
+
+#[derive(Props, Clone, PartialEq)]
+pub struct SearchInputProps {
+    /// Called with the query once the user stops typing
+    pub onsearch: EventHandler<String>,
+    /// How long to wait after the last keystroke, in milliseconds (default: 300)
+    pub debounce_ms: Option<u64>,
+    pub name: Option<String>,
+    pub id: Option<String>,
+    pub label: Option<String>,
+    pub label_class: Option<String>,
+    pub value: Option<String>,
+    pub placeholder: Option<String>,
+    pub input_size: Option<InputSize>,
+    pub disabled: Option<bool>,
+    pub class: Option<String>,
+}
+
+/// A search `Input` that calls `onsearch` only after typing pauses for
+/// `debounce_ms`, so search-as-you-type doesn't run on every keystroke.
+#[component]
+pub fn SearchInput(props: SearchInputProps) -> Element {
+    let debounce = std::time::Duration::from_millis(props.debounce_ms.unwrap_or(SEARCH_DEBOUNCE_MS));
+    let onsearch = props.onsearch;
+    let mut pending = use_signal(|| None::<String>);
+
+    // Restarted on every keystroke, which cancels the previous wait
+    let mut timer = use_future(move || async move {
+        if pending.peek().is_none() {
+            return;
+        }
+        futures_timer::Delay::new(debounce).await;
+        if let Some(query) = pending.take() {
+            onsearch.call(query);
+        }
+    });
+
+    rsx!(
+        Input {
+            input_type: InputType::Search,
+            input_size: props.input_size,
+            name: props.name.unwrap_or_default(),
+            id: props.id,
+            label: props.label,
+            label_class: props.label_class,
+            value: props.value,
+            placeholder: props.placeholder,
+            disabled: props.disabled,
+            class: props.class,
+            oninput: move |query| {
+                pending.set(Some(query));
+                timer.restart();
+            },
+        }
+    )
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub(crate) struct ClearableInputProps {
+    pub id: Option<String>,
+    pub class: String,
+    pub input_type: String,
+    pub name: Option<String>,
+    pub placeholder: Option<String>,
+    pub step: Option<String>,
+    /// Current value; edits are tracked locally until the prop changes again
+    pub value: Option<String>,
+    pub required: Option<bool>,
+    pub disabled: Option<bool>,
+    pub readonly: Option<bool>,
+    /// Called with the new value on every edit, including clearing
+    pub oninput: Option<EventHandler<String>>,
+    pub onkeydown: Option<EventHandler<KeyboardEvent>>,
+    pub aria_label: Option<String>,
+    pub autofocus: Option<bool>,
+    pub onclear: Option<EventHandler<()>>,
+}
+
+/// An input with a trailing clear button, shared by `Input` and
+/// `InputGroupInput` when `clearable` is set.
+#[component]
+pub(crate) fn ClearableInput(props: ClearableInputProps) -> Element {
+    let mut value = use_signal(|| props.value.clone().unwrap_or_default());
+    let oninput = props.oninput;
+    let onclear = props.onclear;
+
+    // Follow the value prop when the parent changes it
+    use_effect(use_reactive(&props.value, move |next| {
+        let next = next.unwrap_or_default();
+        if *value.peek() != next {
+            value.set(next);
+        }
+    }));
+
+    let mut listeners = vec![];
+    if let Some(handler) = props.onkeydown {
+        listeners.push(dioxus_elements::events::onkeydown(move |event| handler.call(event)));
+    }
+
+    rsx!(
+        div {
+            class: "relative",
+            input {
+                id: props.id,
+                class: "{props.class} pr-8",
+                value: "{value}",
+                required: props.required,
+                disabled: props.disabled,
+                readonly: props.readonly,
+                name: props.name,
+                placeholder: props.placeholder,
+                step: props.step,
+                "type": "{props.input_type}",
+                aria_label: props.aria_label,
+                autofocus: props.autofocus,
+                oninput: move |event: FormEvent| {
+                    value.set(event.value());
+                    if let Some(handler) = oninput {
+                        handler.call(event.value());
+                    }
+                },
+                ..listeners,
+            }
+            if !value.read().is_empty() {
+                button {
+                    class: "btn btn-ghost btn-xs btn-circle absolute right-1 top-1/2 -translate-y-1/2",
+                    r#type: "button",
+                    aria_label: "Clear",
+                    onclick: move |_| {
+                        value.set(String::new());
+                        if let Some(handler) = oninput {
+                            handler.call(String::new());
+                        }
+                        if let Some(handler) = onclear {
+                            handler.call(());
+                        }
+                    },
+                    "✕"
+                }
+            }
+        }
+    )
+}
+
+#[test]
+fn test_input_clearable_clears_value() {
+    use crate::test_utils::{fire, listener, rebuild, TestMouseData};
+
+    fn app() -> Element {
+        let mut cleared = use_signal(|| false);
+        rsx!(
+            Input {
+                name: "q",
+                value: "daisy",
+                clearable: true,
+                onclear: move |_| cleared.set(true),
+            }
+            "Cleared: {cleared}"
+        )
+    }
+
+    let (mut dom, listeners) = rebuild(app);
+    let result = dioxus_ssr::render(&dom);
+    assert!(result.contains(r#"value="daisy""#));
+    assert!(result.contains(r#"aria-label="Clear""#));
+
+    fire(&mut dom, "click", listener(&listeners, "click", 0), TestMouseData::default());
+    let result = dioxus_ssr::render(&dom);
+    assert!(result.contains(r#"value="""#));
+    assert!(!result.contains(r#"aria-label="Clear""#));
+    assert!(result.contains("Cleared: true"));
+}
+
+#[test]
+fn test_input_clearable_follows_value_prop() {
+    use crate::test_utils::{block_on, fire, listener, rebuild, TestMouseData};
+
+    fn app() -> Element {
+        let mut query = use_signal(|| "daisy".to_string());
+        rsx!(
+            button { onclick: move |_| query.set("tulip".to_string()), "Change" }
+            Input { name: "q", value: query(), clearable: true }
+        )
+    }
+
+    let (mut dom, listeners) = rebuild(app);
+    assert!(dioxus_ssr::render(&dom).contains(r#"value="daisy""#));
+
+    fire(&mut dom, "click", listener(&listeners, "click", 0), TestMouseData::default());
+    block_on(dom.wait_for_work());
+    dom.render_immediate_to_vec();
+    let result = dioxus_ssr::render(&dom);
+    assert!(result.contains(r#"value="tulip""#));
+    assert!(!result.contains(r#"value="daisy""#));
+}
+
+#[test]
+fn test_password_input_toggles_type() {
+    use crate::test_utils::{fire, listener, rebuild, TestMouseData};
+
+    fn app() -> Element {
+        rsx!(
+            PasswordInput { name: "password", label: "Password" }
+        )
+    }
+
+    let (mut dom, listeners) = rebuild(app);
+    let result = dioxus_ssr::render(&dom);
+    assert!(result.contains(r#"type="password""#));
+    assert!(result.contains(r#"aria-pressed="false""#));
+    // Only the input and the button share the positioned wrapper
+    assert!(result.starts_with(r#"<label>Password</label><div class="relative"><input class="input input-bordered input-sm pr-14""#));
+
+    fire(&mut dom, "click", listener(&listeners, "click", 0), TestMouseData::default());
+    let result = dioxus_ssr::render(&dom);
+    assert!(result.contains(r#"type="text""#));
+    assert!(result.contains(r#"aria-label="Hide password""#));
+
+    fire(&mut dom, "click", listener(&listeners, "click", 0), TestMouseData::default());
+    assert!(dioxus_ssr::render(&dom).contains(r#"type="password""#));
+}
+
+// With `web` on, the click goes to the browser's Clipboard API, covered by the browser tests below
+#[cfg(not(feature = "web"))]
+#[test]
+fn test_copy_field_shows_copied() {
+    use crate::test_utils::{fire, listener, rebuild, run_for, TestMouseData};
+
+    fn app() -> Element {
+        let mut copied = use_signal(String::new);
+        rsx!(
+            CopyField {
+                value: "sk-123",
+                oncopy: move |value| copied.set(value),
+            }
+            "Copied value: {copied}"
+        )
+    }
+
+    let (mut dom, listeners) = rebuild(app);
+    let result = dioxus_ssr::render(&dom);
+    assert!(result.contains(r#"value="sk-123""#));
+    assert!(result.contains(">Copy</button>"));
+
+    fire(&mut dom, "click", listener(&listeners, "click", 0), TestMouseData::default());
+    run_for(&mut dom, 20);
+    let result = dioxus_ssr::render(&dom);
+    assert!(result.contains(">Copied!</button>"));
+    assert!(result.contains("Copied value: sk-123"));
+}
+
+/// Replaces `navigator.clipboard` with one whose `writeText` runs `body`.
+#[cfg(all(feature = "web", target_arch = "wasm32"))]
+fn stub_clipboard(body: &str) {
+    let clipboard = js_sys::Object::new();
+    let write_text = js_sys::Function::new_with_args("text", body);
+    js_sys::Reflect::set(&clipboard, &"writeText".into(), &write_text).unwrap();
+
+    let descriptor = js_sys::Object::new();
+    js_sys::Reflect::set(&descriptor, &"value".into(), &clipboard).unwrap();
+    js_sys::Reflect::set(&descriptor, &"configurable".into(), &true.into()).unwrap();
+    let navigator = web_sys::window().unwrap().navigator();
+    js_sys::Object::define_property(&navigator, &"clipboard".into(), &descriptor);
+}
+
+#[cfg(all(feature = "web", target_arch = "wasm32"))]
+#[wasm_bindgen_test::wasm_bindgen_test]
+async fn test_copy_field_shows_copied_in_browser() {
+    use wasm_bindgen::JsCast;
+
+    use crate::test_utils::{mount, settle};
+
+    fn app() -> Element {
+        rsx!(CopyField { value: "sk-123" })
+    }
+
+    stub_clipboard("window.copied = text; return Promise.resolve();");
+    let root = mount(app).await;
+    let button = root.query_selector("button").unwrap().unwrap();
+    assert_eq!(button.text_content().as_deref(), Some("Copy"));
+
+    button.unchecked_ref::<web_sys::HtmlElement>().click();
+    settle().await;
+    assert_eq!(button.text_content().as_deref(), Some("Copied!"));
+    let copied = js_sys::Reflect::get(&web_sys::window().unwrap(), &"copied".into()).unwrap();
+    assert_eq!(copied.as_string().as_deref(), Some("sk-123"));
+}
+
+#[cfg(all(feature = "web", target_arch = "wasm32"))]
+#[wasm_bindgen_test::wasm_bindgen_test]
+async fn test_copy_field_shows_failure_in_browser() {
+    use wasm_bindgen::JsCast;
+
+    use crate::test_utils::{mount, settle};
+
+    fn app() -> Element {
+        rsx!(CopyField { value: "sk-123" })
+    }
+
+    stub_clipboard("return Promise.reject(new Error('denied'));");
+    let root = mount(app).await;
+    let button = root.query_selector("button").unwrap().unwrap();
+
+    button.unchecked_ref::<web_sys::HtmlElement>().click();
+    settle().await;
+    assert_eq!(button.text_content().as_deref(), Some("Copy failed"));
+}
+
+#[test]
+fn test_search_input_debounces() {
+    use crate::test_utils::{block_on, fire, listener, rebuild, TestFormData};
+
+    fn app() -> Element {
+        let mut searches = use_signal(Vec::<String>::new);
+        rsx!(
+            SearchInput {
+                name: "q",
+                debounce_ms: 10,
+                onsearch: move |query| searches.write().push(query),
+            }
+            span { "Searches: {searches.read().join(\",\")}" }
+        )
+    }
+
+    let (mut dom, listeners) = rebuild(app);
+    assert!(dioxus_ssr::render(&dom).contains(r#"type="search""#));
+
+    let input = listener(&listeners, "input", 0);
+    for value in ["d", "da", "dai"] {
+        fire(&mut dom, "input", input, TestFormData { value: value.to_string() });
+    }
+    assert!(dioxus_ssr::render(&dom).contains("Searches: </span>"));
+
+    // Let the debounce window elapse
+    block_on(dom.wait_for_work());
+    dom.render_immediate_to_vec();
+    assert!(dioxus_ssr::render(&dom).contains("Searches: dai</span>"));
+}
@@ -2,6 +2,9 @@
 use std::fmt::Display;
 use dioxus::prelude::*;
 
+use crate::context::try_context;
+use crate::input::ClearableInput;
+
 /// An Input Group component for grouping inputs with buttons, selects, or icons.
 ///
 /// # Examples
@@ -43,9 +46,29 @@ use dioxus::prelude::*;
 ///     )
 /// }
 /// ```
+///
+/// Reporting the typed value on Enter or button click:
+///
+/// ```text
+/// InputGroup {
+///     onsubmit: move |query: String| search(query),
+///     children: rsx!(
+///         InputGroupInput {
+///             input_type: "search".to_string(),
+///             placeholder: "Search...".to_string()
+///         }
+///         InputGroupButton {
+///             button_type: "button".to_string(),
+///             children: rsx!("Search")
+///         }
+///     )
+/// }
+/// ```
 
 /// Size options for Input Group component
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
 pub enum InputGroupSize {
     /// Small size
     Small,
@@ -77,6 +100,8 @@ pub struct InputGroupProps {
     size: Option<InputGroupSize>,
     /// Vertical layout
     vertical: Option<bool>,
+    /// Called with the input's value when Enter is pressed in it or the button is clicked
+    onsubmit: Option<EventHandler<String>>,
 }
 
 #[component]
@@ -102,13 +127,70 @@ pub fn InputGroup(props: InputGroupProps) -> Element {
 
     let class_string = classes.join(" ");
 
-    rsx!(
-        div {
-            class: "{class_string}",
-            id: props.id,
-            {props.children}
+    if let Some(onsubmit) = props.onsubmit {
+        rsx!(
+            InputGroupSubmitScope {
+                onsubmit,
+                div {
+                    class: "{class_string}",
+                    id: props.id,
+                    {props.children}
+                }
+            }
+        )
+    } else {
+        rsx!(
+            div {
+                class: "{class_string}",
+                id: props.id,
+                {props.children}
+            }
+        )
+    }
+}
+
+/// Shared by the parts of an InputGroup with `onsubmit`, so the input can
+/// record its value and the input or button can report it.
+#[derive(Clone, Copy)]
+struct InputGroupSubmit {
+    /// The input's value, `None` until the input has rendered with one or been edited
+    value: Signal<Option<String>>,
+    onsubmit: EventHandler<String>,
+}
+
+impl InputGroupSubmit {
+    /// Starts from the input's initial value, so a prefilled field submits
+    /// without being edited first
+    fn seed(&self, initial: Option<&String>) {
+        let mut value = self.value;
+        if let Some(initial) = initial.filter(|_| value.peek().is_none()) {
+            value.set(Some(initial.clone()));
         }
-    )
+    }
+
+    fn record(&self, text: String) {
+        let mut value = self.value;
+        value.set(Some(text));
+    }
+
+    fn submit(&self) {
+        self.onsubmit.call((self.value)().unwrap_or_default());
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+struct InputGroupSubmitScopeProps {
+    children: Element,
+    onsubmit: EventHandler<String>,
+}
+
+#[component]
+fn InputGroupSubmitScope(props: InputGroupSubmitScopeProps) -> Element {
+    let value = use_signal(|| None);
+    let onsubmit = props.onsubmit;
+    use_context_provider(|| InputGroupSubmit { value, onsubmit });
+
+    rsx!({ props.children })
 }
 
 #[derive(Props, Clone, PartialEq)]
@@ -131,6 +213,10 @@ pub struct InputGroupInputProps {
     required: Option<bool>,
     /// Read-only state
     readonly: Option<bool>,
+    /// Show a button that clears the input while it has a value
+    clearable: Option<bool>,
+    /// Called after the clear button empties the input
+    onclear: Option<EventHandler<()>>,
 }
 
 #[component]
@@ -149,6 +235,53 @@ pub fn InputGroupInput(props: InputGroupInputProps) -> Element {
 
     let class_string = classes.join(" ");
 
+    let submit = try_context::<InputGroupSubmit>();
+    if let Some(submit) = submit {
+        submit.seed(props.value.as_ref());
+    }
+
+    if props.clearable.unwrap_or(false) {
+        return rsx!(
+            ClearableInput {
+                id: props.id,
+                class: class_string,
+                input_type: props.input_type,
+                placeholder: props.placeholder,
+                name: props.name,
+                value: props.value,
+                disabled,
+                required,
+                readonly,
+                oninput: move |value| {
+                    if let Some(submit) = submit {
+                        submit.record(value);
+                    }
+                },
+                onkeydown: move |event: KeyboardEvent| {
+                    if let Some(submit) = submit.filter(|_| event.key() == Key::Enter) {
+                        event.prevent_default();
+                        submit.submit();
+                    }
+                },
+                onclear: props.onclear,
+            }
+        );
+    }
+
+    // Inside an InputGroup with `onsubmit`, track the value and submit on Enter
+    let mut listeners = vec![];
+    if let Some(submit) = submit {
+        listeners.push(dioxus_elements::events::oninput(move |event: FormEvent| {
+            submit.record(event.value());
+        }));
+        listeners.push(dioxus_elements::events::onkeydown(move |event: KeyboardEvent| {
+            if event.key() == Key::Enter {
+                event.prevent_default();
+                submit.submit();
+            }
+        }));
+    }
+
     rsx!(
         input {
             class: "{class_string}",
@@ -160,6 +293,7 @@ pub fn InputGroupInput(props: InputGroupInputProps) -> Element {
             disabled: disabled,
             required: required,
             readonly: readonly,
+            ..listeners,
         }
     )
 }
@@ -192,12 +326,19 @@ pub fn InputGroupButton(props: InputGroupButtonProps) -> Element {
 
     let class_string = classes.join(" ");
 
+    // Inside an InputGroup with `onsubmit`, clicking reports the input's value
+    let mut listeners = vec![];
+    if let Some(submit) = try_context::<InputGroupSubmit>() {
+        listeners.push(dioxus_elements::events::onclick(move |_| submit.submit()));
+    }
+
     rsx!(
         button {
             class: "{class_string}",
             id: props.id,
             type: "{props.button_type}",
             disabled: disabled,
+            ..listeners,
             {props.children}
         }
     )
@@ -338,6 +479,7 @@ fn test_input_group_basic() {
         class: None,
         size: None,
         vertical: None,
+        onsubmit: None,
     };
 
     let result = dioxus_ssr::render_element(InputGroup(props));
@@ -356,6 +498,8 @@ fn test_input_group_input() {
         disabled: None,
         required: None,
         readonly: None,
+        clearable: None,
+        onclear: None,
     };
 
     let result = dioxus_ssr::render_element(InputGroupInput(props));
@@ -440,6 +584,7 @@ fn test_input_group_with_size() {
         class: None,
         size: Some(InputGroupSize::Large),
         vertical: None,
+        onsubmit: None,
     };
 
     let result = dioxus_ssr::render_element(InputGroup(props));
@@ -459,6 +604,7 @@ fn test_input_group_vertical() {
         class: None,
         size: None,
         vertical: Some(true),
+        onsubmit: None,
     };
 
     let result = dioxus_ssr::render_element(InputGroup(props));
@@ -478,6 +624,7 @@ fn test_input_group_custom_class() {
         class: Some("custom-class".to_string()),
         size: None,
         vertical: None,
+        onsubmit: None,
     };
 
     let result = dioxus_ssr::render_element(InputGroup(props));
@@ -497,6 +644,7 @@ fn test_input_group_with_id() {
         class: None,
         size: None,
         vertical: None,
+        onsubmit: None,
     };
 
     let result = dioxus_ssr::render_element(InputGroup(props));
@@ -515,6 +663,8 @@ fn test_input_group_input_disabled() {
         disabled: Some(true),
         required: None,
         readonly: None,
+        clearable: None,
+        onclear: None,
     };
 
     let result = dioxus_ssr::render_element(InputGroupInput(props));
@@ -533,6 +683,8 @@ fn test_input_group_input_required() {
         disabled: None,
         required: Some(true),
         readonly: None,
+        clearable: None,
+        onclear: None,
     };
 
     let result = dioxus_ssr::render_element(InputGroupInput(props));
@@ -551,6 +703,8 @@ fn test_input_group_input_readonly() {
         disabled: None,
         required: None,
         readonly: Some(true),
+        clearable: None,
+        onclear: None,
     };
 
     let result = dioxus_ssr::render_element(InputGroupInput(props));
@@ -586,3 +740,93 @@ fn test_input_group_option_disabled() {
     let result = dioxus_ssr::render_element(InputGroupOption(props));
     assert!(result.contains("disabled"));
 }
+
+#[test]
+fn test_input_group_onsubmit_on_enter() {
+    use crate::test_utils::{fire, listener, rebuild, TestFormData, TestKeyboardData, TestMouseData};
+
+    fn app() -> Element {
+        let mut submitted = use_signal(Vec::<String>::new);
+        let joined = submitted.read().join(",");
+        rsx!(
+            InputGroup {
+                onsubmit: move |value| submitted.write().push(value),
+                InputGroupInput { input_type: "search", placeholder: "Search..." }
+                InputGroupButton { button_type: "button", "Search" }
+            }
+            "Submitted: {joined}"
+        )
+    }
+
+    let (mut dom, listeners) = rebuild(app);
+    fire(
+        &mut dom,
+        "input",
+        listener(&listeners, "input", 0),
+        TestFormData { value: "daisy".to_string() },
+    );
+    fire(&mut dom, "keydown", listener(&listeners, "keydown", 0), TestKeyboardData::new(Key::Enter));
+    assert!(dioxus_ssr::render(&dom).contains("Submitted: daisy"));
+
+    fire(&mut dom, "click", listener(&listeners, "click", 0), TestMouseData::default());
+    assert!(dioxus_ssr::render(&dom).contains("Submitted: daisy,daisy"));
+}
+
+#[test]
+fn test_input_group_submits_prefilled_value() {
+    use crate::test_utils::{fire, listener, rebuild, TestMouseData};
+
+    fn app() -> Element {
+        let mut submitted = use_signal(String::new);
+        rsx!(
+            InputGroup {
+                onsubmit: move |value| submitted.set(value),
+                InputGroupInput { input_type: "search", placeholder: "Search...", value: "daisy" }
+                InputGroupButton { button_type: "button", "Search" }
+            }
+            "Submitted: {submitted}"
+        )
+    }
+
+    let (mut dom, listeners) = rebuild(app);
+    fire(&mut dom, "click", listener(&listeners, "click", 0), TestMouseData::default());
+    assert!(dioxus_ssr::render(&dom).contains("Submitted: daisy"));
+}
+
+#[test]
+fn test_input_group_input_clearable() {
+    use crate::test_utils::{fire, listener, rebuild, TestFormData, TestKeyboardData, TestMouseData};
+
+    fn app() -> Element {
+        let mut submitted = use_signal(String::new);
+        rsx!(
+            InputGroup {
+                onsubmit: move |value| submitted.set(value),
+                InputGroupInput {
+                    input_type: "search",
+                    placeholder: "Search...",
+                    value: "daisy",
+                    clearable: true,
+                }
+            }
+            "Submitted: {submitted}"
+        )
+    }
+
+    let (mut dom, listeners) = rebuild(app);
+    assert!(dioxus_ssr::render(&dom).contains(r#"aria-label="Clear""#));
+
+    fire(&mut dom, "click", listener(&listeners, "click", 0), TestMouseData::default());
+    let result = dioxus_ssr::render(&dom);
+    assert!(result.contains(r#"value="""#));
+    assert!(!result.contains(r#"aria-label="Clear""#));
+
+    fire(
+        &mut dom,
+        "input",
+        listener(&listeners, "input", 0),
+        TestFormData { value: "rsx".to_string() },
+    );
+    fire(&mut dom, "keydown", listener(&listeners, "keydown", 0), TestKeyboardData::new(Key::Enter));
+    assert!(dioxus_ssr::render(&dom).contains("Submitted: rsx"));
+}
@@ -31,6 +31,8 @@ use dioxus::prelude::*;
 
 /// Orientation options for Join component
 #[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
 pub enum JoinOrientation {
     #[default]
     /// Horizontal orientation (default)
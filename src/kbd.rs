@@ -47,6 +47,80 @@ pub fn Kbd(props: KbdProps) -> Element {
     )
 }
 
+#[derive(Props, Clone, PartialEq)]
+pub struct KbdComboProps {
+    /// Keys pressed together, e.g. `["Ctrl", "Shift", "P"]`
+    keys: Vec<String>,
+    /// Optional ID for the combo element
+    id: Option<String>,
+    /// Additional CSS classes to apply to each key
+    class: Option<String>,
+}
+
+/// A key combination shown as `Kbd`s joined by `+`
+#[component]
+pub fn KbdCombo(props: KbdComboProps) -> Element {
+    let count = props.keys.len();
+
+    rsx!(
+        span {
+            class: "inline-flex items-center gap-1",
+            id: props.id,
+            for (index, key) in props.keys.into_iter().enumerate() {
+                Kbd { class: props.class.clone(), "{key}" }
+                if index + 1 < count {
+                    "+"
+                }
+            }
+        }
+    )
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct ShortcutListProps {
+    /// Shortcuts as (keys, description) pairs
+    shortcuts: Vec<(Vec<String>, String)>,
+    /// Optional ID for the list element
+    id: Option<String>,
+    /// Additional CSS classes to apply to the list
+    class: Option<String>,
+}
+
+/// A two-column legend of keyboard shortcuts, e.g. for a help overlay.
+///
+/// ```text
+/// ShortcutList {
+///     shortcuts: vec![
+///         (vec!["Ctrl".into(), "K".into()], "Open command palette".into()),
+///         (vec!["?".into()], "Show this help".into()),
+///     ],
+/// }
+/// ```
+#[component]
+pub fn ShortcutList(props: ShortcutListProps) -> Element {
+    let class = props.class.unwrap_or_default();
+
+    // Build CSS classes
+    let mut classes = vec!["grid grid-cols-[auto_1fr] items-center gap-x-4 gap-y-2".to_string()];
+
+    if !class.is_empty() {
+        classes.push(class);
+    }
+
+    let class_string = classes.join(" ");
+
+    rsx!(
+        dl {
+            class: "{class_string}",
+            id: props.id,
+            for (keys, description) in props.shortcuts {
+                dt { KbdCombo { keys } }
+                dd { "{description}" }
+            }
+        }
+    )
+}
+
 #[test]
 fn test_kbd_basic() {
     let props = KbdProps {
@@ -94,3 +168,23 @@ fn test_kbd_multiple_keys() {
     let result = dioxus_ssr::render_element(Kbd(props));
     assert!(result.contains("Ctrl"));
 }
+
+#[test]
+fn test_shortcut_list_rows() {
+    let props = ShortcutListProps {
+        shortcuts: vec![
+            (vec!["Ctrl".to_string(), "K".to_string()], "Open command palette".to_string()),
+            (vec!["?".to_string()], "Show this help".to_string()),
+        ],
+        id: None,
+        class: None,
+    };
+
+    let result = dioxus_ssr::render_element(ShortcutList(props));
+    assert_eq!(result.matches("<dt>").count(), 2);
+    assert_eq!(result.matches("<dd>").count(), 2);
+    assert!(result.contains(
+        r#"<dt><span class="inline-flex items-center gap-1"><kbd class="kbd">Ctrl</kbd>+<kbd class="kbd">K</kbd></span></dt><dd>Open command palette</dd>"#
+    ));
+    assert!(result.contains(r#"<kbd class="kbd">?</kbd></span></dt><dd>Show this help</dd>"#));
+}
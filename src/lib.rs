@@ -5,6 +5,7 @@ pub mod avatar;
 pub mod badge;
 pub mod blank_slate;
 pub mod breadcrumb;
+pub mod breakpoint;
 pub mod button;
 pub mod button_ui;
 pub mod card;
@@ -16,15 +17,19 @@ pub mod file_input;
 pub mod input;
 pub mod join;
 pub mod link;
+pub mod loading;
 pub mod mask;
 pub mod menu;
 pub mod marketing;
 pub mod navbar;
+pub mod prelude;
 pub mod progress;
 pub mod radio;
 pub mod rating;
+pub mod size;
 pub mod skeleton;
 pub mod steps;
+pub mod status;
 pub mod swap;
 pub mod tabs;
 pub mod theme;
@@ -33,17 +38,26 @@ pub mod toggle;
 pub mod divider;
 pub mod chat;
 pub mod code;
+pub mod container;
 pub mod collapse;
+pub mod command_palette;
+mod class;
+mod context;
 pub mod countdown;
+pub mod density;
+mod css;
 pub mod indicator;
 pub mod kbd;
 pub mod stack;
 pub mod modal;
+pub mod motion;
 pub mod nav_item;
 pub mod pagination;
+pub mod portal;
 pub mod range;
 pub mod relative_time;
 pub mod select;
+pub mod show;
 pub mod tab_container;
 pub mod text_area;
 pub mod time_line;
@@ -59,61 +73,76 @@ pub mod calendar;
 pub mod carousel;
 pub mod input_group;
 
+#[cfg(test)]
+mod test_utils;
+
+#[allow(deprecated)]
 pub use accordian::Accordian;
+pub use accordian::{Accordion, AccordionItem};
 pub use alert::{Alert, AlertColor};
 pub use app_layout::AppLayout;
 pub use avatar::{Avatar, AvatarSize, AvatarType};
 pub use badge::{Badge, BadgeColor, BadgeSize, BadgeStyle};
 pub use blank_slate::BlankSlate;
-pub use breadcrumb::{Breadcrumb, BreadcrumbItem};
+pub use breadcrumb::{breadcrumb_items_from_path, Breadcrumb, BreadcrumbItem, BreadcrumbsFromPath};
+pub use breakpoint::Breakpoint;
 pub use button::{Button, ButtonScheme, ButtonShape, ButtonSize, ButtonStyle, ButtonType};
-pub use button_ui::{ButtonUI, ButtonUIColorScheme, ButtonUISize, ButtonUIShape, ButtonUIVariant, ButtonUIState};
+pub use button_ui::{ButtonUI, ButtonUIColorScheme, ButtonUISize, ButtonUIShape, ButtonUIVariant, ButtonUIState, ButtonUIType, IconPosition};
 pub use card::{Card, CardBody, CardHeader};
 pub use check_box::{CheckBox, CheckBoxScheme, CheckBoxSize};
 pub use drawer::{Drawer, DrawerBody, DrawerFooter};
 pub use drop_down::{Direction, DropDown, DropDownLink};
-pub use file_input::{FileInput, FileInputColor, FileInputSize, FileInputStyle};
-pub use input::{Input, InputSize, InputType};
+pub use file_input::{FileInput, FileInputColor, FileInputSize, FileInputStyle, FileUpload};
+pub use input::{CopyField, Input, InputSize, InputType, PasswordInput, SearchInput};
 pub use modal::{Modal, ModalAction, ModalBody};
+pub use motion::{MotionPreference, MotionProvider};
 pub use nav_item::{NavGroup, NavItem, NavSubGroup, NavSubItem};
 pub use pagination::Pagination;
+pub use portal::Portal;
 pub use range::{Range, RangeColor};
 pub use relative_time::{RelativeTime, RelativeTimeFormat};
 pub use select::{Select, SelectOption, SelectSize};
+pub use show::Show;
 pub use fieldset::Fieldset;
 pub use tab_container::{TabContainer, TabPanel};
 pub use text_area::{TextArea, TextAreaSize};
 pub use time_line::{TimeLine, TimeLineBadge, TimeLineBody};
 pub use timeline::{Timeline, TimelineItem, TimelineStart, TimelineMiddle, TimelineEnd};
 pub use tooltip::{ToolTip, ToolTipColor};
-pub use table::{Table, TableSize};
+pub use table::{CaptionSide, DataTable, SortDirection, Table, TableCell, TableCellAlign, TableFooter, TableRow, TableSize, TableSortHeader};
 pub use join::{Join, JoinItem, JoinOrientation};
 pub use link::{Link, LinkColorScheme};
+pub use loading::{Loading, LoadingOverlay, LoadingVariant};
 pub use mask::{Mask, MaskVariant, MaskSize};
-pub use menu::{Menu, MenuItem, MenuTitle, MenuOrientation};
+pub use menu::{Menu, MenuItem, MenuSection, MenuTitle, MenuOrientation, VirtualMenu, VirtualMenuItem};
 pub use navbar::{Navbar, NavbarStart, NavbarCenter, NavbarEnd};
-pub use progress::{Progress, ProgressColorScheme, ProgressSize};
+pub use progress::{LabelPosition, Progress, ProgressColorScheme, ProgressMulti, ProgressSize, ProgressWithLabel, RadialProgress};
 pub use radio::{Radio, RadioColorScheme, RadioSize};
-pub use rating::{Rating, RatingColorScheme, RatingSize};
-pub use skeleton::{Skeleton, SkeletonVariant};
-pub use steps::{Steps, Step, StepsOrientation};
+pub use rating::{Rating, RatingColorScheme, RatingShape, RatingSize};
+pub use size::Size;
+pub use skeleton::{Skeleton, SkeletonSwap, SkeletonText, SkeletonVariant};
+pub use steps::{use_stepper, Steps, Step, StepColor, StepsOrientation, UseStepper};
+pub use status::{Status, StatusColor};
 pub use swap::{Swap, SwapItem, SwapAnimation, SwapSize};
-pub use theme::{Theme, ThemeName};
-pub use toast::{Toast, ToastType};
-pub use toggle::{Toggle, ToggleColorScheme, ToggleSize};
-pub use divider::{Divider, DividerOrientation};
+pub use theme::{use_theme, ParseThemeNameError, Theme, ThemeController, ThemeName, ThemeTag, ThemeToggle};
+pub use toast::{Toast, ToastContainer, ToastType};
+pub use toggle::{Toggle, ToggleColorScheme, ToggleSize, ToggleWithLabel};
+pub use divider::{Divider, DividerIcon, DividerOrientation, DividerStyle};
 pub use chat::{Chat, ChatBubble, ChatHeader, ChatFooter, ChatBubbleColor};
-pub use code::{Code, CodeType};
+pub use code::{Code, CodeColorScheme, CodeType};
+pub use container::Container;
 pub use collapse::{Collapse, CollapseTitle, CollapseContent};
+pub use command_palette::{CommandPalette, PaletteCommand};
 pub use countdown::{Countdown, CountdownValue};
+pub use density::{Density, DensityProvider};
 pub use indicator::{Indicator, IndicatorItem};
-pub use kbd::Kbd;
+pub use kbd::{Kbd, KbdCombo, ShortcutList};
 pub use stack::{Stack, StackDirection};
-pub use stats::{Stats, StatsColorScheme, StatsSize, StatsItem, StatsTitle, StatsValue, StatsDescription};
+pub use stats::{Stats, StatsColorScheme, StatsSize, StatsItem, StatsTitle, StatsValue, StatsDescription, StatsFigure, StatsRadial};
 pub use hero::{Hero, HeroColorScheme, HeroSize, HeroAlign, HeroTitleLevel, HeroContent, HeroTitle, HeroSubtitle, HeroActions};
-pub use footer::{Footer, FooterColorScheme, FooterSize, FooterSection, FooterLink, FooterCopyright};
+pub use footer::{Footer, FooterColorScheme, FooterSize, FooterSection, FooterLink, FooterCopyright, FooterBackToTop, FooterForm};
 pub use artboard::{Artboard, ArtboardDevice, ArtboardBorderRadius, ArtboardShadow, ArtboardColorScheme, ArtboardSize, ArtboardContent};
 pub use comments::{Comments, CommentsColorScheme, CommentsSize, Comment, CommentHeader, CommentBody, CommentActions};
 pub use calendar::{Calendar, CalendarColorScheme, CalendarSize, CalendarHeader, CalendarBody, CalendarWeekday, CalendarDay};
-pub use carousel::{Carousel, CarouselColorScheme, CarouselSize, CarouselItem};
+pub use carousel::{use_carousel, use_carousel_auto_play, Carousel, CarouselAlignment, CarouselColorScheme, CarouselOrientation, CarouselSize, CarouselItem, CarouselItemWidth, CarouselThumbnails, UseCarousel};
 pub use input_group::{InputGroup, InputGroupSize, InputGroupInput, InputGroupButton, InputGroupSelect, InputGroupOption, InputGroupIcon};
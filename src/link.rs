@@ -31,6 +31,8 @@ use dioxus::prelude::*;
 
 /// Color scheme options for Link component
 #[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
 pub enum LinkColorScheme {
     #[default]
     /// Neutral gray color scheme
@@ -82,6 +84,8 @@ pub struct LinkProps {
     color_scheme: Option<LinkColorScheme>,
     /// Whether to add rel="noopener noreferrer" for external links
     external: Option<bool>,
+    /// Whether the link is disabled (drops the href)
+    disabled: Option<bool>,
 }
 
 #[component]
@@ -89,6 +93,7 @@ pub fn Link(props: LinkProps) -> Element {
     let color_scheme = props.color_scheme.unwrap_or_default();
     let class = props.class.unwrap_or_default();
     let external = props.external.filter(|&x| x);
+    let disabled = props.disabled.filter(|&x| x);
 
     // Build CSS classes
     let mut classes = vec!["link".to_string()];
@@ -96,6 +101,10 @@ pub fn Link(props: LinkProps) -> Element {
     if !color_scheme.to_string().is_empty() {
         classes.push(color_scheme.to_string());
     }
+
+    if disabled.is_some() {
+        classes.push("pointer-events-none opacity-50".to_string());
+    }
     
     if !class.is_empty() {
         classes.push(class);
@@ -103,18 +112,24 @@ pub fn Link(props: LinkProps) -> Element {
 
     let class_string = classes.join(" ");
 
-    // Build rel attribute for external links
-    let rel = if external.is_some() && props.target.as_deref() == Some("_blank") {
+    // Any new tab is cut off from window.opener, external links opt in too
+    let opens_new_tab = props.target.as_deref() == Some("_blank");
+    let rel = if opens_new_tab || external.is_some() {
         Some("noopener noreferrer".to_string())
     } else {
         None
     };
 
+    // A disabled link has nowhere to go
+    let href = if disabled.is_some() { None } else { Some(props.href) };
+    let aria_disabled = disabled.map(|_| "true");
+
     rsx!(
         a {
             class: "{class_string}",
             id: props.id,
-            href: "{props.href}",
+            href: href,
+            "aria-disabled": aria_disabled,
             target: props.target,
             rel: rel,
             {props.children}
@@ -132,6 +147,7 @@ fn test_link_basic() {
         class: None,
         color_scheme: None,
         external: None,
+        disabled: None,
     };
 
     let result = dioxus_ssr::render_element(Link(props));
@@ -162,6 +178,7 @@ fn test_link_with_color_scheme() {
             class: None,
             color_scheme: Some(scheme),
             external: None,
+            disabled: None,
         };
 
         let result = dioxus_ssr::render_element(Link(props));
@@ -181,6 +198,7 @@ fn test_link_with_target() {
         class: None,
         color_scheme: None,
         external: None,
+        disabled: None,
     };
 
     let result = dioxus_ssr::render_element(Link(props));
@@ -197,6 +215,7 @@ fn test_link_external_with_rel() {
         class: None,
         color_scheme: None,
         external: Some(true),
+        disabled: None,
     };
 
     let result = dioxus_ssr::render_element(Link(props));
@@ -213,6 +232,7 @@ fn test_link_with_custom_class() {
         class: Some("custom-class".to_string()),
         color_scheme: None,
         external: None,
+        disabled: None,
     };
 
     let result = dioxus_ssr::render_element(Link(props));
@@ -229,8 +249,45 @@ fn test_link_with_id() {
         class: None,
         color_scheme: None,
         external: None,
+        disabled: None,
     };
 
     let result = dioxus_ssr::render_element(Link(props));
     assert!(result.contains(r#"id="test-link""#));
 }
+
+#[test]
+fn test_link_disabled() {
+    let props = LinkProps {
+        children: rsx!("Current page"),
+        id: None,
+        href: "/docs".to_string(),
+        target: None,
+        class: None,
+        color_scheme: None,
+        external: None,
+        disabled: Some(true),
+    };
+
+    let result = dioxus_ssr::render_element(Link(props));
+    assert!(!result.contains("href"));
+    assert!(result.contains(r#"class="link link-neutral pointer-events-none opacity-50""#));
+    assert!(result.contains(r#"aria-disabled="true""#));
+}
+
+#[test]
+fn test_link_blank_target_always_has_rel() {
+    let props = LinkProps {
+        children: rsx!("Docs"),
+        id: None,
+        href: "/docs".to_string(),
+        target: Some("_blank".to_string()),
+        class: None,
+        color_scheme: None,
+        external: None,
+        disabled: None,
+    };
+
+    let result = dioxus_ssr::render_element(Link(props));
+    assert!(result.contains(r#"rel="noopener noreferrer""#));
+}
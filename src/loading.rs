@@ -0,0 +1,193 @@
+#![allow(non_snake_case)]
+use std::fmt::Display;
+use dioxus::prelude::*;
+
+use crate::size::Size;
+
+/// A Loading component showing an animated loading indicator.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```text
+/// use daisy_rsx::{Loading, LoadingVariant, Size};
+///
+/// Loading {
+///     variant: LoadingVariant::Dots,
+///     size: Size::Sm,
+/// }
+/// ```
+
+/// Animation options for Loading component
+#[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum LoadingVariant {
+    /// Spinner animation (default)
+    #[default]
+    Spinner,
+    /// Dots animation
+    Dots,
+    /// Ring animation
+    Ring,
+    /// Ball animation
+    Ball,
+    /// Bars animation
+    Bars,
+    /// Infinity animation
+    Infinity,
+}
+
+impl Display for LoadingVariant {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LoadingVariant::Spinner => write!(f, "loading-spinner"),
+            LoadingVariant::Dots => write!(f, "loading-dots"),
+            LoadingVariant::Ring => write!(f, "loading-ring"),
+            LoadingVariant::Ball => write!(f, "loading-ball"),
+            LoadingVariant::Bars => write!(f, "loading-bars"),
+            LoadingVariant::Infinity => write!(f, "loading-infinity"),
+        }
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct LoadingProps {
+    /// Optional ID for loading element
+    id: Option<String>,
+    /// Additional CSS classes to apply to loading
+    class: Option<String>,
+    /// Animation of the indicator
+    variant: Option<LoadingVariant>,
+    /// Size of the indicator
+    size: Option<Size>,
+}
+
+#[component]
+pub fn Loading(props: LoadingProps) -> Element {
+    let class = props.class.unwrap_or_default();
+    let variant = props.variant.unwrap_or_default();
+
+    // Build CSS classes
+    let mut classes = vec!["loading".to_string(), variant.to_string()];
+
+    if let Some(size) = props.size {
+        classes.push(size.class("loading"));
+    }
+
+    if !class.is_empty() {
+        classes.push(class);
+    }
+
+    let class_string = classes.join(" ");
+
+    rsx!(
+        span {
+            class: "{class_string}",
+            id: props.id,
+            "aria-label": "Loading",
+        }
+    )
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct LoadingOverlayProps {
+    /// Whether the overlay is shown
+    show: bool,
+    /// Optional ID for the overlay element
+    id: Option<String>,
+    /// Additional CSS classes to apply to the overlay
+    class: Option<String>,
+    /// Animation of the indicator
+    variant: Option<LoadingVariant>,
+    /// Size of the indicator (default: large)
+    size: Option<Size>,
+}
+
+/// A translucent layer with a centered `Loading` indicator that covers its
+/// nearest positioned ancestor.
+///
+/// ```text
+/// Card { class: "relative",
+///     LoadingOverlay { show: saving() }
+///     CardBody { "..." }
+/// }
+/// ```
+#[component]
+pub fn LoadingOverlay(props: LoadingOverlayProps) -> Element {
+    if !props.show {
+        return rsx!();
+    }
+
+    let class = props.class.unwrap_or_default();
+
+    // Build CSS classes
+    let mut classes = vec![
+        "absolute inset-0 z-10 flex items-center justify-center bg-base-100/60".to_string(),
+    ];
+
+    if !class.is_empty() {
+        classes.push(class);
+    }
+
+    let class_string = classes.join(" ");
+
+    rsx!(
+        div {
+            class: "{class_string}",
+            id: props.id,
+            role: "status",
+            "aria-busy": "true",
+            Loading {
+                variant: props.variant,
+                size: props.size.unwrap_or(Size::Lg),
+            }
+        }
+    )
+}
+
+#[test]
+fn test_loading_basic() {
+    let props = LoadingProps {
+        id: None,
+        class: None,
+        variant: None,
+        size: None,
+    };
+
+    let result = dioxus_ssr::render_element(Loading(props));
+    assert!(result.contains(r#"class="loading loading-spinner""#));
+}
+
+#[test]
+fn test_loading_size() {
+    let props = LoadingProps {
+        id: None,
+        class: None,
+        variant: Some(LoadingVariant::Dots),
+        size: Some(Size::Sm),
+    };
+
+    let result = dioxus_ssr::render_element(Loading(props));
+    assert!(result.contains(r#"class="loading loading-dots loading-sm""#));
+}
+
+#[test]
+fn test_loading_overlay_show() {
+    let props = |show| LoadingOverlayProps {
+        show,
+        id: None,
+        class: None,
+        variant: None,
+        size: None,
+    };
+
+    let result = dioxus_ssr::render_element(LoadingOverlay(props(true)));
+    assert!(result.contains(
+        r#"<div class="absolute inset-0 z-10 flex items-center justify-center bg-base-100/60" role="status" aria-busy="true"><span class="loading loading-spinner loading-lg""#
+    ));
+
+    let result = dioxus_ssr::render_element(LoadingOverlay(props(false)));
+    assert!(result.is_empty());
+}
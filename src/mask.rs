@@ -2,6 +2,8 @@
 use std::fmt::Display;
 use dioxus::prelude::*;
 
+use crate::css::css_url;
+
 /// A Mask component that applies shape masks to images and other elements.
 ///
 /// # Examples
@@ -30,6 +32,8 @@ use dioxus::prelude::*;
 
 /// Shape variant options for Mask component
 #[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
 pub enum MaskVariant {
     #[default]
     /// No mask (default)
@@ -64,6 +68,8 @@ impl Display for MaskVariant {
 
 /// Size options for Mask component
 #[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
 pub enum MaskSize {
     #[default]
     /// Default size
@@ -103,6 +109,8 @@ pub struct MaskProps {
     width: Option<String>,
     /// Custom height for the mask
     height: Option<String>,
+    /// Image to mask directly as a cover background, instead of a child img
+    background_image: Option<String>,
 }
 
 #[component]
@@ -136,6 +144,11 @@ pub fn Mask(props: MaskProps) -> Element {
     if let Some(height) = &props.height {
         style_parts.push(format!("height: {}", height));
     }
+    if let Some(image) = &props.background_image {
+        style_parts.push(format!("background-image: {}", css_url(image)));
+        style_parts.push("background-size: cover".to_string());
+        style_parts.push("background-position: center".to_string());
+    }
     let style = if !style_parts.is_empty() {
         Some(style_parts.join("; "))
     } else {
@@ -162,6 +175,7 @@ fn test_mask_basic() {
         size: None,
         width: None,
         height: None,
+        background_image: None,
     };
 
     let result = dioxus_ssr::render_element(Mask(props));
@@ -178,6 +192,7 @@ fn test_mask_circle() {
         size: None,
         width: None,
         height: None,
+        background_image: None,
     };
 
     let result = dioxus_ssr::render_element(Mask(props));
@@ -194,6 +209,7 @@ fn test_mask_square() {
         size: None,
         width: None,
         height: None,
+        background_image: None,
     };
 
     let result = dioxus_ssr::render_element(Mask(props));
@@ -218,6 +234,7 @@ fn test_mask_with_size() {
             size: Some(size),
             width: None,
             height: None,
+            background_image: None,
         };
 
         let result = dioxus_ssr::render_element(Mask(props));
@@ -241,6 +258,7 @@ fn test_mask_with_custom_dimensions() {
         size: None,
         width: Some("100px".to_string()),
         height: Some("100px".to_string()),
+        background_image: None,
     };
 
     let result = dioxus_ssr::render_element(Mask(props));
@@ -257,6 +275,7 @@ fn test_mask_with_custom_class() {
         size: None,
         width: None,
         height: None,
+        background_image: None,
     };
 
     let result = dioxus_ssr::render_element(Mask(props));
@@ -273,8 +292,29 @@ fn test_mask_with_id() {
         size: None,
         width: None,
         height: None,
+        background_image: None,
     };
 
     let result = dioxus_ssr::render_element(Mask(props));
     assert!(result.contains(r#"id="test-mask""#));
 }
+
+#[test]
+fn test_mask_background_image() {
+    let props = MaskProps {
+        children: rsx!(),
+        id: None,
+        class: None,
+        variant: Some(MaskVariant::Hexagon),
+        size: None,
+        width: Some("8rem".to_string()),
+        height: Some("8rem".to_string()),
+        background_image: Some("/avatar.png".to_string()),
+    };
+
+    let result = dioxus_ssr::render_element(Mask(props));
+    assert!(result.contains(r#"class="mask mask-hexagon""#));
+    assert!(result.contains("background-image: url("));
+    assert!(result.contains("/avatar.png"));
+    assert!(result.contains("background-size: cover"));
+}
@@ -2,6 +2,8 @@
 use std::fmt::Display;
 use dioxus::prelude::*;
 
+use crate::density::current_density;
+
 /// A Menu component that creates vertical and horizontal navigation menus with nested items.
 ///
 /// # Examples
@@ -21,6 +23,8 @@ use dioxus::prelude::*;
 
 /// Orientation options for Menu component
 #[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
 pub enum MenuOrientation {
     #[default]
     /// Vertical orientation (default)
@@ -58,6 +62,10 @@ pub fn Menu(props: MenuProps) -> Element {
     // Build CSS classes
     let mut classes = vec!["menu".to_string()];
     classes.push(orientation.to_string());
+
+    if let Some(compact) = current_density().class("menu-sm") {
+        classes.push(compact);
+    }
     
     if !class.is_empty() {
         classes.push(class);
@@ -167,6 +175,164 @@ pub fn MenuTitle(props: MenuTitleProps) -> Element {
     )
 }
 
+#[derive(Props, Clone, PartialEq)]
+pub struct MenuSectionProps {
+    /// The menu items nested under the section
+    children: Element,
+    /// Header shown in the section's summary, styled like a `MenuTitle`
+    title: String,
+    /// Optional ID for the section
+    id: Option<String>,
+    /// Additional CSS classes to apply to the section
+    class: Option<String>,
+    /// Whether the section starts expanded
+    open: Option<bool>,
+}
+
+/// A collapsible group of menu items under a title, for nesting inside a `Menu`.
+///
+/// ```text
+/// use daisy_rsx::{Menu, MenuItem, MenuSection};
+///
+/// Menu {
+///     MenuSection {
+///         title: "Settings",
+///         open: true,
+///         MenuItem { href: "/settings/profile", "Profile" }
+///         MenuItem { href: "/settings/billing", "Billing" }
+///     }
+/// }
+/// ```
+#[component]
+pub fn MenuSection(props: MenuSectionProps) -> Element {
+    let class = props.class.unwrap_or_default();
+    let open = props.open.filter(|&x| x);
+
+    rsx!(
+        li {
+            class: "{class}",
+            id: props.id,
+            details {
+                open: open,
+                summary { class: "menu-title", "{props.title}" }
+                ul {
+                    {props.children}
+                }
+            }
+        }
+    )
+}
+
+/// A single entry in a VirtualMenu
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VirtualMenuItem {
+    /// Identifier passed to `onselect`
+    pub id: String,
+    /// Text shown in the menu
+    pub label: String,
+}
+
+impl VirtualMenuItem {
+    pub fn new(id: impl Into<String>, label: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            label: label.into(),
+        }
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct VirtualMenuProps {
+    /// Items to show, already flattened in display order
+    items: Vec<VirtualMenuItem>,
+    /// Height of the scrolling viewport in pixels
+    viewport_height: f64,
+    /// Called with the id of the chosen item
+    onselect: EventHandler<String>,
+    /// Height of each item in pixels (default: 36)
+    item_height: Option<f64>,
+    /// Extra items rendered above and below the viewport (default: 4)
+    overscan: Option<usize>,
+    /// Id of the item to show as active
+    active: Option<String>,
+    /// Optional ID for the scrolling element
+    id: Option<String>,
+    /// Additional CSS classes to apply to the scrolling element
+    class: Option<String>,
+}
+
+/// The items that are in view when scrolled to `scroll_top`, widened by
+/// `overscan` items on each side.
+fn visible_range(
+    count: usize,
+    item_height: f64,
+    viewport_height: f64,
+    scroll_top: f64,
+    overscan: usize,
+) -> std::ops::Range<usize> {
+    if count == 0 || item_height <= 0.0 {
+        return 0..0;
+    }
+    let first = (scroll_top.max(0.0) / item_height).floor() as usize;
+    let last = ((scroll_top.max(0.0) + viewport_height) / item_height).ceil() as usize;
+    let start = first.saturating_sub(overscan).min(count);
+    let end = (last + overscan).min(count);
+    start..end
+}
+
+/// A vertical menu for long lists, such as file trees with hundreds of
+/// entries. Only the items in view are rendered, with spacers standing in
+/// for the rest so the scrollbar keeps its size.
+///
+/// ```text
+/// VirtualMenu {
+///     items: files.iter().map(|f| VirtualMenuItem::new(&f.path, &f.name)).collect(),
+///     viewport_height: 480.0,
+///     onselect: move |path: String| open_file(&path),
+/// }
+/// ```
+#[component]
+pub fn VirtualMenu(props: VirtualMenuProps) -> Element {
+    let class = props.class.unwrap_or_default();
+    let item_height = props.item_height.unwrap_or(36.0);
+    let overscan = props.overscan.unwrap_or(4);
+    let viewport_height = props.viewport_height;
+    let onselect = props.onselect;
+
+    let mut scroll_top = use_signal(|| 0.0);
+
+    let count = props.items.len();
+    let range = visible_range(count, item_height, viewport_height, scroll_top(), overscan);
+    let top_spacer = range.start as f64 * item_height;
+    let bottom_spacer = (count - range.end) as f64 * item_height;
+
+    rsx!(
+        div {
+            class: "overflow-y-auto {class}",
+            id: props.id,
+            style: "height: {viewport_height}px",
+            onscroll: move |event| scroll_top.set(event.scroll_top()),
+            div { aria_hidden: "true", style: "height: {top_spacer}px" }
+            Menu {
+                class: "w-full flex-nowrap p-0",
+                for item in props.items[range].iter().cloned() {
+                    MenuItem {
+                        key: "{item.id}",
+                        active: props.active.as_ref() == Some(&item.id),
+                        a {
+                            class: "truncate",
+                            style: "height: {item_height}px",
+                            onclick: move |_| onselect.call(item.id.clone()),
+                            "{item.label}"
+                        }
+                    }
+                }
+            }
+            div { aria_hidden: "true", style: "height: {bottom_spacer}px" }
+        }
+    )
+}
+
 #[test]
 fn test_menu_basic() {
     let props = MenuProps {
@@ -259,3 +425,94 @@ fn test_menu_with_custom_class() {
     let result = dioxus_ssr::render_element(Menu(props));
     assert!(result.contains(r#"class="menu menu-vertical custom-class""#));
 }
+
+#[test]
+fn test_menu_section() {
+    let props = MenuSectionProps {
+        children: rsx!(
+            MenuItem { href: "/settings/profile", children: rsx!("Profile") }
+            MenuItem { href: "/settings/billing", children: rsx!("Billing") }
+        ),
+        title: "Settings".to_string(),
+        id: None,
+        class: None,
+        open: Some(true),
+    };
+
+    let result = dioxus_ssr::render_element(MenuSection(props));
+    assert!(result.contains("<details open"));
+    assert!(result.contains(r#"<summary class="menu-title">Settings</summary><ul><li class="menu-item"><a href="/settings/profile">Profile</a></li>"#));
+}
+
+#[test]
+fn test_virtual_menu_renders_window() {
+    use crate::test_utils::rebuild;
+
+    fn app() -> Element {
+        let items: Vec<VirtualMenuItem> = (0..1000)
+            .map(|i| VirtualMenuItem::new(format!("file-{i}"), format!("File {i}")))
+            .collect();
+        rsx!(
+            VirtualMenu {
+                items,
+                viewport_height: 360.0,
+                onselect: |_| {},
+            }
+        )
+    }
+
+    let (dom, _) = rebuild(app);
+    let result = dioxus_ssr::render(&dom);
+    // 10 items fit the viewport, plus 4 overscan below
+    assert_eq!(result.matches("<li").count(), 14);
+    assert!(result.contains("File 13<"));
+    assert!(!result.contains("File 14<"));
+    assert!(!result.contains("File 999<"));
+    assert!(result.contains(r#"style="height: 0px""#));
+    assert!(result.contains(r#"style="height: 35496px""#));
+}
+
+#[test]
+fn test_virtual_menu_visible_range() {
+    assert_eq!(visible_range(1000, 36.0, 360.0, 0.0, 4), 0..14);
+    assert_eq!(visible_range(1000, 36.0, 360.0, 3600.0, 4), 96..114);
+    assert_eq!(visible_range(1000, 36.0, 360.0, 36000.0, 4), 996..1000);
+    assert_eq!(visible_range(5, 36.0, 360.0, 0.0, 4), 0..5);
+    assert_eq!(visible_range(0, 36.0, 360.0, 0.0, 4), 0..0);
+}
+
+#[test]
+fn test_virtual_menu_scroll_and_select() {
+    use crate::test_utils::{fire, listener, rebuild, TestMouseData, TestScrollData};
+
+    fn app() -> Element {
+        let mut selected = use_signal(String::new);
+        let items: Vec<VirtualMenuItem> = (0..1000)
+            .map(|i| VirtualMenuItem::new(format!("file-{i}"), format!("File {i}")))
+            .collect();
+        rsx!(
+            VirtualMenu {
+                items,
+                viewport_height: 360.0,
+                onselect: move |id| selected.set(id),
+            }
+            "Selected: {selected}"
+        )
+    }
+
+    let (mut dom, listeners) = rebuild(app);
+    fire(&mut dom, "click", listener(&listeners, "click", 2), TestMouseData::default());
+    assert!(dioxus_ssr::render(&dom).contains("Selected: file-2"));
+
+    fire(
+        &mut dom,
+        "scroll",
+        listener(&listeners, "scroll", 0),
+        TestScrollData { scroll_top: 3600.0 },
+    );
+    let result = dioxus_ssr::render(&dom);
+    assert!(result.contains("File 96<"));
+    assert!(result.contains("File 113<"));
+    assert!(!result.contains("File 95<"));
+    assert!(!result.contains("File 114<"));
+}
@@ -2,37 +2,229 @@
 
 use dioxus::prelude::*;
 
+use crate::portal::Portal;
+
 #[derive(Props, Clone, PartialEq)]
 pub struct ModalProps {
     trigger_id: String,
     children: Element,
     submit_action: Option<String>,
     class: Option<String>,
+    /// Keep Tab focus inside the modal-box while open and restore focus on close (needs the `web` feature)
+    trap_focus: Option<bool>,
+    /// Open state, kept in sync when the dialog closes itself (adds `modal-open` while true)
+    open: Option<Signal<bool>>,
+    /// Called when the modal is dismissed
+    onclose: Option<EventHandler<()>>,
+    /// Whether Escape dismisses the modal (defaults to true)
+    close_on_escape: Option<bool>,
+    /// Whether clicking the backdrop dismisses the modal (defaults to true)
+    close_on_backdrop: Option<bool>,
 }
 
 #[component]
 pub fn Modal(props: ModalProps) -> Element {
+    #[cfg(feature = "web")]
+    let trap_focus = props.trap_focus.unwrap_or(false);
+    let close_on_escape = props.close_on_escape.unwrap_or(true);
+    let close_on_backdrop = props.close_on_backdrop.unwrap_or(true);
+    let open = props.open;
+    let onclose = props.onclose;
+    let class = props.class.clone().unwrap_or_default();
+    let open_class = if open.is_some_and(|open| open()) { " modal-open" } else { "" };
+
+    // The open signal doubles as a guard so Escape, cancel and close
+    // firing together only report one dismissal
+    let close = move || {
+        if let Some(mut open) = open {
+            if !open() {
+                return;
+            }
+            open.set(false);
+        }
+        if let Some(handler) = onclose {
+            handler.call(());
+        }
+    };
+
+    #[cfg(feature = "web")]
+    let trigger_id = props.trigger_id.clone();
+    let onkeydown = move |event: KeyboardEvent| {
+        if event.key() == Key::Escape {
+            if close_on_escape {
+                close();
+            } else {
+                event.prevent_default();
+            }
+        }
+        #[cfg(feature = "web")]
+        if trap_focus {
+            dom::on_keydown(&trigger_id, &event);
+        }
+    };
+    let oncancel = move |event: CancelEvent| {
+        if close_on_escape {
+            close();
+        } else {
+            event.prevent_default();
+        }
+    };
+    // Dioxus has no `onclose` listener, so light dismissal is picked up
+    // from the popover toggle instead
+    #[cfg(feature = "web")]
+    let trigger_id = props.trigger_id.clone();
+    let ontoggle = move |_: ToggleEvent| {
+        #[cfg(feature = "web")]
+        {
+            let is_open = dom::is_open(&trigger_id);
+            if trap_focus {
+                dom::on_toggle(&trigger_id, is_open);
+            }
+            if !is_open {
+                close();
+            }
+        }
+    };
+
+    // The backdrop sits behind the modal-box, so clicks on the box never reach it
+    let backdrop = close_on_backdrop.then(|| rsx!(
+        button {
+            class: "modal-backdrop",
+            r#type: "button",
+            "popovertarget": "{props.trigger_id}",
+            "popovertargetaction": "hide",
+            onclick: move |_| close(),
+            "close"
+        }
+    ));
+
     rsx!(
-        if let Some(action) = &props.submit_action {
-            form { action: "{action}", method: "post",
+        Portal {
+            if let Some(action) = &props.submit_action {
+                form { action: "{action}", method: "post",
+                    dialog {
+                        class: "modal{open_class} {class}",
+                        id: "{props.trigger_id}",
+                        popover: "auto",
+                        onkeydown,
+                        oncancel,
+                        ontoggle,
+                        {props.children}
+                        {backdrop}
+                    }
+                }
+            } else {
                 dialog {
-                    class: "modal {props.class.clone().unwrap_or_default()}",
+                    class: "modal{open_class} {class}",
                     id: "{props.trigger_id}",
                     popover: "auto",
+                    onkeydown,
+                    oncancel,
+                    ontoggle,
                     {props.children}
+                    {backdrop}
                 }
             }
-        } else {
-            dialog {
-                class: "modal {props.class.clone().unwrap_or_default()}",
-                id: "{props.trigger_id}",
-                popover: "auto",
-                {props.children}
-            }
         }
     )
 }
 
+/// Index to move focus to when tabbing out of either end of the list,
+/// or `None` when the browser's default order should apply.
+#[cfg(any(feature = "web", test))]
+fn wrap_focus(current: Option<usize>, len: usize, backwards: bool) -> Option<usize> {
+    if len == 0 {
+        return None;
+    }
+    match (current, backwards) {
+        (Some(0), true) | (None, true) => Some(len - 1),
+        (Some(index), false) if index == len - 1 => Some(0),
+        (None, false) => Some(0),
+        _ => None,
+    }
+}
+
+#[cfg(feature = "web")]
+mod dom {
+    use std::cell::RefCell;
+    use std::collections::HashMap;
+
+    use dioxus::prelude::*;
+    use wasm_bindgen::JsCast;
+    use web_sys::HtmlElement;
+
+    use super::wrap_focus;
+
+    const FOCUSABLE: &str = "a[href], button:not([disabled]), input:not([disabled]), \
+        select:not([disabled]), textarea:not([disabled]), [tabindex]:not([tabindex='-1'])";
+
+    thread_local! {
+        // Element focused before each modal opened, keyed by modal id
+        static RETURN_FOCUS: RefCell<HashMap<String, HtmlElement>> = RefCell::new(HashMap::new());
+    }
+
+    fn focusable(modal_id: &str) -> Vec<HtmlElement> {
+        let Some(document) = web_sys::window().and_then(|window| window.document()) else {
+            return Vec::new();
+        };
+        let selector = format!("#{} .modal-box", modal_id);
+        let Ok(Some(modal_box)) = document.query_selector(&selector) else {
+            return Vec::new();
+        };
+        let Ok(nodes) = modal_box.query_selector_all(FOCUSABLE) else {
+            return Vec::new();
+        };
+        (0..nodes.length())
+            .filter_map(|index| nodes.get(index))
+            .filter_map(|node| node.dyn_into::<HtmlElement>().ok())
+            .collect()
+    }
+
+    fn active_element() -> Option<HtmlElement> {
+        web_sys::window()
+            .and_then(|window| window.document())
+            .and_then(|document| document.active_element())
+            .and_then(|element| element.dyn_into::<HtmlElement>().ok())
+    }
+
+    pub(super) fn on_keydown(modal_id: &str, event: &KeyboardEvent) {
+        if event.key() != Key::Tab {
+            return;
+        }
+        let elements = focusable(modal_id);
+        let active = active_element();
+        let current = elements
+            .iter()
+            .position(|element| Some(element) == active.as_ref());
+        let backwards = event.modifiers().shift();
+        if let Some(target) = wrap_focus(current, elements.len(), backwards) {
+            event.prevent_default();
+            let _ = elements[target].focus();
+        }
+    }
+
+    pub(super) fn is_open(modal_id: &str) -> bool {
+        web_sys::window()
+            .and_then(|window| window.document())
+            .and_then(|document| document.get_element_by_id(modal_id))
+            .and_then(|dialog| dialog.matches(":popover-open").ok())
+            .unwrap_or(false)
+    }
+
+    pub(super) fn on_toggle(modal_id: &str, open: bool) {
+        if open {
+            if let Some(active) = active_element() {
+                RETURN_FOCUS.with(|map| map.borrow_mut().insert(modal_id.to_string(), active));
+            }
+            if let Some(first) = focusable(modal_id).first() {
+                let _ = first.focus();
+            }
+        } else if let Some(previous) = RETURN_FOCUS.with(|map| map.borrow_mut().remove(modal_id)) {
+            let _ = previous.focus();
+        }
+    }
+}
+
 #[derive(Props, Clone, PartialEq)]
 pub struct ModalBodyProps {
     children: Element,
@@ -42,7 +234,10 @@ pub struct ModalBodyProps {
 #[component]
 pub fn ModalBody(props: ModalBodyProps) -> Element {
     rsx!(
-        div { class: "modal-box {props.class.clone().unwrap_or_default()}", {props.children} }
+        div {
+            class: "modal-box {props.class.clone().unwrap_or_default()}",
+            {props.children}
+        }
     )
 }
 
@@ -59,6 +254,14 @@ pub fn ModalAction(props: ModalActionProps) -> Element {
     )
 }
 
+#[test]
+fn test_focus_wraps_from_last_to_first() {
+    assert_eq!(wrap_focus(Some(2), 3, false), Some(0));
+    assert_eq!(wrap_focus(Some(0), 3, true), Some(2));
+    assert_eq!(wrap_focus(Some(1), 3, false), None);
+    assert_eq!(wrap_focus(None, 0, false), None);
+}
+
 #[test]
 fn test_modal() {
     let props = ModalProps {
@@ -66,10 +269,18 @@ fn test_modal() {
         class: Some("test".to_string()),
         submit_action: Some("test".to_string()),
         trigger_id: "id".to_string(),
+        trap_focus: None,
+        open: None,
+        onclose: None,
+        close_on_escape: None,
+        close_on_backdrop: None,
     };
 
-    let expected = r#"<form action="test" method="post"><dialog class="modal test" id="id" popover="auto">Hello</dialog></form>"#;
-    let result = dioxus_ssr::render_element(Modal(props));
+    let expected = r#"<div class="contents" data-portal="true"><form action="test" method="post"><dialog class="modal test" id="id" popover="auto">Hello<button class="modal-backdrop" type="button" popovertarget="id" popovertargetaction="hide">close</button></dialog></form></div>"#;
+    // Modal attaches listeners, so it has to render inside a runtime
+    let mut dom = VirtualDom::new_with_props(Modal, props);
+    dom.rebuild_in_place();
+    let result = dioxus_ssr::render(&dom);
     // println!("{}", result);
     assert_eq!(expected, result);
 }
@@ -81,10 +292,102 @@ fn test_modal_without_submit_action() {
         class: Some("test".to_string()),
         submit_action: None,
         trigger_id: "id".to_string(),
+        trap_focus: None,
+        open: None,
+        onclose: None,
+        close_on_escape: None,
+        close_on_backdrop: None,
     };
 
-    let expected = r#"<dialog class="modal test" id="id" popover="auto">Hello</dialog>"#;
-    let result = dioxus_ssr::render_element(Modal(props));
+    let expected = r#"<div class="contents" data-portal="true"><dialog class="modal test" id="id" popover="auto">Hello<button class="modal-backdrop" type="button" popovertarget="id" popovertargetaction="hide">close</button></dialog></div>"#;
+    // Modal attaches listeners, so it has to render inside a runtime
+    let mut dom = VirtualDom::new_with_props(Modal, props);
+    dom.rebuild_in_place();
+    let result = dioxus_ssr::render(&dom);
     // println!("{}", result);
     assert_eq!(expected, result);
 }
+
+#[test]
+fn test_modal_cancel_updates_open_signal() {
+    use crate::test_utils::{fire, listener, rebuild, TestEmptyData, TestKeyboardData};
+
+    fn app() -> Element {
+        let open = use_signal(|| true);
+        let mut closed = use_signal(|| 0);
+        rsx!(
+            Modal {
+                trigger_id: "dialog",
+                open,
+                onclose: move |_| closed += 1,
+                ModalBody { "Closed {closed}" }
+            }
+        )
+    }
+
+    let (mut dom, listeners) = rebuild(app);
+    assert!(dioxus_ssr::render(&dom).contains(r#"class="modal modal-open ""#));
+
+    fire(&mut dom, "cancel", listener(&listeners, "cancel", 0), TestEmptyData);
+    let result = dioxus_ssr::render(&dom);
+    assert!(result.contains(r#"class="modal ""#));
+    assert!(result.contains("Closed 1"));
+
+    // A following Escape keydown must not report a second dismissal
+    fire(&mut dom, "keydown", listener(&listeners, "keydown", 0), TestKeyboardData::new(Key::Escape));
+    assert!(dioxus_ssr::render(&dom).contains("Closed 1"));
+}
+
+#[test]
+fn test_modal_backdrop_click_closes() {
+    use crate::test_utils::{fire, listener, rebuild, TestMouseData};
+
+    fn app() -> Element {
+        let open = use_signal(|| true);
+        let mut closed = use_signal(|| 0);
+        let mut clicks = use_signal(|| 0);
+        rsx!(
+            Modal {
+                trigger_id: "dialog",
+                open,
+                onclose: move |_| closed += 1,
+                ModalBody {
+                    button { onclick: move |_| clicks += 1, "Clicked {clicks}" }
+                    "Closed {closed}"
+                }
+            }
+        )
+    }
+
+    let (mut dom, listeners) = rebuild(app);
+    let result = dioxus_ssr::render(&dom);
+    assert!(result.contains(r#"<button class="modal-backdrop" type="button" popovertarget="dialog" popovertargetaction="hide">close</button>"#));
+
+    // The backdrop is created with the dialog, before the nested ModalBody
+    let backdrop = listener(&listeners, "click", 0);
+    let inside = listener(&listeners, "click", 1);
+
+    // The backdrop is a sibling of the box, so clicks inside never reach it
+    fire(&mut dom, "click", inside, TestMouseData::default());
+    let result = dioxus_ssr::render(&dom);
+    assert!(result.contains("Clicked 1"));
+    assert!(result.contains("Closed 0"));
+
+    fire(&mut dom, "click", backdrop, TestMouseData::default());
+    let result = dioxus_ssr::render(&dom);
+    assert!(result.contains("Closed 1"));
+    assert!(!result.contains("modal-open"));
+}
+
+#[test]
+fn test_modal_without_backdrop_close() {
+    fn app() -> Element {
+        rsx!(
+            Modal { trigger_id: "dialog", close_on_backdrop: false, ModalBody { "Hello" } }
+        )
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    assert!(!dioxus_ssr::render(&dom).contains("modal-backdrop"));
+}
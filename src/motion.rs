@@ -0,0 +1,131 @@
+#![allow(non_snake_case)]
+use dioxus::prelude::*;
+
+use crate::context::try_context;
+
+/// A MotionProvider sets whether `Swap`, `Carousel`, `Progress` and `Toast`
+/// animate for everything inside it. Without an explicit preference it follows
+/// the `prefers-reduced-motion` media query, which needs the `web` feature.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```text
+/// use daisy_rsx::{MotionPreference, MotionProvider, Swap, SwapAnimation};
+///
+/// MotionProvider {
+///     preference: MotionPreference::Reduced,
+///     Swap { animation: SwapAnimation::Flip, ... }
+/// }
+/// ```
+
+/// Whether components play their animations
+#[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum MotionPreference {
+    #[default]
+    /// Animations play as usual
+    Full,
+    /// Animations are dropped in favor of static states
+    Reduced,
+}
+
+/// The preference of the nearest `MotionProvider`. Reading it subscribes the
+/// reader, so renders and resources follow a changed preference.
+pub(crate) fn motion_preference() -> Option<Memo<MotionPreference>> {
+    try_context::<Memo<MotionPreference>>()
+}
+
+/// Whether the nearest `MotionProvider` asks for reduced motion. Outside a
+/// provider, or outside a runtime, components animate.
+pub(crate) fn reduced_motion() -> bool {
+    motion_preference().is_some_and(|preference| preference() == MotionPreference::Reduced)
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct MotionProviderProps {
+    /// The content that picks up the preference
+    children: Element,
+    /// Preference for the components inside, detected from the browser when not set
+    preference: Option<MotionPreference>,
+}
+
+#[component]
+pub fn MotionProvider(props: MotionProviderProps) -> Element {
+    // The media query is read once, when the provider mounts without a preference
+    #[cfg(feature = "web")]
+    let detected = use_hook(|| {
+        props.preference.map_or_else(dom::detect_preference, |_| MotionPreference::default())
+    });
+    #[cfg(not(feature = "web"))]
+    let detected = MotionPreference::default();
+
+    // Provided as a memo so a changed preference reaches the children
+    let preference = use_memo(use_reactive(&props.preference, move |preference| {
+        preference.unwrap_or(detected)
+    }));
+    use_context_provider(|| preference);
+
+    rsx!({ props.children })
+}
+
+#[cfg(feature = "web")]
+mod dom {
+    use super::MotionPreference;
+
+    /// Reads `prefers-reduced-motion` from the browser.
+    pub(super) fn detect_preference() -> MotionPreference {
+        let reduced = web_sys::window()
+            .and_then(|window| window.match_media("(prefers-reduced-motion: reduce)").ok())
+            .flatten()
+            .is_some_and(|query| query.matches());
+        if reduced {
+            MotionPreference::Reduced
+        } else {
+            MotionPreference::Full
+        }
+    }
+}
+
+#[test]
+fn test_motion_reduced_drops_animation_classes() {
+    use crate::carousel::Carousel;
+    use crate::progress::Progress;
+    use crate::swap::{Swap, SwapAnimation, SwapItem};
+    use crate::toast::{Toast, ToastType};
+
+    let result = dioxus_ssr::render_element(rsx!(
+        MotionProvider {
+            preference: MotionPreference::Reduced,
+            Swap {
+                animation: SwapAnimation::Flip,
+                SwapItem { "On" }
+                SwapItem { "Off" }
+            }
+            Progress { indeterminate: true }
+            Carousel { auto_play: true, div { "Slide" } }
+            Toast { r#type: ToastType::Info, duration_ms: 3000, "Saved" }
+        }
+    ));
+    assert!(result.contains(r#"class="swap""#));
+    assert!(result.contains(r#"class="progress progress-primary" value="0""#));
+    assert!(result.contains(r#"aria-busy="true""#));
+    assert!(result.contains(r#"class="carousel""#));
+    assert!(!result.contains("<progress class=\"progress progress-info"));
+}
+
+#[test]
+fn test_motion_defaults_to_full() {
+    use crate::swap::{Swap, SwapAnimation, SwapItem};
+
+    let result = dioxus_ssr::render_element(rsx!(
+        Swap {
+            animation: SwapAnimation::Flip,
+            SwapItem { "On" }
+            SwapItem { "Off" }
+        }
+    ));
+    assert!(result.contains(r#"class="swap swap-flip""#));
+}
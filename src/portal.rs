@@ -0,0 +1,121 @@
+#![allow(non_snake_case)]
+use dioxus::prelude::*;
+
+/// A Portal component that mounts its children at the end of `document.body`,
+/// so overlays like `Modal` and `ToastContainer` escape `overflow: hidden` and
+/// stacking contexts of the component that declares them.
+///
+/// Moving the content needs the `web` feature. Without it, or when rendered on
+/// the server, the children stay where the Portal is declared.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```text
+/// use daisy_rsx::Portal;
+///
+/// Portal {
+///     div { class: "toast", "Saved" }
+/// }
+/// ```
+
+#[derive(Props, Clone, PartialEq)]
+pub struct PortalProps {
+    /// The content to mount at the body
+    children: Element,
+}
+
+#[component]
+pub fn Portal(props: PortalProps) -> Element {
+    #[allow(unused_mut)]
+    let mut listeners: Vec<Attribute> = vec![];
+
+    #[cfg(feature = "web")]
+    {
+        let moved = use_hook(|| std::rc::Rc::new(dom::MovedElement::default()));
+        listeners.push(dioxus_elements::events::onmounted(move |event: MountedEvent| {
+            moved.move_to_body(&event)
+        }));
+    }
+
+    rsx!(
+        div {
+            class: "contents",
+            "data-portal": "true",
+            ..listeners,
+            {props.children}
+        }
+    )
+}
+
+#[cfg(feature = "web")]
+mod dom {
+    use std::cell::RefCell;
+
+    use dioxus::prelude::*;
+
+    /// The portal's element once it has been moved to the body, removed again
+    /// when the portal is dropped.
+    #[derive(Default)]
+    pub(super) struct MovedElement(RefCell<Option<web_sys::Element>>);
+
+    impl MovedElement {
+        pub(super) fn move_to_body(&self, event: &MountedEvent) {
+            let Some(element) = event.data().downcast::<web_sys::Element>().cloned() else {
+                return;
+            };
+            let body = web_sys::window()
+                .and_then(|window| window.document())
+                .and_then(|document| document.body());
+            if let Some(body) = body {
+                let _ = body.append_child(&element);
+                self.0.replace(Some(element));
+            }
+        }
+    }
+
+    impl Drop for MovedElement {
+        fn drop(&mut self) {
+            if let Some(element) = self.0.take() {
+                element.remove();
+            }
+        }
+    }
+}
+
+#[test]
+fn test_portal_renders_children() {
+    let result = dioxus_ssr::render_element(rsx!(
+        div { class: "card", Portal { span { "Floating" } } }
+    ));
+    assert_eq!(
+        result,
+        r#"<div class="card"><div class="contents" data-portal="true"><span>Floating</span></div></div>"#
+    );
+}
+
+#[cfg(all(feature = "web", target_arch = "wasm32"))]
+#[wasm_bindgen_test::wasm_bindgen_test]
+async fn test_portal_mounts_children_at_body() {
+    use crate::test_utils::{mount, settle};
+
+    fn app() -> Element {
+        rsx!(
+            div { id: "declared", class: "overflow-hidden",
+                Portal { span { id: "portaled", "Floating" } }
+            }
+        )
+    }
+
+    let root = mount(app).await;
+    settle().await;
+    let document = web_sys::window().unwrap().document().unwrap();
+    let declared = document.get_element_by_id("declared").unwrap();
+    let portaled = document.get_element_by_id("portaled").unwrap();
+
+    assert!(root.contains(Some(declared.as_ref())));
+    assert!(!declared.contains(Some(portaled.as_ref())));
+    let portal = portaled.closest("[data-portal]").unwrap().unwrap();
+    assert_eq!(portal.parent_element(), document.body().map(Into::into));
+}
@@ -0,0 +1,103 @@
+//! Everything needed to build pages with daisy_rsx in one import:
+//!
+//! ```text
+//! use daisy_rsx::prelude::*;
+//! ```
+//!
+//! Brings in every component, its props and its style enums. The marketing
+//! sections stay under `daisy_rsx::marketing`, since names like `Hero` clash.
+
+pub use crate::accordian::*;
+pub use crate::alert::*;
+pub use crate::app_layout::*;
+pub use crate::artboard::*;
+pub use crate::avatar::*;
+pub use crate::badge::*;
+pub use crate::blank_slate::*;
+pub use crate::breadcrumb::*;
+pub use crate::breakpoint::*;
+pub use crate::button::*;
+pub use crate::button_ui::*;
+pub use crate::calendar::*;
+pub use crate::card::*;
+pub use crate::carousel::*;
+pub use crate::chat::*;
+pub use crate::check_box::*;
+pub use crate::code::*;
+pub use crate::collapse::*;
+pub use crate::command_palette::*;
+pub use crate::comments::*;
+pub use crate::container::*;
+pub use crate::countdown::*;
+pub use crate::density::*;
+pub use crate::divider::*;
+pub use crate::drawer::*;
+pub use crate::drop_down::*;
+pub use crate::fieldset::*;
+pub use crate::file_input::*;
+pub use crate::footer::*;
+pub use crate::hero::*;
+pub use crate::indicator::*;
+pub use crate::input::*;
+pub use crate::input_group::*;
+pub use crate::join::*;
+pub use crate::kbd::*;
+pub use crate::link::*;
+pub use crate::loading::*;
+pub use crate::mask::*;
+pub use crate::menu::*;
+pub use crate::modal::*;
+pub use crate::motion::*;
+pub use crate::nav_item::*;
+pub use crate::navbar::*;
+pub use crate::pagination::*;
+pub use crate::portal::*;
+pub use crate::progress::*;
+pub use crate::radio::*;
+pub use crate::range::*;
+pub use crate::rating::*;
+pub use crate::relative_time::*;
+pub use crate::select::*;
+pub use crate::show::*;
+pub use crate::size::*;
+pub use crate::skeleton::*;
+pub use crate::stack::*;
+pub use crate::stats::*;
+pub use crate::status::*;
+pub use crate::steps::*;
+pub use crate::swap::*;
+pub use crate::tab_container::*;
+pub use crate::table::*;
+// `tabs` and `tab_container` both define a `TabPanel`; like the crate root,
+// the prelude uses the `TabContainer` one
+pub use crate::tabs::{Tab, TabProps, Tabs, TabsOrientation, TabsProps};
+pub use crate::text_area::*;
+pub use crate::theme::*;
+pub use crate::time_line::*;
+pub use crate::timeline::*;
+pub use crate::toast::*;
+pub use crate::toggle::*;
+pub use crate::tooltip::*;
+
+#[test]
+fn test_prelude_exports() {
+    use crate::prelude::*;
+    use dioxus::prelude::Element;
+
+    let _: fn(ButtonUIProps) -> Element = ButtonUI;
+    let _: fn(TableProps) -> Element = Table;
+    let _: fn(TabPanelProps) -> Element = TabPanel;
+    let _: fn(ProgressMultiProps) -> Element = ProgressMulti;
+    let _ = (
+        ButtonUIColorScheme::Primary,
+        TableSize::Small,
+        ThemeName::Dracula,
+        ProgressColorScheme::Success,
+        SortDirection::Ascending,
+        Size::Lg,
+        Breakpoint::Md,
+        Density::Compact,
+    );
+    let _ = use_theme;
+    let _ = PaletteCommand::new("id", "Label");
+}
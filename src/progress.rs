@@ -2,6 +2,9 @@
 use std::fmt::Display;
 use dioxus::prelude::*;
 
+use crate::class::ClassBuilder;
+use crate::motion::reduced_motion;
+
 /// A Progress component that displays progress indicators.
 ///
 /// # Examples
@@ -29,6 +32,8 @@ use dioxus::prelude::*;
 
 /// Color scheme options for Progress component
 #[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
 pub enum ProgressColorScheme {
     #[default]
     /// Primary brand color scheme
@@ -61,8 +66,38 @@ impl Display for ProgressColorScheme {
     }
 }
 
+impl ProgressColorScheme {
+    /// Background class for a plain element in this color, used by ProgressMulti segments
+    fn background(&self) -> &'static str {
+        match self {
+            ProgressColorScheme::Primary => "bg-primary",
+            ProgressColorScheme::Secondary => "bg-secondary",
+            ProgressColorScheme::Accent => "bg-accent",
+            ProgressColorScheme::Info => "bg-info",
+            ProgressColorScheme::Success => "bg-success",
+            ProgressColorScheme::Warning => "bg-warning",
+            ProgressColorScheme::Error => "bg-error",
+        }
+    }
+
+    /// Text color class, used by RadialProgress which draws in `currentColor`
+    fn text(&self) -> &'static str {
+        match self {
+            ProgressColorScheme::Primary => "text-primary",
+            ProgressColorScheme::Secondary => "text-secondary",
+            ProgressColorScheme::Accent => "text-accent",
+            ProgressColorScheme::Info => "text-info",
+            ProgressColorScheme::Success => "text-success",
+            ProgressColorScheme::Warning => "text-warning",
+            ProgressColorScheme::Error => "text-error",
+        }
+    }
+}
+
 /// Size options for Progress component
 #[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
 pub enum ProgressSize {
     #[default]
     /// Default size
@@ -109,52 +144,284 @@ pub fn Progress(props: ProgressProps) -> Element {
     let color_scheme = props.color_scheme.unwrap_or_default();
     let size = props.size.unwrap_or_default();
     let class = props.class.unwrap_or_default();
+    // Under reduced motion an indeterminate bar renders as an empty, static one
     let indeterminate = props.indeterminate.filter(|&x| x);
+    let reduced = indeterminate.is_some() && reduced_motion();
     let value = props.value.unwrap_or(0.0);
     let max = props.max.unwrap_or(100.0);
 
     // Build CSS classes
-    let mut classes = vec!["progress".to_string()];
-    
-    if !color_scheme.to_string().is_empty() {
-        classes.push(color_scheme.to_string());
+    let class_string = ClassBuilder::new("progress")
+        .push(color_scheme)
+        .push(size)
+        .push_if(indeterminate.is_some() && !reduced, "progress-indeterminate")
+        .push(class)
+        .build();
+
+    // Without a value the browser animates the native element as indeterminate
+    let value = if reduced {
+        Some("0".to_string())
+    } else {
+        indeterminate.is_none().then(|| value.to_string())
+    };
+
+    rsx!(
+        progress {
+            class: "{class_string}",
+            id: props.id,
+            value,
+            "aria-busy": reduced.then_some("true"),
+            max: "{max}",
+        }
+    )
+}
+
+/// Share of `max` reached by `value`, clamped to 0-100
+fn percentage(value: f64, max: f64) -> f64 {
+    if max <= 0.0 {
+        return 0.0;
     }
-    
-    if !size.to_string().is_empty() {
-        classes.push(size.to_string());
+    (value / max * 100.0).clamp(0.0, 100.0)
+}
+
+/// Where ProgressWithLabel shows its percentage text
+#[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum LabelPosition {
+    /// Centered on top of the bar
+    Inside,
+    #[default]
+    /// Beside the bar, on its right (default)
+    Right,
+    /// Only announced to screen readers
+    Hidden,
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct ProgressWithLabelProps {
+    /// Optional ID for the progress element
+    id: Option<String>,
+    /// Additional CSS classes to apply to the wrapper
+    class: Option<String>,
+    /// Current value of the progress
+    value: f64,
+    /// Maximum value of the progress (default: 100)
+    max: Option<f64>,
+    /// Color scheme for the progress bar
+    color_scheme: Option<ProgressColorScheme>,
+    /// Size of the progress bar
+    size: Option<ProgressSize>,
+    /// Where the label goes (default: `Right`)
+    label_position: Option<LabelPosition>,
+    /// Label text, with `{pct}` replaced by the rounded percentage (default: `{pct}%`)
+    label_format: Option<String>,
+}
+
+/// The label for `value` out of `max`, e.g. `50%` or `50% done`.
+fn progress_label(value: f64, max: f64, format: &str) -> String {
+    let pct = format!("{:.0}", percentage(value, max));
+    format.replace("{pct}", &pct)
+}
+
+/// A Progress bar with its percentage as text, e.g. on a dashboard.
+///
+/// ```text
+/// ProgressWithLabel {
+///     value: 42.0,
+///     label_position: LabelPosition::Inside,
+///     label_format: "{pct}% uploaded",
+/// }
+/// ```
+#[component]
+pub fn ProgressWithLabel(props: ProgressWithLabelProps) -> Element {
+    let class = props.class.unwrap_or_default();
+    let max = props.max.unwrap_or(100.0);
+    let format = props.label_format.unwrap_or_else(|| "{pct}%".to_string());
+    let label = progress_label(props.value, max, &format);
+    let wrapper = |base: &str| ClassBuilder::new(base).push(&class).build();
+
+    match props.label_position.unwrap_or_default() {
+        LabelPosition::Inside => rsx!(
+            div {
+                class: wrapper("relative"),
+                Progress {
+                    id: props.id,
+                    class: "h-4 w-full",
+                    value: props.value,
+                    max,
+                    color_scheme: props.color_scheme,
+                    size: props.size,
+                }
+                span {
+                    class: "absolute inset-0 flex items-center justify-center text-xs",
+                    "{label}"
+                }
+            }
+        ),
+        LabelPosition::Right => rsx!(
+            div {
+                class: wrapper("flex items-center gap-2"),
+                Progress {
+                    id: props.id,
+                    class: "flex-1",
+                    value: props.value,
+                    max,
+                    color_scheme: props.color_scheme,
+                    size: props.size,
+                }
+                span { class: "text-sm tabular-nums", "{label}" }
+            }
+        ),
+        LabelPosition::Hidden => rsx!(
+            div {
+                class: wrapper(""),
+                Progress {
+                    id: props.id,
+                    class: "w-full",
+                    value: props.value,
+                    max,
+                    color_scheme: props.color_scheme,
+                    size: props.size,
+                }
+                span { class: "sr-only", "{label}" }
+            }
+        ),
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct ProgressMultiProps {
+    /// Optional ID for the bar element
+    id: Option<String>,
+    /// Additional CSS classes to apply to the bar
+    class: Option<String>,
+    /// Segments as (percentage, color) pairs, scaled down if they add up to more than 100
+    segments: Vec<(f64, ProgressColorScheme)>,
+    /// Accessible label for the whole bar, e.g. "Storage used"
+    label: Option<String>,
+}
+
+/// A single bar split into colored segments, e.g. storage used per file type.
+///
+/// ```text
+/// ProgressMulti {
+///     label: "Storage used",
+///     segments: vec![
+///         (40.0, ProgressColorScheme::Primary),
+///         (25.0, ProgressColorScheme::Warning),
+///     ],
+/// }
+/// ```
+#[component]
+pub fn ProgressMulti(props: ProgressMultiProps) -> Element {
+    let class = props.class.unwrap_or_default();
+    let total: f64 = props.segments.iter().map(|(value, _)| value.max(0.0)).sum();
+    let scale = total.max(100.0);
+    let count = props.segments.len();
+
+    // Build CSS classes
+    let mut classes = vec![
+        "flex w-full h-2 overflow-hidden rounded-box bg-base-300".to_string(),
+    ];
+
+    if !class.is_empty() {
+        classes.push(class);
     }
-    
-    if indeterminate.is_some() {
-        classes.push("progress-indeterminate".to_string());
+
+    let class_string = classes.join(" ");
+
+    rsx!(
+        div {
+            class: "{class_string}",
+            id: props.id,
+            role: "group",
+            aria_label: props.label,
+            for (index, (value, color)) in props.segments.into_iter().enumerate() {
+                {
+                    let pct = value.max(0.0) / scale * 100.0;
+                    rsx!(
+                        div {
+                            class: "h-full {color.background()}",
+                            role: "progressbar",
+                            "aria-valuenow": "{pct}",
+                            "aria-valuemin": "0",
+                            "aria-valuemax": "100",
+                            aria_label: "Segment {index + 1} of {count}: {pct}%",
+                            style: "width: {pct}%",
+                        }
+                    )
+                }
+            }
+        }
+    )
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct RadialProgressProps {
+    /// The label shown in the middle of the circle
+    children: Element,
+    /// Optional ID for the progress element
+    id: Option<String>,
+    /// Additional CSS classes to apply to the progress element
+    class: Option<String>,
+    /// Current value as a percentage (0-100)
+    value: f64,
+    /// Color of the filled arc
+    color_scheme: Option<ProgressColorScheme>,
+    /// Diameter of the circle as a CSS length, e.g. `6rem` (emitted as `--size`)
+    size: Option<String>,
+    /// Width of the arc as a CSS length, e.g. `4px` (emitted as `--thickness`)
+    thickness: Option<String>,
+}
+
+/// A circular progress indicator, e.g. as the figure of a stat.
+///
+/// ```text
+/// RadialProgress {
+///     value: 70.0,
+///     color_scheme: ProgressColorScheme::Success,
+///     size: "6rem",
+///     thickness: "4px",
+///     "70%"
+/// }
+/// ```
+#[component]
+pub fn RadialProgress(props: RadialProgressProps) -> Element {
+    let class = props.class.unwrap_or_default();
+    let value = props.value.clamp(0.0, 100.0);
+
+    // Build CSS classes
+    let mut classes = vec!["radial-progress".to_string()];
+
+    if let Some(color_scheme) = props.color_scheme {
+        classes.push(color_scheme.text().to_string());
     }
-    
+
     if !class.is_empty() {
         classes.push(class);
     }
 
     let class_string = classes.join(" ");
 
-    // Calculate percentage for determinate progress
-    let percentage = if indeterminate.is_none() {
-        let pct = (value / max * 100.0).min(100.0).max(0.0);
-        Some(format!("{}%", pct))
-    } else {
-        None
-    };
+    let mut style = format!("--value:{value};");
+    if let Some(size) = props.size.filter(|size| !size.is_empty()) {
+        style.push_str(&format!("--size:{size};"));
+    }
+    if let Some(thickness) = props.thickness.filter(|thickness| !thickness.is_empty()) {
+        style.push_str(&format!("--thickness:{thickness};"));
+    }
 
     rsx!(
         div {
             class: "{class_string}",
-            r#role: "progressbar",
             id: props.id,
+            style: "{style}",
+            role: "progressbar",
             "aria-valuenow": "{value}",
             "aria-valuemin": "0",
-            "aria-valuemax": "{max}",
-            style: if percentage.is_some() {
-                format!("width: {}", percentage.unwrap())
-            } else {
-                "".to_string()
-            },
+            "aria-valuemax": "100",
+            {props.children}
         }
     )
 }
@@ -172,7 +439,9 @@ fn test_progress_basic() {
     };
 
     let result = dioxus_ssr::render_element(Progress(props));
-    assert!(result.contains("progress"));
+    assert!(result.contains("<progress"));
+    assert!(result.contains(r#"value="50""#));
+    assert!(result.contains(r#"max="100""#));
 }
 
 #[test]
@@ -250,7 +519,8 @@ fn test_progress_indeterminate() {
 
     let result = dioxus_ssr::render_element(Progress(props));
     assert!(result.contains("progress") && result.contains("progress-indeterminate"));
-    assert!(result.contains(r#"role="progressbar""#));
+    assert!(result.contains("<progress"));
+    assert!(!result.contains("value="));
 }
 
 #[test]
@@ -284,3 +554,108 @@ fn test_progress_with_id() {
     let result = dioxus_ssr::render_element(Progress(props));
     assert!(result.contains(r#"id="test-progress""#));
 }
+
+#[test]
+fn test_progress_multi_segments() {
+    let props = ProgressMultiProps {
+        id: None,
+        class: None,
+        segments: vec![
+            (40.0, ProgressColorScheme::Primary),
+            (25.0, ProgressColorScheme::Warning),
+        ],
+        label: Some("Storage used".to_string()),
+    };
+
+    let result = dioxus_ssr::render_element(ProgressMulti(props));
+    assert!(result.contains(r#"aria-label="Storage used""#));
+    assert!(result.contains(r#"class="h-full bg-primary""#));
+    assert!(result.contains(r#"style="width: 40%""#));
+    assert!(result.contains(r#"class="h-full bg-warning""#));
+    assert!(result.contains(r#"style="width: 25%""#));
+    assert!(result.contains(r#"aria-label="Segment 2 of 2: 25%""#));
+}
+
+#[test]
+fn test_progress_multi_scales_overflow() {
+    let props = ProgressMultiProps {
+        id: None,
+        class: None,
+        segments: vec![
+            (150.0, ProgressColorScheme::Error),
+            (50.0, ProgressColorScheme::Info),
+        ],
+        label: None,
+    };
+
+    let result = dioxus_ssr::render_element(ProgressMulti(props));
+    assert!(result.contains(r#"style="width: 75%""#));
+    assert!(result.contains(r#"style="width: 25%""#));
+}
+
+#[test]
+fn test_radial_progress_value_and_color() {
+    let props = RadialProgressProps {
+        children: rsx!("70%"),
+        id: None,
+        class: None,
+        value: 70.0,
+        color_scheme: Some(ProgressColorScheme::Success),
+        size: None,
+        thickness: None,
+    };
+
+    let result = dioxus_ssr::render_element(RadialProgress(props));
+    assert!(result.contains(r#"class="radial-progress text-success""#));
+    assert!(result.contains(r#"style="--value:70;""#));
+    assert!(result.contains(r#"aria-valuenow="70""#));
+}
+
+#[test]
+fn test_radial_progress_size_and_thickness() {
+    let props = RadialProgressProps {
+        children: rsx!("100%"),
+        id: None,
+        class: None,
+        value: 120.0,
+        color_scheme: None,
+        size: Some("6rem".to_string()),
+        thickness: Some("4px".to_string()),
+    };
+
+    let result = dioxus_ssr::render_element(RadialProgress(props));
+    assert!(result.contains(r#"style="--value:100;--size:6rem;--thickness:4px;""#));
+}
+
+#[test]
+fn test_progress_label_text() {
+    assert_eq!(progress_label(50.0, 100.0, "{pct}%"), "50%");
+    assert_eq!(progress_label(1.0, 3.0, "{pct}%"), "33%");
+    assert_eq!(progress_label(150.0, 100.0, "{pct}%"), "100%");
+    assert_eq!(progress_label(5.0, 0.0, "{pct}%"), "0%");
+    assert_eq!(progress_label(3.0, 4.0, "{pct} percent done"), "75 percent done");
+}
+
+#[test]
+fn test_progress_with_label_positions() {
+    let props = |label_position: Option<LabelPosition>| ProgressWithLabelProps {
+        id: None,
+        class: None,
+        value: 25.0,
+        max: Some(50.0),
+        color_scheme: None,
+        size: None,
+        label_position,
+        label_format: Some("{pct}% uploaded".to_string()),
+    };
+
+    let right = dioxus_ssr::render_element(ProgressWithLabel(props(None)));
+    assert!(right.contains(r#"class="flex items-center gap-2""#));
+    assert!(right.contains(r#"<span class="text-sm tabular-nums">50% uploaded</span>"#));
+
+    let inside = dioxus_ssr::render_element(ProgressWithLabel(props(Some(LabelPosition::Inside))));
+    assert!(inside.contains("justify-center text-xs\">50% uploaded</span>"));
+
+    let hidden = dioxus_ssr::render_element(ProgressWithLabel(props(Some(LabelPosition::Hidden))));
+    assert!(hidden.contains(r#"<span class="sr-only">50% uploaded</span>"#));
+}
@@ -21,6 +21,8 @@ use dioxus::prelude::*;
 
 /// Color scheme options for Radio component
 #[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
 pub enum RadioColorScheme {
     #[default]
     /// Primary brand color scheme
@@ -52,6 +54,8 @@ impl Display for RadioColorScheme {
 
 /// Size options for Radio component
 #[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
 pub enum RadioSize {
     #[default]
     /// Default size
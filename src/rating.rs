@@ -17,9 +17,17 @@ use dioxus::prelude::*;
 ///     color_scheme: RatingColorScheme::Primary,
 /// }
 /// ```
+///
+/// With `half`, `value` counts half stars:
+///
+/// ```text
+/// Rating { value: 7, half: true } // 3.5 of 5 stars
+/// ```
 
 /// Color scheme options for Rating component
 #[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
 pub enum RatingColorScheme {
     #[default]
     /// Primary brand color scheme
@@ -45,6 +53,8 @@ impl Display for RatingColorScheme {
 
 /// Size options for Rating component
 #[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
 pub enum RatingSize {
     #[default]
     /// Default size
@@ -68,13 +78,44 @@ impl Display for RatingSize {
     }
 }
 
+/// Mask shape options for the Rating inputs
+#[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum RatingShape {
+    /// Pointed star
+    Star,
+    #[default]
+    /// Rounded star, DaisyUI's usual rating shape (default)
+    Star2,
+    /// Heart, e.g. for favorites
+    Heart,
+    /// Circle
+    Circle,
+    /// Square
+    Square,
+}
+
+impl Display for RatingShape {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RatingShape::Star => write!(f, "mask-star"),
+            RatingShape::Star2 => write!(f, "mask-star-2"),
+            RatingShape::Heart => write!(f, "mask-heart"),
+            RatingShape::Circle => write!(f, "mask-circle"),
+            RatingShape::Square => write!(f, "mask-square"),
+        }
+    }
+}
+
 #[derive(Props, Clone, PartialEq)]
 pub struct RatingProps {
     /// Optional ID for the rating element
     id: Option<String>,
     /// Additional CSS classes to apply to the rating
     class: Option<String>,
-    /// Current rating value
+    /// Current rating value, in whole stars or, with `half`, in half stars
+    /// (so 5 is 2.5 stars)
     value: i32,
     /// Maximum rating value (default: 5)
     max: Option<i32>,
@@ -84,8 +125,41 @@ pub struct RatingProps {
     size: Option<RatingSize>,
     /// Whether the rating is read-only
     read_only: Option<bool>,
-    /// Whether to show half-star support
+    /// Split each star into two halves, counting `value` in half stars
     half: Option<bool>,
+    /// Show the value as a trailing label
+    show_value: Option<bool>,
+    /// Exact value for the label, e.g. an average of 4.5 (default: `value`)
+    average: Option<f64>,
+    /// Number of reviews, shown as a trailing `(120)`
+    count: Option<u32>,
+    /// Render plain star spans instead of radio inputs, for showing a rating without editing it
+    display_only: Option<bool>,
+    /// Called with the chosen rating (1-based, in half stars with `half`), or 0 when cleared
+    onchange: Option<EventHandler<i32>>,
+    /// Add a hidden first input so the rating can be reset to 0
+    allow_clear: Option<bool>,
+    /// Shape of each input
+    shape: Option<RatingShape>,
+}
+
+/// A count of half stars as stars, e.g. `2.5` for 5 halves
+fn half_stars(halves: i32) -> String {
+    if halves % 2 == 0 {
+        (halves / 2).to_string()
+    } else {
+        format!("{}.5", halves / 2)
+    }
+}
+
+/// Mask classes for the input at `index`, alternating left and right halves
+/// when `half` is set
+fn star_mask(shape: RatingShape, half: bool, index: i32) -> String {
+    match (half, index % 2) {
+        (false, _) => format!("mask {shape}"),
+        (true, 0) => format!("mask {shape} mask-half-1"),
+        (true, _) => format!("mask {shape} mask-half-2"),
+    }
 }
 
 #[component]
@@ -96,6 +170,7 @@ pub fn Rating(props: RatingProps) -> Element {
     let read_only = props.read_only.filter(|&x| x);
     let half = props.half.filter(|&x| x);
     let max = props.max.unwrap_or(5);
+    let shape = props.shape.unwrap_or_default();
     let rating_id = props.id.clone().unwrap_or_default();
     let value = props.value;
     let div_id = props.id;
@@ -121,23 +196,90 @@ pub fn Rating(props: RatingProps) -> Element {
 
     let class_string = classes.join(" ");
 
-    rsx!(
-        div {
-            class: "{class_string}",
-            id: div_id,
-            {(0..max).map(|i| {
-                let is_filled = i < value;
-                rsx!(
+    let value_label = props.show_value.filter(|&x| x).map(|_| match props.average {
+        Some(average) => format!("{:.1}", average),
+        None if half.is_some() => format!("{:.1}", value as f64 / 2.0),
+        None => value.to_string(),
+    });
+
+    // With half stars every star is two inputs, each worth half a star
+    let inputs = if half.is_some() { max * 2 } else { max };
+    let stars_label = |position: i32| if half.is_some() { half_stars(position) } else { position.to_string() };
+    let value_stars = stars_label(value);
+
+    let onchange = props.onchange;
+    let change_listeners = move |rating: i32| {
+        let mut listeners = vec![];
+        if let Some(handler) = onchange {
+            listeners.push(dioxus_elements::events::onchange(move |_| handler.call(rating)));
+        }
+        listeners
+    };
+
+    let stars = if props.display_only.unwrap_or(false) {
+        // DaisyUI dims the stars after the one marked aria-current
+        rsx!(
+            div {
+                class: "{class_string}",
+                id: div_id,
+                role: "img",
+                aria_label: "{value_stars} out of {max} stars",
+                {(0..inputs).map(|i| rsx!(
+                    span {
+                        class: star_mask(shape, half.is_some(), i),
+                        aria_current: (i + 1 == value).then_some("true"),
+                    }
+                ))}
+            }
+        )
+    } else {
+        rsx!(
+            div {
+                class: "{class_string}",
+                id: div_id,
+                if props.allow_clear.unwrap_or(false) {
                     input {
                         r#type: "radio",
                         name: "rating-{rating_id}",
-                        class: "mask mask-star",
-                        r#aria_label: format!("{} star", i + 1),
-                        checked: is_filled,
+                        class: "rating-hidden",
+                        aria_label: "Clear rating",
+                        checked: value == 0,
                         disabled: read_only,
+                        ..change_listeners(0),
                     }
-                )
-            })}
+                }
+                {(0..inputs).map(|i| {
+                    let is_filled = i < value;
+                    rsx!(
+                        input {
+                            r#type: "radio",
+                            name: "rating-{rating_id}",
+                            class: star_mask(shape, half.is_some(), i),
+                            r#aria_label: format!("{} star", stars_label(i + 1)),
+                            checked: is_filled,
+                            disabled: read_only,
+                            ..change_listeners(i + 1),
+                        }
+                    )
+                })}
+            }
+        )
+    };
+
+    if value_label.is_none() && props.count.is_none() {
+        return stars;
+    }
+
+    rsx!(
+        div {
+            class: "flex items-center gap-2",
+            {stars}
+            if let Some(label) = value_label {
+                span { class: "text-sm font-medium", "{label}" }
+            }
+            if let Some(count) = props.count {
+                span { class: "text-sm opacity-70", "({count})" }
+            }
         }
     )
 }
@@ -153,6 +295,13 @@ fn test_rating_basic() {
         size: None,
         read_only: None,
         half: None,
+        show_value: None,
+        average: None,
+        count: None,
+        display_only: None,
+        onchange: None,
+        allow_clear: None,
+        shape: None,
     };
 
     let result = dioxus_ssr::render_element(Rating(props));
@@ -178,6 +327,13 @@ fn test_rating_with_color_scheme() {
             size: None,
             read_only: None,
             half: None,
+            show_value: None,
+            average: None,
+            count: None,
+            display_only: None,
+            onchange: None,
+            allow_clear: None,
+            shape: None,
         };
 
         let result = dioxus_ssr::render_element(Rating(props));
@@ -206,6 +362,13 @@ fn test_rating_with_size() {
             size: Some(size),
             read_only: None,
             half: None,
+            show_value: None,
+            average: None,
+            count: None,
+            display_only: None,
+            onchange: None,
+            allow_clear: None,
+            shape: None,
         };
 
         let result = dioxus_ssr::render_element(Rating(props));
@@ -230,6 +393,13 @@ fn test_rating_half() {
         size: None,
         read_only: None,
         half: Some(true),
+        show_value: None,
+        average: None,
+        count: None,
+        display_only: None,
+        onchange: None,
+        allow_clear: None,
+        shape: None,
     };
 
     let result = dioxus_ssr::render_element(Rating(props));
@@ -247,6 +417,13 @@ fn test_rating_read_only() {
         size: None,
         read_only: Some(true),
         half: None,
+        show_value: None,
+        average: None,
+        count: None,
+        display_only: None,
+        onchange: None,
+        allow_clear: None,
+        shape: None,
     };
 
     let result = dioxus_ssr::render_element(Rating(props));
@@ -264,6 +441,13 @@ fn test_rating_with_custom_class() {
         size: None,
         read_only: None,
         half: None,
+        show_value: None,
+        average: None,
+        count: None,
+        display_only: None,
+        onchange: None,
+        allow_clear: None,
+        shape: None,
     };
 
     let result = dioxus_ssr::render_element(Rating(props));
@@ -281,8 +465,210 @@ fn test_rating_with_id() {
         size: None,
         read_only: None,
         half: None,
+        show_value: None,
+        average: None,
+        count: None,
+        display_only: None,
+        onchange: None,
+        allow_clear: None,
+        shape: None,
     };
 
     let result = dioxus_ssr::render_element(Rating(props));
     assert!(result.contains(r#"id="test-rating""#));
 }
+
+#[test]
+fn test_rating_value_label() {
+    let props = RatingProps {
+        id: None,
+        class: None,
+        value: 4,
+        max: Some(5),
+        color_scheme: None,
+        size: None,
+        read_only: Some(true),
+        half: None,
+        show_value: Some(true),
+        average: Some(4.5),
+        count: Some(120),
+        display_only: None,
+        onchange: None,
+        allow_clear: None,
+        shape: None,
+    };
+
+    let result = dioxus_ssr::render_element(Rating(props));
+    assert!(result.starts_with(r#"<div class="flex items-center gap-2"><div class="rating"#));
+    assert!(result.contains(r#"<span class="text-sm font-medium">4.5</span>"#));
+    assert!(result.contains(r#"<span class="text-sm opacity-70">(120)</span>"#));
+}
+
+#[test]
+fn test_rating_display_only() {
+    let props = RatingProps {
+        id: None,
+        class: None,
+        value: 3,
+        max: Some(5),
+        color_scheme: None,
+        size: None,
+        read_only: None,
+        half: None,
+        show_value: None,
+        average: None,
+        count: None,
+        display_only: Some(true),
+        onchange: None,
+        allow_clear: None,
+        shape: None,
+    };
+
+    let result = dioxus_ssr::render_element(Rating(props));
+    assert!(!result.contains("<input"));
+    assert_eq!(result.matches(r#"<span class="mask mask-star-2""#).count(), 5);
+    assert!(result.contains(r#"<span class="mask mask-star-2" aria-current="true"></span>"#));
+    assert!(result.contains(r#"aria-label="3 out of 5 stars""#));
+}
+
+#[test]
+fn test_rating_half_inputs() {
+    let props = RatingProps {
+        id: None,
+        class: None,
+        value: 5,
+        max: Some(3),
+        color_scheme: None,
+        size: None,
+        read_only: None,
+        half: Some(true),
+        show_value: Some(true),
+        average: None,
+        count: None,
+        display_only: None,
+        onchange: None,
+        allow_clear: None,
+        shape: None,
+    };
+
+    let result = dioxus_ssr::render_element(Rating(props));
+    assert_eq!(result.matches("<input").count(), 6);
+    assert_eq!(result.matches(r#"class="mask mask-star-2 mask-half-1""#).count(), 3);
+    assert_eq!(result.matches(r#"class="mask mask-star-2 mask-half-2""#).count(), 3);
+    assert_eq!(result.matches("checked=true").count(), 5);
+    assert!(result.contains(r#"class="mask mask-star-2 mask-half-1" aria-label="2.5 star" checked=true"#));
+    assert!(result.contains(r#"class="mask mask-star-2 mask-half-2" aria-label="3 star"/>"#));
+    assert!(result.contains(r#"<span class="text-sm font-medium">2.5</span>"#));
+}
+
+#[test]
+fn test_rating_half_display_only() {
+    let props = RatingProps {
+        id: None,
+        class: None,
+        value: 7,
+        max: Some(5),
+        color_scheme: None,
+        size: None,
+        read_only: None,
+        half: Some(true),
+        show_value: None,
+        average: None,
+        count: None,
+        display_only: Some(true),
+        onchange: None,
+        allow_clear: None,
+        shape: None,
+    };
+
+    let result = dioxus_ssr::render_element(Rating(props));
+    assert_eq!(result.matches("<span").count(), 10);
+    assert!(result.contains(r#"aria-label="3.5 out of 5 stars""#));
+    assert!(result.contains(r#"<span class="mask mask-star-2 mask-half-1" aria-current="true"></span>"#));
+}
+
+#[test]
+fn test_rating_allow_clear() {
+    let props = RatingProps {
+        id: Some("review".to_string()),
+        class: None,
+        value: 0,
+        max: Some(5),
+        color_scheme: None,
+        size: None,
+        read_only: None,
+        half: None,
+        show_value: None,
+        average: None,
+        count: None,
+        display_only: None,
+        onchange: None,
+        allow_clear: Some(true),
+        shape: None,
+    };
+
+    let result = dioxus_ssr::render_element(Rating(props));
+    assert!(result.contains(
+        r#"<div class="rating rating-primary" id="review"><input type="radio" name="rating-review" class="rating-hidden" aria-label="Clear rating" checked=true/>"#
+    ));
+    assert_eq!(result.matches(r#"name="rating-review""#).count(), 6);
+}
+
+#[test]
+fn test_rating_onchange() {
+    use crate::test_utils::{fire, listener, rebuild, TestFormData};
+
+    fn app() -> Element {
+        let mut rating = use_signal(|| 3);
+        rsx!(
+            Rating {
+                value: rating(),
+                allow_clear: true,
+                onchange: move |value| rating.set(value),
+            }
+            span { "Rating {rating}" }
+        )
+    }
+
+    let (mut dom, listeners) = rebuild(app);
+    // The star inputs are created before the hidden clearing input
+    fire(&mut dom, "change", listener(&listeners, "change", 4), TestFormData::default());
+    assert!(dioxus_ssr::render(&dom).contains("Rating 5</span>"));
+
+    fire(&mut dom, "change", listener(&listeners, "change", 5), TestFormData::default());
+    assert!(dioxus_ssr::render(&dom).contains("Rating 0</span>"));
+}
+
+#[test]
+fn test_rating_shapes() {
+    let shapes = [
+        (RatingShape::Star, "mask mask-star"),
+        (RatingShape::Star2, "mask mask-star-2"),
+        (RatingShape::Heart, "mask mask-heart"),
+        (RatingShape::Circle, "mask mask-circle"),
+        (RatingShape::Square, "mask mask-square"),
+    ];
+
+    for (shape, expected_class) in shapes {
+        let props = RatingProps {
+            id: None,
+            class: None,
+            value: 2,
+            max: Some(4),
+            color_scheme: None,
+            size: None,
+            read_only: None,
+            half: None,
+            show_value: None,
+            average: None,
+            count: None,
+            display_only: None,
+            onchange: None,
+            allow_clear: None,
+            shape: Some(shape),
+        };
+
+        let result = dioxus_ssr::render_element(Rating(props));
+        assert_eq!(result.matches(&format!(r#"class="{expected_class}""#)).count(), 4);
+    }
+}
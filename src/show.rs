@@ -0,0 +1,143 @@
+#![allow(non_snake_case)]
+use dioxus::prelude::*;
+
+use crate::breakpoint::Breakpoint;
+
+/// A Show component for showing or hiding content by breakpoint.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```text
+/// use daisy_rsx::{Breakpoint, Show};
+///
+/// Show {
+///     from: Breakpoint::Lg,
+///     children: rsx!(
+///         div { "Only on large screens" }
+///     )
+/// }
+/// ```
+
+#[derive(Props, Clone, PartialEq)]
+pub struct ShowProps {
+    /// The content to show or hide
+    children: Element,
+    /// Optional ID for the wrapper element
+    id: Option<String>,
+    /// Additional CSS classes to apply to the wrapper. A display class such
+    /// as `flex` is used in place of the default `block`
+    class: Option<String>,
+    /// Show the content from this breakpoint upwards
+    from: Option<Breakpoint>,
+    /// Show the content only below this breakpoint
+    until: Option<Breakpoint>,
+}
+
+/// Display utilities that can stand in for `block` on the visible side
+const DISPLAY_CLASSES: [&str; 8] = [
+    "block", "inline-block", "inline", "flex", "inline-flex", "grid", "inline-grid", "contents",
+];
+
+#[component]
+pub fn Show(props: ShowProps) -> Element {
+    // A display class from the caller, e.g. `flex`, replaces the default `block`
+    let mut display = "block";
+    let class = props
+        .class
+        .unwrap_or_default()
+        .split_whitespace()
+        .filter(|token| match DISPLAY_CLASSES.iter().find(|display| *display == token) {
+            Some(found) => {
+                display = found;
+                false
+            }
+            None => true,
+        })
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    // Build CSS classes
+    let mut classes = vec![];
+
+    if let Some(from) = props.from {
+        classes.push("hidden".to_string());
+        classes.push(from.prefix(display));
+    } else {
+        classes.push(display.to_string());
+    }
+
+    if let Some(until) = props.until {
+        classes.push(until.prefix("hidden"));
+    }
+
+    if !class.is_empty() {
+        classes.push(class);
+    }
+
+    let class_string = classes.join(" ");
+
+    rsx!(
+        div {
+            class: "{class_string}",
+            id: props.id,
+            {props.children}
+        }
+    )
+}
+
+#[test]
+fn test_show_from_lg() {
+    let props = ShowProps {
+        children: rsx!(div { "Content" }),
+        id: None,
+        class: None,
+        from: Some(Breakpoint::Lg),
+        until: None,
+    };
+
+    let result = dioxus_ssr::render_element(Show(props));
+    assert!(result.contains(r#"class="hidden lg:block""#));
+}
+
+#[test]
+fn test_show_until_md() {
+    let props = ShowProps {
+        children: rsx!(div { "Content" }),
+        id: None,
+        class: None,
+        from: None,
+        until: Some(Breakpoint::Md),
+    };
+
+    let result = dioxus_ssr::render_element(Show(props));
+    assert!(result.contains(r#"class="block md:hidden""#));
+}
+
+#[test]
+fn test_show_between() {
+    let props = ShowProps {
+        children: rsx!(div { "Content" }),
+        id: None,
+        class: None,
+        from: Some(Breakpoint::Sm),
+        until: Some(Breakpoint::Xxl),
+    };
+
+    let result = dioxus_ssr::render_element(Show(props));
+    assert!(result.contains(r#"class="hidden sm:block 2xl:hidden""#));
+}
+
+#[test]
+fn test_show_keeps_display_class() {
+    let result = dioxus_ssr::render_element(rsx!(
+        Show { until: Breakpoint::Md, class: "flex flex-col", "Content" }
+    ));
+    assert!(result.contains(r#"class="flex md:hidden flex-col""#));
+
+    let result = dioxus_ssr::render_element(rsx!(
+        Show { from: Breakpoint::Lg, class: "grid gap-2", "Content" }
+    ));
+    assert!(result.contains(r#"class="hidden lg:grid gap-2""#));
+}
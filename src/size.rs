@@ -0,0 +1,55 @@
+use std::fmt::Display;
+
+/// Canonical size scale shared by components using DaisyUI's `-xs`..`-xl`
+/// modifiers.
+///
+/// # Examples
+///
+/// ```text
+/// use daisy_rsx::Size;
+///
+/// assert_eq!(Size::Sm.class("badge"), "badge-sm");
+/// assert_eq!(Size::Lg.class("loading"), "loading-lg");
+/// ```
+#[derive(Default, Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum Size {
+    /// Extra small
+    Xs,
+    /// Small
+    Sm,
+    /// Medium (DaisyUI's default)
+    #[default]
+    Md,
+    /// Large
+    Lg,
+    /// Extra large
+    Xl,
+}
+
+impl Size {
+    /// The modifier class for a component, e.g. `badge-sm` for prefix `badge`
+    pub fn class(&self, prefix: &str) -> String {
+        format!("{}-{}", prefix, self)
+    }
+}
+
+impl Display for Size {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Size::Xs => write!(f, "xs"),
+            Size::Sm => write!(f, "sm"),
+            Size::Md => write!(f, "md"),
+            Size::Lg => write!(f, "lg"),
+            Size::Xl => write!(f, "xl"),
+        }
+    }
+}
+
+#[test]
+fn test_size_class_prefix() {
+    assert_eq!(Size::Sm.class("badge"), "badge-sm");
+    assert_eq!(Size::Sm.class("loading"), "loading-sm");
+    assert_eq!(Size::Xl.class("status"), "status-xl");
+}
@@ -18,6 +18,8 @@ use dioxus::prelude::*;
 
 /// Variant options for Skeleton component
 #[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
 pub enum SkeletonVariant {
     #[default]
     /// Text variant
@@ -74,6 +76,74 @@ pub fn Skeleton(props: SkeletonProps) -> Element {
     )
 }
 
+#[derive(Props, Clone, PartialEq)]
+pub struct SkeletonSwapProps {
+    /// The real content to display once loading has finished
+    children: Element,
+    /// Whether the content is still loading
+    loading: bool,
+    /// Placeholder to display while loading, usually one or more Skeleton components
+    skeleton: Element,
+}
+
+#[component]
+pub fn SkeletonSwap(props: SkeletonSwapProps) -> Element {
+    if props.loading {
+        rsx!({props.skeleton})
+    } else {
+        rsx!({props.children})
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct SkeletonTextProps {
+    /// Optional ID for the skeleton text container
+    id: Option<String>,
+    /// Additional CSS classes to apply to the skeleton text container
+    class: Option<String>,
+    /// Number of skeleton lines to render
+    lines: usize,
+    /// Width of the last line as a CSS length (e.g. "60%")
+    last_line_width: Option<String>,
+}
+
+#[component]
+pub fn SkeletonText(props: SkeletonTextProps) -> Element {
+    let class = props.class.unwrap_or_default();
+    let lines = props.lines;
+
+    // Build CSS classes
+    let mut classes = vec!["flex flex-col gap-2".to_string()];
+
+    if !class.is_empty() {
+        classes.push(class);
+    }
+
+    let class_string = classes.join(" ");
+
+    rsx!(
+        div {
+            class: "{class_string}",
+            id: props.id,
+            {(0..lines).map(|i| {
+                let is_last = i + 1 == lines;
+                let style = props
+                    .last_line_width
+                    .as_ref()
+                    .filter(|_| is_last)
+                    .map(|width| format!("width: {};", width));
+                let width_class = if style.is_some() { "" } else { " w-full" };
+                rsx!(
+                    div {
+                        class: "skeleton h-4{width_class}",
+                        style,
+                    }
+                )
+            })}
+        }
+    )
+}
+
 #[test]
 fn test_skeleton_basic() {
     let props = SkeletonProps {
@@ -121,3 +191,58 @@ fn test_skeleton_with_id() {
     let result = dioxus_ssr::render_element(Skeleton(props));
     assert!(result.contains(r#"id="test-skeleton""#));
 }
+
+#[test]
+fn test_skeleton_swap_loading() {
+    let props = SkeletonSwapProps {
+        children: rsx!(p { "Loaded content" }),
+        loading: true,
+        skeleton: rsx!(Skeleton { variant: SkeletonVariant::Text }),
+    };
+
+    let result = dioxus_ssr::render_element(SkeletonSwap(props));
+    assert!(result.contains(r#"class="skeleton skeleton-text""#));
+    assert!(!result.contains("Loaded content"));
+}
+
+#[test]
+fn test_skeleton_swap_loaded() {
+    let props = SkeletonSwapProps {
+        children: rsx!(p { "Loaded content" }),
+        loading: false,
+        skeleton: rsx!(Skeleton { variant: SkeletonVariant::Text }),
+    };
+
+    let result = dioxus_ssr::render_element(SkeletonSwap(props));
+    assert!(result.contains("<p>Loaded content</p>"));
+    assert!(!result.contains("skeleton"));
+}
+
+#[test]
+fn test_skeleton_text_lines() {
+    let props = SkeletonTextProps {
+        id: None,
+        class: None,
+        lines: 3,
+        last_line_width: Some("60%".to_string()),
+    };
+
+    let result = dioxus_ssr::render_element(SkeletonText(props));
+    assert_eq!(result.matches(r#"class="skeleton h-4"#).count(), 3);
+    assert_eq!(result.matches(r#"class="skeleton h-4 w-full""#).count(), 2);
+    assert!(result.contains(r#"<div class="skeleton h-4" style="width: 60%;"></div></div>"#));
+}
+
+#[test]
+fn test_skeleton_text_without_last_line_width() {
+    let props = SkeletonTextProps {
+        id: None,
+        class: None,
+        lines: 2,
+        last_line_width: None,
+    };
+
+    let result = dioxus_ssr::render_element(SkeletonText(props));
+    assert_eq!(result.matches(r#"class="skeleton h-4 w-full""#).count(), 2);
+    assert!(!result.contains("style"));
+}
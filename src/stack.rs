@@ -23,6 +23,8 @@ use dioxus::prelude::*;
 
 /// Direction options for Stack component
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
 pub enum StackDirection {
     /// Vertical direction
     Vertical,
@@ -2,6 +2,8 @@
 use std::fmt::Display;
 use dioxus::prelude::*;
 
+use crate::progress::{ProgressColorScheme, RadialProgress};
+
 /// A Stats component for displaying statistics and metrics.
 ///
 /// # Examples
@@ -24,6 +26,8 @@ use dioxus::prelude::*;
 
 /// Color scheme options for Stats component
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
 pub enum StatsColorScheme {
     /// Primary color
     Primary,
@@ -39,6 +43,8 @@ pub enum StatsColorScheme {
     Warning,
     /// Error color
     Error,
+    /// Neutral color
+    Neutral,
 }
 
 impl Display for StatsColorScheme {
@@ -51,12 +57,15 @@ impl Display for StatsColorScheme {
             StatsColorScheme::Success => write!(f, "stats-success"),
             StatsColorScheme::Warning => write!(f, "stats-warning"),
             StatsColorScheme::Error => write!(f, "stats-error"),
+            StatsColorScheme::Neutral => write!(f, "stats-neutral"),
         }
     }
 }
 
 /// Size options for Stats component
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
 pub enum StatsSize {
     /// Small size
     Small,
@@ -88,6 +97,8 @@ pub struct StatsProps {
     color_scheme: Option<StatsColorScheme>,
     /// Size of stats
     size: Option<StatsSize>,
+    /// Stack vertically on small screens and lay out horizontally from `lg` up
+    responsive: Option<bool>,
 }
 
 #[component]
@@ -95,6 +106,7 @@ pub fn Stats(props: StatsProps) -> Element {
     let class = props.class.unwrap_or_default();
     let color_scheme = props.color_scheme;
     let size = props.size;
+    let responsive = props.responsive.filter(|&x| x);
 
     // Build CSS classes
     let mut classes = vec!["stats".to_string()];
@@ -106,6 +118,10 @@ pub fn Stats(props: StatsProps) -> Element {
     if let Some(s) = size {
         classes.push(s.to_string());
     }
+
+    if responsive.is_some() {
+        classes.push("stats-vertical lg:stats-horizontal".to_string());
+    }
     
     if !class.is_empty() {
         classes.push(class);
@@ -132,12 +148,17 @@ pub struct StatsItemProps {
     class: Option<String>,
     /// Color scheme for stats item
     color_scheme: Option<StatsColorScheme>,
+    /// Link target, rendering the stat as an anchor
+    href: Option<String>,
+    /// Click handler, making the stat clickable
+    onclick: Option<EventHandler<MouseEvent>>,
 }
 
 #[component]
 pub fn StatsItem(props: StatsItemProps) -> Element {
     let class = props.class.unwrap_or_default();
     let color_scheme = props.color_scheme;
+    let onclick = props.onclick;
 
     // Build CSS classes
     let mut classes = vec!["stat".to_string()];
@@ -150,15 +171,46 @@ pub fn StatsItem(props: StatsItemProps) -> Element {
         classes.push(class);
     }
 
+    if props.href.is_none() && onclick.is_some() {
+        classes.push("cursor-pointer".to_string());
+    }
+
     let class_string = classes.join(" ");
 
-    rsx!(
-        div {
-            class: "{class_string}",
-            id: props.id,
-            {props.children}
-        }
-    )
+    if let Some(href) = props.href {
+        rsx!(
+            a {
+                class: "{class_string}",
+                id: props.id,
+                href: "{href}",
+                onclick: move |event| {
+                    if let Some(handler) = onclick {
+                        handler.call(event);
+                    }
+                },
+                {props.children}
+            }
+        )
+    } else if let Some(handler) = onclick {
+        rsx!(
+            div {
+                class: "{class_string}",
+                id: props.id,
+                role: "button",
+                tabindex: "0",
+                onclick: move |event| handler.call(event),
+                {props.children}
+            }
+        )
+    } else {
+        rsx!(
+            div {
+                class: "{class_string}",
+                id: props.id,
+                {props.children}
+            }
+        )
+    }
 }
 
 #[derive(Props, Clone, PartialEq)]
@@ -201,11 +253,61 @@ pub struct StatsValueProps {
     id: Option<String>,
     /// Additional CSS classes to apply to stats value
     class: Option<String>,
+    /// Number to count up to from 0 when mounted, rendered ahead of the children.
+    /// Server renders show the number itself; later changes count on from the
+    /// value shown at the time
+    animate_to: Option<f64>,
+    /// Duration of the count-up animation in milliseconds (default: 1000)
+    duration_ms: Option<u64>,
+    /// Number of decimal places shown while counting up (default: 0)
+    decimals: Option<usize>,
+}
+
+/// Interval between count-up animation frames in milliseconds
+const COUNT_UP_FRAME_MS: u64 = 16;
+
+/// Value shown by a count-up animation after `elapsed_ms`, eased out so it slows
+/// down as it approaches the target.
+fn count_up_value(target: f64, elapsed_ms: u64, duration_ms: u64) -> f64 {
+    if duration_ms == 0 || elapsed_ms >= duration_ms {
+        return target;
+    }
+    let progress = elapsed_ms as f64 / duration_ms as f64;
+    let eased = 1.0 - (1.0 - progress).powi(3);
+    target * eased
 }
 
 #[component]
 pub fn StatsValue(props: StatsValueProps) -> Element {
     let class = props.class.unwrap_or_default();
+    let animate_to = props.animate_to;
+    let duration_ms = props.duration_ms.unwrap_or(1000);
+    let decimals = props.decimals.unwrap_or(0);
+
+    // Starts at the target so server renders show the final number; once
+    // mounted, a background task counts up from 0, and from the shown value
+    // to the new target whenever `animate_to` changes
+    let mut current = use_signal(|| animate_to.unwrap_or_default());
+    let mounted = use_hook(|| std::rc::Rc::new(std::cell::Cell::new(false)));
+    use_resource(use_reactive((&animate_to, &duration_ms), move |(animate_to, duration_ms)| {
+        let mounted = mounted.clone();
+        async move {
+            let Some(target) = animate_to else {
+                return;
+            };
+            let from = if mounted.replace(true) { *current.peek() } else { 0.0 };
+            current.set(from);
+            let mut elapsed_ms = 0;
+            while elapsed_ms < duration_ms {
+                futures_timer::Delay::new(std::time::Duration::from_millis(COUNT_UP_FRAME_MS)).await;
+                elapsed_ms += COUNT_UP_FRAME_MS;
+                current.set(from + count_up_value(target - from, elapsed_ms, duration_ms));
+            }
+            current.set(target);
+        }
+    }));
+
+    let animated_value = animate_to.map(|_| format!("{:.*}", decimals, current()));
 
     // Build CSS classes
     let mut classes = vec!["stat-value".to_string()];
@@ -220,6 +322,7 @@ pub fn StatsValue(props: StatsValueProps) -> Element {
         div {
             class: "{class_string}",
             id: props.id,
+            {animated_value}
             {props.children}
         }
     )
@@ -257,6 +360,139 @@ pub fn StatsDescription(props: StatsDescriptionProps) -> Element {
     )
 }
 
+#[derive(Props, Clone, PartialEq)]
+pub struct StatsFigureProps {
+    /// The figure, e.g. an icon, avatar or RadialProgress
+    children: Element,
+    /// Optional ID for stats figure element
+    id: Option<String>,
+    /// Additional CSS classes to apply to stats figure
+    class: Option<String>,
+    /// Series drawn as a small trend line after the children, scaled to fit
+    sparkline: Option<Vec<f64>>,
+}
+
+/// Width and height of the sparkline's viewBox
+const SPARKLINE_WIDTH: f64 = 100.0;
+const SPARKLINE_HEIGHT: f64 = 30.0;
+
+/// Rounds a coordinate to two decimals, printed without trailing zeros
+fn sparkline_coord(value: f64) -> String {
+    let rounded = (value * 100.0).round() / 100.0;
+    // Avoid printing -0
+    format!("{}", rounded + 0.0)
+}
+
+/// Polyline points for `values` spread across `width`, with the smallest
+/// value at the bottom and the largest at the top. A flat or single-value
+/// series is drawn as a line through the middle.
+fn sparkline_points(values: &[f64], width: f64, height: f64) -> String {
+    let values: Vec<f64> = values.iter().copied().filter(|value| value.is_finite()).collect();
+    if values.is_empty() {
+        return String::new();
+    }
+    if values.len() == 1 {
+        let y = sparkline_coord(height / 2.0);
+        return format!("0,{y} {},{y}", sparkline_coord(width));
+    }
+
+    let min = values.iter().copied().fold(f64::INFINITY, f64::min);
+    let max = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    let step = width / (values.len() - 1) as f64;
+    values
+        .iter()
+        .enumerate()
+        .map(|(index, value)| {
+            let y = if max > min {
+                height - (value - min) / (max - min) * height
+            } else {
+                height / 2.0
+            };
+            format!("{},{}", sparkline_coord(index as f64 * step), sparkline_coord(y))
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[component]
+pub fn StatsFigure(props: StatsFigureProps) -> Element {
+    let class = props.class.unwrap_or_default();
+
+    // Build CSS classes
+    let mut classes = vec!["stat-figure".to_string()];
+
+    if !class.is_empty() {
+        classes.push(class);
+    }
+
+    let class_string = classes.join(" ");
+
+    let points = props
+        .sparkline
+        .map(|values| sparkline_points(&values, SPARKLINE_WIDTH, SPARKLINE_HEIGHT))
+        .filter(|points| !points.is_empty());
+
+    rsx!(
+        div {
+            class: "{class_string}",
+            id: props.id,
+            {props.children}
+            if let Some(points) = points {
+                svg {
+                    "aria-hidden": true,
+                    xmlns: "http://www.w3.org/2000/svg",
+                    class: "h-8 w-24",
+                    "viewBox": "0 0 {SPARKLINE_WIDTH} {SPARKLINE_HEIGHT}",
+                    "preserveAspectRatio": "none",
+                    fill: "none",
+                    stroke: "currentColor",
+                    stroke_width: "2",
+                    polyline { points: "{points}", "vector-effect": "non-scaling-stroke" }
+                }
+            }
+        }
+    )
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct StatsRadialProps {
+    /// Percentage shown by the radial progress (0-100)
+    value: f64,
+    /// Text in the middle of the circle (default: the value as a percentage)
+    label: Option<String>,
+    /// Color of the filled arc
+    color_scheme: Option<ProgressColorScheme>,
+    /// Optional ID for stats figure element
+    id: Option<String>,
+    /// Additional CSS classes to apply to the radial progress
+    class: Option<String>,
+}
+
+/// A stat figure holding a RadialProgress with its value in the middle.
+///
+/// ```text
+/// StatsItem {
+///     StatsRadial { value: 86.0, color_scheme: ProgressColorScheme::Success }
+///     StatsTitle { "Tasks done" }
+/// }
+/// ```
+#[component]
+pub fn StatsRadial(props: StatsRadialProps) -> Element {
+    let label = props.label.unwrap_or_else(|| format!("{}%", props.value));
+
+    rsx!(
+        StatsFigure {
+            id: props.id,
+            RadialProgress {
+                value: props.value,
+                color_scheme: props.color_scheme,
+                class: props.class,
+                "{label}"
+            }
+        }
+    )
+}
+
 #[test]
 fn test_stats_basic() {
     let props = StatsProps {
@@ -271,6 +507,7 @@ fn test_stats_basic() {
         class: None,
         color_scheme: None,
         size: None,
+        responsive: None,
     };
 
     let result = dioxus_ssr::render_element(Stats(props));
@@ -288,6 +525,8 @@ fn test_stats_item() {
         id: None,
         class: None,
         color_scheme: None,
+        href: None,
+        onclick: None,
     };
 
     let result = dioxus_ssr::render_element(StatsItem(props));
@@ -302,6 +541,7 @@ fn test_stats_with_color_scheme() {
         class: None,
         color_scheme: Some(StatsColorScheme::Primary),
         size: None,
+        responsive: None,
     };
 
     let result = dioxus_ssr::render_element(Stats(props));
@@ -316,6 +556,7 @@ fn test_stats_with_size() {
         class: None,
         color_scheme: None,
         size: Some(StatsSize::Large),
+        responsive: None,
     };
 
     let result = dioxus_ssr::render_element(Stats(props));
@@ -330,6 +571,7 @@ fn test_stats_custom_class() {
         class: Some("custom-class".to_string()),
         color_scheme: None,
         size: None,
+        responsive: None,
     };
 
     let result = dioxus_ssr::render_element(Stats(props));
@@ -344,8 +586,165 @@ fn test_stats_with_id() {
         class: None,
         color_scheme: None,
         size: None,
+        responsive: None,
     };
 
     let result = dioxus_ssr::render_element(Stats(props));
     assert!(result.contains(r#"id="test-stats""#));
 }
+
+#[test]
+fn test_stats_responsive() {
+    let props = StatsProps {
+        children: rsx!(StatsItem { children: rsx!(StatsValue { children: rsx!("100") }) }),
+        id: None,
+        class: Some("shadow".to_string()),
+        color_scheme: None,
+        size: None,
+        responsive: Some(true),
+    };
+
+    let result = dioxus_ssr::render_element(Stats(props));
+    assert!(result.contains(r#"class="stats stats-vertical lg:stats-horizontal shadow""#));
+}
+
+#[test]
+fn test_stats_value_count_up_renders_target_on_server() {
+    let result = dioxus_ssr::render_element(rsx!(
+        StatsValue { animate_to: 75.0, duration_ms: 500, "%" }
+    ));
+    assert_eq!(result, r#"<div class="stat-value">75%</div>"#);
+}
+
+#[test]
+fn test_stats_value_count_up_intermediate_values() {
+    let target = 1000.0;
+    let duration_ms = 1000;
+
+    let quarter = count_up_value(target, 250, duration_ms);
+    let half = count_up_value(target, 500, duration_ms);
+
+    assert!(quarter > 0.0 && quarter < half);
+    assert!(half < target);
+    assert_eq!(format!("{:.0}", half), "875");
+    assert_eq!(count_up_value(target, duration_ms, duration_ms), target);
+    assert_eq!(count_up_value(target, 100, 0), target);
+}
+
+#[cfg(test)]
+fn shown_stats_value(dom: &VirtualDom) -> f64 {
+    let result = dioxus_ssr::render(dom);
+    let (_, value) = result.split_once(r#"<div class="stat-value">"#).unwrap();
+    let (value, _) = value.split_once("%</div>").unwrap();
+    value.parse().unwrap()
+}
+
+/// Samples the shown value as time passes until it settles on `target`
+#[cfg(test)]
+fn sample_stats_value(dom: &mut VirtualDom, target: f64) -> Vec<f64> {
+    let mut seen = vec![];
+    for _ in 0..100 {
+        crate::test_utils::run_for(dom, 10);
+        seen.push(shown_stats_value(dom));
+        if seen.last() == Some(&target) {
+            break;
+        }
+    }
+    seen
+}
+
+#[test]
+fn test_stats_value_counts_up_over_time() {
+    use crate::test_utils::rebuild;
+
+    fn app() -> Element {
+        rsx!(StatsValue { animate_to: 1000.0, duration_ms: 200, "%" })
+    }
+
+    let (mut dom, _) = rebuild(app);
+    assert_eq!(shown_stats_value(&dom), 1000.0);
+
+    // Once mounted the count starts over from 0
+    let seen = sample_stats_value(&mut dom, 1000.0);
+    assert!(seen[0] < 1000.0);
+    assert_eq!(seen.last(), Some(&1000.0));
+    assert!(seen.iter().any(|&value| value > 0.0 && value < 1000.0));
+    assert!(seen.windows(2).all(|pair| pair[0] <= pair[1]));
+}
+
+#[test]
+fn test_stats_value_counts_on_when_target_changes() {
+    use crate::test_utils::{fire, listener, rebuild, TestMouseData};
+
+    fn app() -> Element {
+        let mut target = use_signal(|| 1000.0);
+        rsx!(
+            button { onclick: move |_| target.set(2000.0), "Raise" }
+            StatsValue { animate_to: target(), duration_ms: 200, "%" }
+        )
+    }
+
+    let (mut dom, listeners) = rebuild(app);
+    sample_stats_value(&mut dom, 1000.0);
+
+    fire(&mut dom, "click", listener(&listeners, "click", 0), TestMouseData::default());
+    let seen = sample_stats_value(&mut dom, 2000.0);
+    assert_eq!(seen.last(), Some(&2000.0));
+    assert!(seen.iter().all(|&value| value >= 1000.0));
+    assert!(seen.iter().any(|&value| value > 1000.0 && value < 2000.0));
+}
+
+#[test]
+fn test_stats_item_href() {
+    let result = dioxus_ssr::render_element(rsx!(
+        StatsItem {
+            href: "/users",
+            StatsTitle { children: rsx!("Users") }
+        }
+    ));
+    assert!(result.contains(r#"<a class="stat" href="/users"><div class="stat-title">Users</div></a>"#));
+}
+
+#[test]
+fn test_stats_radial_figure() {
+    let result = dioxus_ssr::render_element(rsx!(
+        StatsItem {
+            StatsRadial { value: 70.0, color_scheme: ProgressColorScheme::Success }
+            StatsTitle { "Tasks done" }
+        }
+    ));
+    assert!(result.contains(
+        r#"<div class="stat-figure"><div class="radial-progress text-success" style="--value:70;" role="progressbar""#
+    ));
+    assert!(result.contains(">70%</div></div>"));
+}
+
+#[test]
+fn test_stats_sparkline_points() {
+    assert_eq!(sparkline_points(&[1.0, 3.0, 2.0], 100.0, 30.0), "0,30 50,0 100,15");
+    assert_eq!(sparkline_points(&[5.0, 10.0, 15.0, 20.0], 90.0, 30.0), "0,30 30,20 60,10 90,0");
+    assert_eq!(sparkline_points(&[1.0, 2.0, 4.0], 100.0, 30.0), "0,30 50,20 100,0");
+    assert_eq!(sparkline_points(&[4.0, 4.0], 100.0, 30.0), "0,15 100,15");
+    assert_eq!(sparkline_points(&[7.0], 100.0, 30.0), "0,15 100,15");
+    assert_eq!(sparkline_points(&[], 100.0, 30.0), "");
+}
+
+#[test]
+fn test_stats_figure_sparkline() {
+    let props = StatsFigureProps {
+        children: rsx!(),
+        id: None,
+        class: Some("text-success".to_string()),
+        sparkline: Some(vec![1.0, 3.0, 2.0]),
+    };
+
+    let result = dioxus_ssr::render_element(StatsFigure(props));
+    assert!(result.starts_with(r#"<div class="stat-figure text-success"><svg"#));
+    assert!(result.contains(r#"viewBox="0 0 100 30""#));
+    assert!(result.contains(r#"<polyline points="0,30 50,0 100,15""#));
+}
+
+#[test]
+fn test_stats_neutral_color_scheme() {
+    assert_eq!(StatsColorScheme::Neutral.to_string(), "stats-neutral");
+}
@@ -0,0 +1,133 @@
+#![allow(non_snake_case)]
+use std::fmt::Display;
+use dioxus::prelude::*;
+
+use crate::size::Size;
+
+/// A Status component showing a small colored status dot.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```text
+/// use daisy_rsx::{Size, Status, StatusColor};
+///
+/// Status {
+///     color: StatusColor::Success,
+///     size: Size::Md,
+///     label: "Online",
+/// }
+/// ```
+
+/// Color options for Status component
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum StatusColor {
+    /// Neutral color
+    Neutral,
+    /// Primary color
+    Primary,
+    /// Secondary color
+    Secondary,
+    /// Accent color
+    Accent,
+    /// Info color
+    Info,
+    /// Success color
+    Success,
+    /// Warning color
+    Warning,
+    /// Error color
+    Error,
+}
+
+impl Display for StatusColor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StatusColor::Neutral => write!(f, "status-neutral"),
+            StatusColor::Primary => write!(f, "status-primary"),
+            StatusColor::Secondary => write!(f, "status-secondary"),
+            StatusColor::Accent => write!(f, "status-accent"),
+            StatusColor::Info => write!(f, "status-info"),
+            StatusColor::Success => write!(f, "status-success"),
+            StatusColor::Warning => write!(f, "status-warning"),
+            StatusColor::Error => write!(f, "status-error"),
+        }
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct StatusProps {
+    /// Optional ID for status element
+    id: Option<String>,
+    /// Additional CSS classes to apply to status
+    class: Option<String>,
+    /// Color of the status dot
+    color: Option<StatusColor>,
+    /// Size of the status dot
+    size: Option<Size>,
+    /// Accessible label describing the status
+    label: Option<String>,
+}
+
+#[component]
+pub fn Status(props: StatusProps) -> Element {
+    let class = props.class.unwrap_or_default();
+
+    // Build CSS classes
+    let mut classes = vec!["status".to_string()];
+
+    if let Some(color) = props.color {
+        classes.push(color.to_string());
+    }
+
+    if let Some(size) = props.size {
+        classes.push(size.class("status"));
+    }
+
+    if !class.is_empty() {
+        classes.push(class);
+    }
+
+    let class_string = classes.join(" ");
+
+    rsx!(
+        div {
+            class: "{class_string}",
+            id: props.id,
+            role: "status",
+            "aria-label": props.label,
+        }
+    )
+}
+
+#[test]
+fn test_status_basic() {
+    let props = StatusProps {
+        id: None,
+        class: None,
+        color: None,
+        size: None,
+        label: None,
+    };
+
+    let result = dioxus_ssr::render_element(Status(props));
+    assert!(result.contains(r#"class="status""#));
+}
+
+#[test]
+fn test_status_color_size() {
+    let props = StatusProps {
+        id: None,
+        class: None,
+        color: Some(StatusColor::Success),
+        size: Some(Size::Lg),
+        label: Some("Online".to_string()),
+    };
+
+    let result = dioxus_ssr::render_element(Status(props));
+    assert!(result.contains(r#"class="status status-success status-lg""#));
+    assert!(result.contains(r#"aria-label="Online""#));
+}
@@ -2,6 +2,8 @@
 use std::fmt::Display;
 use dioxus::prelude::*;
 
+use crate::context::{try_context, try_provide_context};
+
 /// A Steps component that displays step-by-step progress indicators.
 ///
 /// # Examples
@@ -22,6 +24,8 @@ use dioxus::prelude::*;
 
 /// Orientation options for Steps component
 #[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
 pub enum StepsOrientation {
     #[default]
     /// Vertical orientation (default)
@@ -39,6 +43,42 @@ impl Display for StepsOrientation {
     }
 }
 
+/// Color options for a Step
+#[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum StepColor {
+    #[default]
+    /// Primary brand color
+    Primary,
+    /// Secondary color
+    Secondary,
+    /// Accent color
+    Accent,
+    /// Informational blue color
+    Info,
+    /// Success green color
+    Success,
+    /// Warning yellow color
+    Warning,
+    /// Error red color
+    Error,
+}
+
+impl Display for StepColor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StepColor::Primary => write!(f, "step-primary"),
+            StepColor::Secondary => write!(f, "step-secondary"),
+            StepColor::Accent => write!(f, "step-accent"),
+            StepColor::Info => write!(f, "step-info"),
+            StepColor::Success => write!(f, "step-success"),
+            StepColor::Warning => write!(f, "step-warning"),
+            StepColor::Error => write!(f, "step-error"),
+        }
+    }
+}
+
 #[derive(Props, Clone, PartialEq)]
 pub struct StepsProps {
     /// The content to display inside the steps
@@ -51,6 +91,8 @@ pub struct StepsProps {
     orientation: Option<StepsOrientation>,
     /// Current step number (1-indexed)
     current_step: Option<i32>,
+    /// Scroll horizontally instead of overflowing when there are many steps
+    scrollable: Option<bool>,
 }
 
 #[component]
@@ -58,10 +100,15 @@ pub fn Steps(props: StepsProps) -> Element {
     let orientation = props.orientation.unwrap_or_default();
     let class = props.class.unwrap_or_default();
     let current_step = props.current_step.unwrap_or(0);
+    let scrollable = props.scrollable.filter(|&x| x);
 
     // Build CSS classes
     let mut classes = vec!["steps".to_string()];
     classes.push(orientation.to_string());
+
+    if scrollable.is_some() {
+        classes.push("min-w-max".to_string());
+    }
     
     if !class.is_empty() {
         classes.push(class);
@@ -69,19 +116,33 @@ pub fn Steps(props: StepsProps) -> Element {
 
     let class_string = classes.join(" ");
 
-    // Provide context for child steps
-    let steps_context = StepsContext { current_step };
+    // Provided on every render so a changed step reaches the children
+    try_provide_context(StepsContext { current_step });
 
-    rsx!(
-        ul {
-            class: "{class_string}",
-            id: props.id,
-            {props.children}
-        }
-    )
+    if scrollable.is_some() {
+        rsx!(
+            div {
+                class: "overflow-x-auto",
+                ul {
+                    class: "{class_string}",
+                    id: props.id,
+                    {props.children}
+                }
+            }
+        )
+    } else {
+        rsx!(
+            ul {
+                class: "{class_string}",
+                id: props.id,
+                {props.children}
+            }
+        )
+    }
 }
 
-#[derive(Clone, Copy)]
+/// The current step, shared by `Steps` with the `Step`s inside it
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct StepsContext {
     pub current_step: i32,
 }
@@ -94,26 +155,30 @@ pub struct StepProps {
     id: Option<String>,
     /// Additional CSS classes to apply to the step
     class: Option<String>,
-    /// Step number
+    /// Step number (1-indexed), compared with the parent's `current_step`
     value: i32,
+    /// Color of the step once reached, in place of `step-primary`
+    color: Option<StepColor>,
+    /// Glyph shown in the step's circle instead of its number, e.g. `✓`
+    content: Option<String>,
 }
 
 #[component]
 pub fn Step(props: StepProps) -> Element {
     let class = props.class.unwrap_or_default();
 
-    // Determine step state based on current step
-    let state = if props.value < 0 {
-        "step-completed".to_string()
-    } else if props.value == 0 {
-        "step-current".to_string()
-    } else {
-        "step-pending".to_string()
-    };
+    let current_step = try_context::<StepsContext>()
+        .map(|context| context.current_step)
+        .unwrap_or(0);
 
     // Build CSS classes
-    let mut classes = vec!["step".to_string(), state];
-    
+    let mut classes = vec!["step".to_string()];
+
+    // Completed and current steps are highlighted, pending ones stay plain
+    if props.value <= current_step {
+        classes.push(props.color.unwrap_or(StepColor::Primary).to_string());
+    }
+
     if !class.is_empty() {
         classes.push(class);
     }
@@ -124,11 +189,70 @@ pub fn Step(props: StepProps) -> Element {
         li {
             class: "{class_string}",
             id: props.id,
+            "data-content": props.content,
             {props.children}
         }
     )
 }
 
+/// Wizard state returned by [`use_stepper`]. Steps are 1-indexed so
+/// `current()` can be passed straight to `Steps.current_step`.
+#[derive(Clone, Copy, PartialEq)]
+pub struct UseStepper {
+    current: Signal<usize>,
+    total: usize,
+}
+
+impl UseStepper {
+    /// The current step, between 1 and the total number of steps
+    pub fn current(&self) -> i32 {
+        (self.current)() as i32
+    }
+
+    /// The total number of steps
+    pub fn total(&self) -> usize {
+        self.total
+    }
+
+    /// Whether the current step is the first one
+    pub fn is_first(&self) -> bool {
+        (self.current)() <= 1
+    }
+
+    /// Whether the current step is the last one
+    pub fn is_last(&self) -> bool {
+        (self.current)() >= self.total
+    }
+
+    /// Move to the next step, staying on the last step at the end
+    pub fn next(&mut self) {
+        let step = (self.current)() + 1;
+        self.goto(step);
+    }
+
+    /// Move to the previous step, staying on the first step at the start
+    pub fn prev(&mut self) {
+        let step = (self.current)().saturating_sub(1);
+        self.goto(step);
+    }
+
+    /// Jump to `step`, clamped to the valid range
+    pub fn goto(&mut self, step: usize) {
+        self.current.set(clamp_step(step, self.total));
+    }
+}
+
+fn clamp_step(step: usize, total: usize) -> usize {
+    step.clamp(1, total.max(1))
+}
+
+/// Tracks the current step of a multi-step flow with `total` steps,
+/// starting on step 1.
+pub fn use_stepper(total: usize) -> UseStepper {
+    let current = use_signal(|| 1);
+    UseStepper { current, total }
+}
+
 #[test]
 fn test_steps_basic() {
     let props = StepsProps {
@@ -141,6 +265,7 @@ fn test_steps_basic() {
         class: None,
         orientation: None,
         current_step: None,
+        scrollable: None,
     };
 
     let result = dioxus_ssr::render_element(Steps(props));
@@ -158,6 +283,7 @@ fn test_steps_horizontal() {
         class: None,
         orientation: Some(StepsOrientation::Horizontal),
         current_step: None,
+        scrollable: None,
     };
 
     let result = dioxus_ssr::render_element(Steps(props));
@@ -174,6 +300,7 @@ fn test_steps_with_custom_class() {
         class: Some("custom-class".to_string()),
         orientation: None,
         current_step: None,
+        scrollable: None,
     };
 
     let result = dioxus_ssr::render_element(Steps(props));
@@ -187,10 +314,12 @@ fn test_step_basic() {
         id: None,
         class: None,
         value: 1,
+        color: None,
+        content: None,
     };
 
     let result = dioxus_ssr::render_element(Step(props));
-    assert!(result.contains(r#"class="step step-pending""#));
+    assert!(result.contains(r#"class="step""#));
 }
 
 #[test]
@@ -200,10 +329,12 @@ fn test_step_with_custom_class() {
         id: None,
         class: Some("custom-step-class".to_string()),
         value: 1,
+        color: None,
+        content: None,
     };
 
     let result = dioxus_ssr::render_element(Step(props));
-    assert!(result.contains(r#"class="step step-pending custom-step-class""#));
+    assert!(result.contains(r#"class="step custom-step-class""#));
 }
 
 #[test]
@@ -213,8 +344,118 @@ fn test_step_with_id() {
         id: Some("test-step".to_string()),
         class: None,
         value: 1,
+        color: None,
+        content: None,
     };
 
     let result = dioxus_ssr::render_element(Step(props));
     assert!(result.contains(r#"id="test-step""#));
 }
+
+#[test]
+fn test_steps_scrollable() {
+    let props = StepsProps {
+        children: rsx!(
+            Step { value: 1, children: rsx!("Step 1") }
+        ),
+        id: None,
+        class: None,
+        orientation: Some(StepsOrientation::Horizontal),
+        current_step: None,
+        scrollable: Some(true),
+    };
+
+    let result = dioxus_ssr::render_element(Steps(props));
+    assert!(result.starts_with(
+        r#"<div class="overflow-x-auto"><ul class="steps steps-horizontal min-w-max">"#
+    ));
+}
+
+#[test]
+fn test_steps_current_step_marks_primary() {
+    let result = dioxus_ssr::render_element(rsx!(
+        Steps {
+            current_step: 2,
+            Step { value: 1, "Register" }
+            Step { value: 2, "Choose plan" }
+            Step { value: 3, "Purchase" }
+        }
+    ));
+    assert!(result.contains(r#"<li class="step step-primary">Register</li>"#));
+    assert!(result.contains(r#"<li class="step step-primary">Choose plan</li>"#));
+    assert!(result.contains(r#"<li class="step">Purchase</li>"#));
+}
+
+#[test]
+fn test_step_color() {
+    let result = dioxus_ssr::render_element(rsx!(
+        Steps {
+            current_step: 1,
+            Step { value: 1, color: StepColor::Success, "Step 1" }
+        }
+    ));
+    assert!(result.contains(r#"<li class="step step-success">Step 1</li>"#));
+}
+
+#[test]
+fn test_step_color_skips_pending_steps() {
+    let result = dioxus_ssr::render_element(rsx!(
+        Steps {
+            current_step: 1,
+            Step { value: 1, color: StepColor::Success, "Register" }
+            Step { value: 2, color: StepColor::Success, "Purchase" }
+        }
+    ));
+    assert!(result.contains(r#"<li class="step step-success">Register</li>"#));
+    assert!(result.contains(r#"<li class="step">Purchase</li>"#));
+}
+
+#[test]
+fn test_step_content() {
+    let props = StepProps {
+        children: rsx!("Register"),
+        id: None,
+        class: None,
+        value: 1,
+        color: None,
+        content: Some("✓".to_string()),
+    };
+
+    let result = dioxus_ssr::render_element(Step(props));
+    assert!(result.contains(r#"<li class="step" data-content="✓">Register</li>"#));
+}
+
+#[test]
+fn test_use_stepper_clamps() {
+    use crate::test_utils::{fire, listener, rebuild, TestMouseData};
+
+    fn app() -> Element {
+        let mut stepper = use_stepper(3);
+        rsx!(
+            button { onclick: move |_| stepper.next(), "Next" }
+            button { onclick: move |_| stepper.prev(), "Back" }
+            button { onclick: move |_| stepper.goto(10), "Finish" }
+            "Step {stepper.current()} of {stepper.total()}"
+        )
+    }
+
+    let (mut dom, listeners) = rebuild(app);
+    let next = listener(&listeners, "click", 0);
+    let prev = listener(&listeners, "click", 1);
+    let finish = listener(&listeners, "click", 2);
+    assert!(dioxus_ssr::render(&dom).contains("Step 1 of 3"));
+
+    fire(&mut dom, "click", prev, TestMouseData::default());
+    assert!(dioxus_ssr::render(&dom).contains("Step 1 of 3"));
+
+    for _ in 0..4 {
+        fire(&mut dom, "click", next, TestMouseData::default());
+    }
+    assert!(dioxus_ssr::render(&dom).contains("Step 3 of 3"));
+
+    fire(&mut dom, "click", prev, TestMouseData::default());
+    assert!(dioxus_ssr::render(&dom).contains("Step 2 of 3"));
+
+    fire(&mut dom, "click", finish, TestMouseData::default());
+    assert!(dioxus_ssr::render(&dom).contains("Step 3 of 3"));
+}
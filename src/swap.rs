@@ -2,6 +2,8 @@
 use std::fmt::Display;
 use dioxus::prelude::*;
 
+use crate::motion::reduced_motion;
+
 /// A Swap component that allows swapping between two elements on hover or click.
 ///
 /// # Examples
@@ -20,6 +22,8 @@ use dioxus::prelude::*;
 
 /// Animation options for Swap component
 #[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
 pub enum SwapAnimation {
     #[default]
     /// Fade animation
@@ -42,6 +46,8 @@ impl Display for SwapAnimation {
 
 /// Size options for Swap component
 #[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
 pub enum SwapSize {
     #[default]
     /// Default size
@@ -91,7 +97,7 @@ pub fn Swap(props: SwapProps) -> Element {
     // Build CSS classes
     let mut classes = vec!["swap".to_string()];
     
-    if !animation.to_string().is_empty() {
+    if !animation.to_string().is_empty() && !reduced_motion() {
         classes.push(animation.to_string());
     }
     
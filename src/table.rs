@@ -2,6 +2,12 @@
 use std::fmt::Display;
 use dioxus::prelude::*;
 
+use crate::breakpoint::Breakpoint;
+use crate::card::{Card, CardBody};
+use crate::class::ClassBuilder;
+use crate::density::current_density;
+use crate::show::Show;
+
 /// An enhanced table component that provides comprehensive styling options based on DaisyUI table component.
 ///
 /// # Examples
@@ -58,6 +64,8 @@ use dioxus::prelude::*;
 
 /// Size options for Table component
 #[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
 pub enum TableSize {
     #[default]
     /// Default size (equivalent to Medium)
@@ -87,6 +95,27 @@ impl Display for TableSize {
     }
 }
 
+/// Placement options for a Table caption
+#[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum CaptionSide {
+    #[default]
+    /// Above the table
+    Top,
+    /// Below the table
+    Bottom,
+}
+
+impl Display for CaptionSide {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CaptionSide::Top => write!(f, "caption-top"),
+            CaptionSide::Bottom => write!(f, "caption-bottom"),
+        }
+    }
+}
+
 #[derive(Props, Clone, PartialEq)]
 pub struct TableProps {
     /// The content to display inside the table
@@ -105,6 +134,12 @@ pub struct TableProps {
     pin_cols: Option<bool>,
     /// Whether to apply hover effects to rows
     row_hover: Option<bool>,
+    /// Caption describing the table, rendered as its first child
+    caption: Option<String>,
+    /// Whether the caption sits above or below the table
+    caption_side: Option<CaptionSide>,
+    /// Wrap the table in a horizontally scrolling container; the `id` moves to the wrapper
+    responsive: Option<bool>,
 }
 
 #[component]
@@ -117,28 +152,132 @@ pub fn Table(props: TableProps) -> Element {
     let row_hover = props.row_hover.filter(|&x| x);
 
     // Build CSS classes
-    let mut classes = vec!["table".to_string()];
-    
-    if !size.to_string().is_empty() {
-        classes.push(size.to_string());
+    let mut classes = ClassBuilder::new("table");
+
+    if size == TableSize::Default {
+        classes.push_opt(current_density().class("table-xs"));
+    } else {
+        classes.push(size);
     }
-    
-    if zebra.is_some() {
-        classes.push("table-zebra".to_string());
+
+    let class_string = classes
+        .push_if(zebra.is_some(), "table-zebra")
+        .push_if(pin_rows.is_some(), "table-pin-rows")
+        .push_if(pin_cols.is_some(), "table-pin-cols")
+        .push_if(row_hover.is_some(), "row-hover")
+        .push(class)
+        .build();
+    let caption_side = props.caption_side.unwrap_or_default();
+    let caption = props.caption.map(|caption| rsx!(
+        caption {
+            class: "{caption_side}",
+            "{caption}"
+        }
+    ));
+
+    if props.responsive.unwrap_or(false) {
+        rsx!(
+            div {
+                class: "overflow-x-auto",
+                id: props.id,
+                table {
+                    class: "{class_string}",
+                    {caption}
+                    {props.children}
+                }
+            }
+        )
+    } else {
+        rsx!(
+            table {
+                class: "{class_string}",
+                id: props.id,
+                {caption}
+                {props.children}
+            }
+        )
     }
-    
-    if pin_rows.is_some() {
-        classes.push("table-pin-rows".to_string());
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct TableFooterProps {
+    /// The footer rows
+    children: Element,
+    /// Optional ID for the footer element
+    id: Option<String>,
+    /// Additional CSS classes to apply to the footer
+    class: Option<String>,
+}
+
+/// A `tfoot` for summary rows, kept in view by the table's `pin_rows`
+#[component]
+pub fn TableFooter(props: TableFooterProps) -> Element {
+    let class = props.class.filter(|class| !class.is_empty());
+
+    rsx!(
+        tfoot {
+            class,
+            id: props.id,
+            {props.children}
+        }
+    )
+}
+
+/// Text alignment options for TableCell component
+#[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum TableCellAlign {
+    #[default]
+    /// Left aligned (default)
+    Left,
+    /// Centered
+    Center,
+    /// Right aligned, e.g. for numbers
+    Right,
+}
+
+impl Display for TableCellAlign {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TableCellAlign::Left => write!(f, "text-left"),
+            TableCellAlign::Center => write!(f, "text-center"),
+            TableCellAlign::Right => write!(f, "text-right"),
+        }
     }
-    
-    if pin_cols.is_some() {
-        classes.push("table-pin-cols".to_string());
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct TableRowProps {
+    /// The cells of the row
+    children: Element,
+    /// Optional ID for the row element
+    id: Option<String>,
+    /// Additional CSS classes to apply to the row
+    class: Option<String>,
+    /// Whether the row is selected (highlighted)
+    selected: Option<bool>,
+    /// Whether the row is the current one, e.g. the record being viewed
+    active: Option<bool>,
+}
+
+#[component]
+pub fn TableRow(props: TableRowProps) -> Element {
+    let class = props.class.unwrap_or_default();
+    let selected = props.selected.filter(|&x| x);
+    let active = props.active.filter(|&x| x);
+
+    // Build CSS classes
+    let mut classes = vec![];
+
+    if selected.is_some() {
+        classes.push("bg-base-200".to_string());
     }
-    
-    if row_hover.is_some() {
-        classes.push("row-hover".to_string());
+
+    if active.is_some() {
+        classes.push("font-semibold".to_string());
     }
-    
+
     if !class.is_empty() {
         classes.push(class);
     }
@@ -146,14 +285,397 @@ pub fn Table(props: TableProps) -> Element {
     let class_string = classes.join(" ");
 
     rsx!(
-        table {
-            class: "{class_string}",
+        tr {
+            class: (!class_string.is_empty()).then_some(class_string),
             id: props.id,
+            aria_selected: selected.map(|_| "true"),
+            aria_current: active.map(|_| "true"),
             {props.children}
         }
     )
 }
 
+#[derive(Props, Clone, PartialEq)]
+pub struct TableCellProps {
+    /// The content to display inside the cell
+    children: Element,
+    /// Optional ID for the cell element
+    id: Option<String>,
+    /// Additional CSS classes to apply to the cell
+    class: Option<String>,
+    /// Text alignment of the cell
+    align: Option<TableCellAlign>,
+    /// Render as a header cell (`th`) instead of a data cell (`td`)
+    header: Option<bool>,
+}
+
+#[component]
+pub fn TableCell(props: TableCellProps) -> Element {
+    let class = props.class.unwrap_or_default();
+    let header = props.header.filter(|&x| x);
+
+    // Build CSS classes
+    let mut classes = vec![];
+
+    if let Some(align) = props.align {
+        classes.push(align.to_string());
+    }
+
+    if !class.is_empty() {
+        classes.push(class);
+    }
+
+    let class_string = classes.join(" ");
+    let class = (!class_string.is_empty()).then_some(class_string);
+
+    if header.is_some() {
+        rsx!(
+            th {
+                class,
+                id: props.id,
+                {props.children}
+            }
+        )
+    } else {
+        rsx!(
+            td {
+                class,
+                id: props.id,
+                {props.children}
+            }
+        )
+    }
+}
+
+/// Sort state of a column, shown to assistive technology via `aria-sort`
+#[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum SortDirection {
+    #[default]
+    /// Not sorted by this column
+    None,
+    /// Sorted from lowest to highest
+    Ascending,
+    /// Sorted from highest to lowest
+    Descending,
+}
+
+impl Display for SortDirection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SortDirection::None => write!(f, "none"),
+            SortDirection::Ascending => write!(f, "ascending"),
+            SortDirection::Descending => write!(f, "descending"),
+        }
+    }
+}
+
+impl SortDirection {
+    /// Arrow shown next to the header text
+    fn indicator(&self) -> &'static str {
+        match self {
+            SortDirection::None => "↕",
+            SortDirection::Ascending => "▲",
+            SortDirection::Descending => "▼",
+        }
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct TableSortHeaderProps {
+    /// The header text
+    children: Element,
+    /// Key identifying the column, emitted as `data-sort-key` and passed to `onsort`
+    sort_key: String,
+    /// Current sort direction of the column
+    direction: Option<SortDirection>,
+    /// Called with the sort key when the header is clicked
+    onsort: Option<EventHandler<String>>,
+    /// Whether to show the sort arrow (default: true)
+    show_indicator: Option<bool>,
+    /// Optional ID for the header cell
+    id: Option<String>,
+    /// Additional CSS classes to apply to the header cell
+    class: Option<String>,
+}
+
+/// A `th` for a sortable column. Sorting itself is left to the caller:
+///
+/// ```text
+/// TableSortHeader {
+///     sort_key: "age",
+///     direction: if sort_by() == "age" { SortDirection::Ascending } else { SortDirection::None },
+///     onsort: move |key: String| sort_by.set(key),
+///     "Age"
+/// }
+/// ```
+#[component]
+pub fn TableSortHeader(props: TableSortHeaderProps) -> Element {
+    let direction = props.direction.unwrap_or_default();
+    let class = props.class.unwrap_or_default();
+    let show_indicator = props.show_indicator.unwrap_or(true);
+
+    let content = rsx!(
+        {props.children}
+        if show_indicator {
+            span {
+                class: "ml-1 opacity-60",
+                aria_hidden: "true",
+                "{direction.indicator()}"
+            }
+        }
+    );
+
+    // A button makes the header reachable and operable from the keyboard,
+    // while `aria-sort` stays on the `th` where screen readers expect it
+    let content = match props.onsort {
+        Some(onsort) => {
+            let sort_key = props.sort_key.clone();
+            rsx!(
+                button {
+                    r#type: "button",
+                    class: "inline-flex items-center cursor-pointer select-none",
+                    onclick: move |_| onsort.call(sort_key.clone()),
+                    {content}
+                }
+            )
+        }
+        None => content,
+    };
+
+    rsx!(
+        th {
+            class: (!class.is_empty()).then_some(class),
+            id: props.id,
+            "data-sort-key": "{props.sort_key}",
+            aria_sort: "{direction}",
+            {content}
+        }
+    )
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct DataTableProps {
+    /// Column headings, also used as the labels on mobile cards
+    columns: Vec<String>,
+    /// Cell text, one entry per column in each row
+    rows: Vec<Vec<String>>,
+    /// Optional ID for the table (or the wrapper, when stacking)
+    id: Option<String>,
+    /// Additional CSS classes to apply to the table
+    class: Option<String>,
+    /// Size of the table
+    size: Option<TableSize>,
+    /// Whether to apply zebra striping to rows
+    zebra: Option<bool>,
+    /// Whether to apply hover effects to rows
+    row_hover: Option<bool>,
+    /// Below `breakpoint`, show each row as a card of label/value pairs
+    stack_on_mobile: Option<bool>,
+    /// Screen size where the table replaces the cards (default: md)
+    breakpoint: Option<Breakpoint>,
+    /// Add a checkbox to each row for selecting it. Changing `rows` clears the selection
+    selectable: Option<bool>,
+    /// Show "3 of 20 selected" and a clear button below a selectable table
+    selection_summary: Option<bool>,
+    /// Called with the indexes of the selected rows whenever they change,
+    /// including when new `rows` clear the selection
+    onselectionchange: Option<EventHandler<Vec<usize>>>,
+}
+
+/// A Table built from column headings and rows of text.
+///
+/// ```text
+/// DataTable {
+///     columns: vec!["Name".into(), "Email".into()],
+///     rows: users.iter().map(|u| vec![u.name.clone(), u.email.clone()]).collect(),
+///     stack_on_mobile: true,
+/// }
+/// ```
+#[component]
+pub fn DataTable(props: DataTableProps) -> Element {
+    // Selection state lives in its own component, so a plain DataTable can
+    // still be rendered without a runtime
+    if props.selectable.unwrap_or(false) {
+        return rsx!(SelectableDataTable { table: props });
+    }
+
+    data_table(props, None)
+}
+
+#[derive(Props, Clone, PartialEq)]
+struct SelectableDataTableProps {
+    table: DataTableProps,
+}
+
+#[component]
+fn SelectableDataTable(props: SelectableDataTableProps) -> Element {
+    let mut selection = use_signal(Vec::<usize>::new);
+    let onselectionchange = props.table.onselectionchange;
+
+    // Indexes point into the rows, so new rows start with nothing selected
+    use_effect(use_reactive(&props.table.rows, move |_| {
+        if !selection.peek().is_empty() {
+            selection.set(vec![]);
+            if let Some(handler) = onselectionchange {
+                handler.call(vec![]);
+            }
+        }
+    }));
+
+    data_table(props.table, Some(selection))
+}
+
+/// Renders a DataTable, with a checkbox per row when there is a `selection`
+fn data_table(props: DataTableProps, selection: Option<Signal<Vec<usize>>>) -> Element {
+    let stack_on_mobile = props.stack_on_mobile.filter(|&x| x);
+    let breakpoint = props.breakpoint.unwrap_or(Breakpoint::Md);
+    let onselectionchange = props.onselectionchange;
+
+    let row_count = props.rows.len();
+    // Until SelectableDataTable clears them, indexes past the last row are left out
+    let mut selected: Vec<usize> = selection.map(|selection| selection()).unwrap_or_default();
+    selected.retain(|&index| index < row_count);
+    let all_selected = row_count > 0 && selected.len() == row_count;
+
+    let update = move |next: Vec<usize>| {
+        if let Some(mut selection) = selection {
+            selection.set(next.clone());
+        }
+        if let Some(handler) = onselectionchange {
+            handler.call(next);
+        }
+    };
+    let toggle_row = move |index: usize| {
+        let mut next = selection.map(|selection| selection()).unwrap_or_default();
+        match next.iter().position(|&selected| selected == index) {
+            Some(position) => {
+                next.remove(position);
+            }
+            None => {
+                next.push(index);
+                next.sort_unstable();
+            }
+        }
+        update(next);
+    };
+
+    let table = rsx!(
+        Table {
+            id: if stack_on_mobile.is_none() { props.id.clone() } else { None },
+            class: props.class,
+            size: props.size,
+            zebra: props.zebra,
+            row_hover: props.row_hover,
+            thead {
+                tr {
+                    if selection.is_some() {
+                        th {
+                            input {
+                                r#type: "checkbox",
+                                class: "checkbox checkbox-sm",
+                                aria_label: "Select all rows",
+                                checked: all_selected,
+                                onchange: move |_| {
+                                    update(if all_selected { vec![] } else { (0..row_count).collect() })
+                                },
+                            }
+                        }
+                    }
+                    for column in props.columns.iter() {
+                        th { "{column}" }
+                    }
+                }
+            }
+            tbody {
+                for (index, row) in props.rows.iter().enumerate() {
+                    TableRow {
+                        selected: selected.contains(&index),
+                        if selection.is_some() {
+                            td {
+                                input {
+                                    r#type: "checkbox",
+                                    class: "checkbox checkbox-sm",
+                                    aria_label: "Select row {index + 1}",
+                                    checked: selected.contains(&index),
+                                    onchange: move |_| toggle_row(index),
+                                }
+                            }
+                        }
+                        for cell in row.iter() {
+                            td { "{cell}" }
+                        }
+                    }
+                }
+            }
+        }
+    );
+
+    let summary = props.selection_summary.filter(|&x| x).and(selection).map(|_| rsx!(
+        div {
+            class: "flex items-center justify-between gap-2 px-4 py-2 text-sm",
+            role: "status",
+            span { "{selected.len()} of {row_count} selected" }
+            button {
+                r#type: "button",
+                class: "btn btn-ghost btn-xs",
+                disabled: selected.is_empty(),
+                onclick: move |_| update(vec![]),
+                "Clear selection"
+            }
+        }
+    ));
+
+    if stack_on_mobile.is_none() {
+        return match summary {
+            Some(summary) => rsx!(
+                div {
+                    {table}
+                    {summary}
+                }
+            ),
+            None => table,
+        };
+    }
+
+    rsx!(
+        div {
+            id: props.id,
+            Show { from: breakpoint, {table} }
+            Show {
+                until: breakpoint,
+                class: "flex flex-col gap-2",
+                for (index, row) in props.rows.iter().enumerate() {
+                    Card {
+                        class: "bg-base-100 shadow-sm",
+                        CardBody {
+                            class: "p-4",
+                            if selection.is_some() {
+                                input {
+                                    r#type: "checkbox",
+                                    class: "checkbox checkbox-sm",
+                                    aria_label: "Select row {index + 1}",
+                                    checked: selected.contains(&index),
+                                    onchange: move |_| toggle_row(index),
+                                }
+                            }
+                            dl {
+                                class: "grid grid-cols-2 gap-x-4 gap-y-1 text-sm",
+                                for (column, cell) in props.columns.iter().zip(row.iter()) {
+                                    dt { class: "font-semibold", "{column}" }
+                                    dd { "{cell}" }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            {summary}
+        }
+    )
+}
+
 #[test]
 fn test_table_basic() {
     let props = TableProps {
@@ -176,6 +698,9 @@ fn test_table_basic() {
         pin_rows: None,
         pin_cols: None,
         row_hover: None,
+        caption: None,
+        caption_side: None,
+        responsive: None,
     };
 
     let result = dioxus_ssr::render_element(Table(props));
@@ -208,6 +733,9 @@ fn test_table_with_all_props() {
         pin_rows: Some(true),
         pin_cols: Some(true),
         row_hover: Some(true),
+        caption: None,
+        caption_side: None,
+        responsive: None,
     };
 
     let result = dioxus_ssr::render_element(Table(props));
@@ -238,6 +766,9 @@ fn test_all_table_sizes() {
             pin_rows: None,
             pin_cols: None,
             row_hover: None,
+            caption: None,
+            caption_side: None,
+            responsive: None,
         };
 
         let result = dioxus_ssr::render_element(Table(props));
@@ -264,6 +795,9 @@ fn test_table_zebra() {
         pin_rows: None,
         pin_cols: None,
         row_hover: None,
+        caption: None,
+        caption_side: None,
+        responsive: None,
     };
 
     let result = dioxus_ssr::render_element(Table(props));
@@ -281,6 +815,9 @@ fn test_table_pin_rows() {
         pin_rows: Some(true),
         pin_cols: None,
         row_hover: None,
+        caption: None,
+        caption_side: None,
+        responsive: None,
     };
 
     let result = dioxus_ssr::render_element(Table(props));
@@ -298,6 +835,9 @@ fn test_table_pin_cols() {
         pin_rows: None,
         pin_cols: Some(true),
         row_hover: None,
+        caption: None,
+        caption_side: None,
+        responsive: None,
     };
 
     let result = dioxus_ssr::render_element(Table(props));
@@ -315,6 +855,9 @@ fn test_table_row_hover() {
         pin_rows: None,
         pin_cols: None,
         row_hover: Some(true),
+        caption: None,
+        caption_side: None,
+        responsive: None,
     };
 
     let result = dioxus_ssr::render_element(Table(props));
@@ -349,6 +892,9 @@ fn test_table_with_all_features() {
         pin_rows: Some(true),
         pin_cols: Some(true),
         row_hover: Some(true),
+        caption: None,
+        caption_side: None,
+        responsive: None,
     };
 
     let result = dioxus_ssr::render_element(Table(props));
@@ -358,4 +904,258 @@ fn test_table_with_all_features() {
     assert!(result.contains("<tbody>"));
     assert!(result.contains("<th>Header 1</th>"));
     assert!(result.contains("<td>Row 1 Col 1</td>"));
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_table_rows_and_cells() {
+    let props = TableProps {
+        children: rsx!(
+            thead {
+                TableRow {
+                    TableCell { header: true, "Name" }
+                    TableCell { header: true, align: TableCellAlign::Right, "Age" }
+                }
+            }
+            tbody {
+                TableRow {
+                    TableCell { "John" }
+                    TableCell { align: TableCellAlign::Right, "25" }
+                }
+                TableRow {
+                    selected: true,
+                    TableCell { "Jane" }
+                    TableCell { align: TableCellAlign::Right, "30" }
+                }
+            }
+        ),
+        id: None,
+        class: None,
+        size: None,
+        zebra: None,
+        pin_rows: None,
+        pin_cols: None,
+        row_hover: None,
+        caption: None,
+        caption_side: None,
+        responsive: None,
+    };
+
+    let result = dioxus_ssr::render_element(Table(props));
+    assert!(result.contains(r#"<tr><th>Name</th><th class="text-right">Age</th></tr>"#));
+    assert!(result.contains(r#"<tr><td>John</td><td class="text-right">25</td></tr>"#));
+    assert!(result.contains(r#"<tr class="bg-base-200" aria-selected="true"><td>Jane</td>"#));
+}
+
+#[test]
+fn test_table_sort_header_aria_sort() {
+    for (direction, aria_sort, indicator) in [
+        (SortDirection::None, "none", "↕"),
+        (SortDirection::Ascending, "ascending", "▲"),
+        (SortDirection::Descending, "descending", "▼"),
+    ] {
+        let props = TableSortHeaderProps {
+            children: rsx!("Age"),
+            sort_key: "age".to_string(),
+            direction: Some(direction),
+            onsort: None,
+            show_indicator: None,
+            id: None,
+            class: None,
+        };
+
+        let result = dioxus_ssr::render_element(TableSortHeader(props));
+        assert!(result.contains(&format!(r#"<th data-sort-key="age" aria-sort="{aria_sort}">Age"#)));
+        assert!(result.contains(&format!(r#"aria-hidden="true">{indicator}</span>"#)));
+    }
+}
+
+#[test]
+fn test_table_sort_header_onsort() {
+    use crate::test_utils::{fire, listener, rebuild, TestMouseData};
+
+    fn app() -> Element {
+        let mut sorted_by = use_signal(String::new);
+        rsx!(
+            TableSortHeader {
+                sort_key: "name",
+                onsort: move |key| sorted_by.set(key),
+                "Name"
+            }
+            "Sorted by: {sorted_by}"
+        )
+    }
+
+    let (mut dom, listeners) = rebuild(app);
+    assert!(dioxus_ssr::render(&dom).starts_with(
+        r#"<th data-sort-key="name" aria-sort="none"><button type="button" class="inline-flex items-center cursor-pointer select-none">Name<span"#
+    ));
+
+    fire(&mut dom, "click", listener(&listeners, "click", 0), TestMouseData::default());
+    assert!(dioxus_ssr::render(&dom).contains("Sorted by: name"));
+}
+
+#[test]
+fn test_table_caption_and_footer() {
+    let props = TableProps {
+        children: rsx!(
+            thead { tr { th { "Item" } th { "Price" } } }
+            tbody { tr { td { "Tea" } td { "3" } } }
+            TableFooter { tr { td { "Total" } td { "3" } } }
+        ),
+        id: None,
+        class: None,
+        size: None,
+        zebra: None,
+        pin_rows: Some(true),
+        pin_cols: None,
+        row_hover: None,
+        caption: Some("Order summary".to_string()),
+        caption_side: Some(CaptionSide::Bottom),
+        responsive: None,
+    };
+
+    let result = dioxus_ssr::render_element(Table(props));
+    assert!(result.contains(
+        r#"<table class="table table-pin-rows"><caption class="caption-bottom">Order summary</caption><thead>"#
+    ));
+    assert!(result.contains("<tfoot><tr><td>Total</td><td>3</td></tr></tfoot></table>"));
+}
+
+#[test]
+fn test_table_responsive_wrapper() {
+    let table = |responsive| TableProps {
+        children: rsx!(tbody { tr { td { "Data" } } }),
+        id: Some("orders".to_string()),
+        class: None,
+        size: None,
+        zebra: Some(true),
+        pin_rows: None,
+        pin_cols: None,
+        row_hover: None,
+        caption: None,
+        caption_side: None,
+        responsive,
+    };
+
+    let result = dioxus_ssr::render_element(Table(table(Some(true))));
+    assert!(result.starts_with(
+        r#"<div class="overflow-x-auto" id="orders"><table class="table table-zebra"><tbody>"#
+    ));
+
+    let result = dioxus_ssr::render_element(Table(table(None)));
+    assert!(!result.contains("overflow-x-auto"));
+    assert!(result.starts_with(r#"<table class="table table-zebra" id="orders">"#));
+}
+
+#[test]
+fn test_data_table_stack_on_mobile() {
+    let table = |stack_on_mobile| DataTableProps {
+        columns: vec!["Name".to_string(), "Age".to_string()],
+        rows: vec![
+            vec!["John".to_string(), "25".to_string()],
+            vec!["Jane".to_string(), "30".to_string()],
+        ],
+        id: Some("people".to_string()),
+        class: None,
+        size: None,
+        zebra: None,
+        row_hover: None,
+        stack_on_mobile,
+        breakpoint: None,
+        selectable: None,
+        selection_summary: None,
+        onselectionchange: None,
+    };
+
+    let result = dioxus_ssr::render_element(DataTable(table(Some(true))));
+    assert!(result.starts_with(r#"<div id="people"><div class="hidden md:block"><table class="table">"#));
+    assert!(result.contains(r#"<div class="flex md:hidden flex-col gap-2">"#));
+    assert_eq!(result.matches(r#"class="card bg-base-100 shadow-sm""#).count(), 2);
+    assert!(result.contains(
+        r#"<dt class="font-semibold">Name</dt><dd>Jane</dd><dt class="font-semibold">Age</dt><dd>30</dd>"#
+    ));
+
+    let result = dioxus_ssr::render_element(DataTable(table(None)));
+    assert!(result.starts_with(r#"<table class="table" id="people"><thead><tr><th>Name</th><th>Age</th></tr>"#));
+    assert!(!result.contains("card"));
+}
+
+#[test]
+fn test_data_table_selection_summary() {
+    use crate::test_utils::{fire, listener, rebuild, TestFormData, TestMouseData};
+
+    fn app() -> Element {
+        let mut reported = use_signal(Vec::<usize>::new);
+        rsx!(
+            DataTable {
+                columns: vec!["Name".to_string()],
+                rows: (1..=20).map(|i| vec![format!("User {i}")]).collect(),
+                selectable: true,
+                selection_summary: true,
+                onselectionchange: move |rows| reported.set(rows),
+            }
+            span { "Reported {reported:?}" }
+        )
+    }
+
+    let (mut dom, listeners) = rebuild(app);
+    assert!(dioxus_ssr::render(&dom).contains("<span>0 of 20 selected</span>"));
+
+    // The first change listener is the select-all checkbox
+    for row in [1, 3, 4] {
+        fire(&mut dom, "change", listener(&listeners, "change", row), TestFormData::default());
+    }
+    let result = dioxus_ssr::render(&dom);
+    assert!(result.contains("<span>3 of 20 selected</span>"));
+    assert!(result.contains("Reported [0, 2, 3]"));
+    assert!(result.contains(r#"<tr class="bg-base-200" aria-selected="true"><td><input type="checkbox" class="checkbox checkbox-sm" aria-label="Select row 3" checked=true/>"#));
+
+    fire(&mut dom, "change", listener(&listeners, "change", 3), TestFormData::default());
+    assert!(dioxus_ssr::render(&dom).contains("<span>2 of 20 selected</span>"));
+
+    fire(&mut dom, "change", listener(&listeners, "change", 0), TestFormData::default());
+    assert!(dioxus_ssr::render(&dom).contains("<span>20 of 20 selected</span>"));
+
+    fire(&mut dom, "click", listener(&listeners, "click", 0), TestMouseData::default());
+    let result = dioxus_ssr::render(&dom);
+    assert!(result.contains("<span>0 of 20 selected</span>"));
+    assert!(result.contains("Reported []"));
+}
+
+#[test]
+fn test_data_table_selection_resets_when_rows_change() {
+    use crate::test_utils::{block_on, fire, listener, rebuild, TestFormData, TestMouseData};
+
+    fn app() -> Element {
+        let mut row_count = use_signal(|| 5);
+        let mut reported = use_signal(Vec::<usize>::new);
+        rsx!(
+            button { onclick: move |_| row_count.set(2), "Remove rows" }
+            DataTable {
+                columns: vec!["Name".to_string()],
+                rows: (1..=row_count()).map(|i| vec![format!("User {i}")]).collect(),
+                selectable: true,
+                selection_summary: true,
+                onselectionchange: move |rows| reported.set(rows),
+            }
+            span { "Reported {reported:?}" }
+        )
+    }
+
+    let (mut dom, listeners) = rebuild(app);
+    for row in [1, 4] {
+        fire(&mut dom, "change", listener(&listeners, "change", row), TestFormData::default());
+    }
+    assert!(dioxus_ssr::render(&dom).contains("<span>2 of 5 selected</span>"));
+
+    fire(&mut dom, "click", listener(&listeners, "click", 0), TestMouseData::default());
+    // Row 4 no longer exists, so only row 1 can still show as selected
+    assert!(dioxus_ssr::render(&dom).contains("<span>1 of 2 selected</span>"));
+
+    block_on(dom.wait_for_work());
+    dom.render_immediate_to_vec();
+    let result = dioxus_ssr::render(&dom);
+    assert!(result.contains("<span>0 of 2 selected</span>"));
+    assert!(result.contains("Reported []"));
+    assert!(!result.contains("checked=true"));
+}
@@ -22,6 +22,8 @@ use dioxus::prelude::*;
 
 /// Orientation options for Tabs component
 #[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
 pub enum TabsOrientation {
     #[default]
     /// Vertical orientation (default)
@@ -87,6 +89,8 @@ pub struct TabProps {
     value: String,
     /// Whether tab is disabled
     disabled: Option<bool>,
+    /// Trailing badge after the label, e.g. an unread count
+    badge: Option<String>,
 }
 
 #[component]
@@ -113,6 +117,9 @@ pub fn Tab(props: TabProps) -> Element {
             id: props.id,
             "data-value": "{props.value}",
             {props.children}
+            if let Some(badge) = props.badge {
+                span { class: "badge badge-sm ml-2", "{badge}" }
+            }
         }
     )
 }
@@ -194,6 +201,7 @@ fn test_tab_disabled() {
         class: None,
         value: "tab1".to_string(),
         disabled: Some(true),
+        badge: None,
     };
 
     let result = dioxus_ssr::render_element(Tab(props));
@@ -223,8 +231,27 @@ fn test_tab_with_id() {
         class: None,
         value: "tab1".to_string(),
         disabled: None,
+        badge: None,
     };
 
     let result = dioxus_ssr::render_element(Tab(props));
     assert!(result.contains(r#"id="test-tab""#));
 }
+
+#[test]
+fn test_tab_badge() {
+    let props = TabProps {
+        children: rsx!("Inbox"),
+        id: None,
+        class: None,
+        value: "inbox".to_string(),
+        disabled: None,
+        badge: Some("3".to_string()),
+    };
+
+    let result = dioxus_ssr::render_element(Tab(props));
+    assert_eq!(
+        result,
+        r#"<a class="tab" data-value="inbox">Inbox<span class="badge badge-sm ml-2">3</span></a>"#
+    );
+}
@@ -0,0 +1,443 @@
+//! Helpers for driving components through a `VirtualDom` in unit tests.
+//!
+//! The crate is built without a renderer, so there is no platform event
+//! converter installed. `TestEventConverter` fills that gap by handing back
+//! the test data types defined here, which lets tests dispatch events to
+//! listeners found in the rebuild mutations.
+
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::sync::Arc;
+use std::task::{Context, Poll, Wake, Waker};
+use std::thread::{self, Thread};
+use std::time::{Duration, Instant};
+
+use dioxus::dioxus_core::{ElementId, Event, Mutation};
+use dioxus::html::geometry::{ClientPoint, ElementPoint, PagePoint, ScreenPoint};
+use dioxus::html::input_data::{MouseButton, MouseButtonSet};
+use dioxus::html::*;
+use dioxus::prelude::*;
+
+/// Form data carried by a synthetic `input`/`change` event.
+#[derive(Clone, Debug, Default)]
+pub struct TestFormData {
+    pub value: String,
+}
+
+impl HasFileData for TestFormData {
+    fn files(&self) -> Vec<FileData> {
+        Vec::new()
+    }
+}
+
+impl HasFormData for TestFormData {
+    fn value(&self) -> String {
+        self.value.clone()
+    }
+
+    fn valid(&self) -> bool {
+        true
+    }
+
+    fn values(&self) -> Vec<(String, FormValue)> {
+        Vec::new()
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+/// Key data carried by a synthetic `keydown`/`keyup` event.
+#[derive(Clone, Debug)]
+pub struct TestKeyboardData {
+    pub key: Key,
+    pub modifiers: Modifiers,
+}
+
+impl TestKeyboardData {
+    pub fn new(key: Key) -> Self {
+        Self {
+            key,
+            modifiers: Modifiers::empty(),
+        }
+    }
+}
+
+impl ModifiersInteraction for TestKeyboardData {
+    fn modifiers(&self) -> Modifiers {
+        self.modifiers
+    }
+}
+
+impl HasKeyboardData for TestKeyboardData {
+    fn key(&self) -> Key {
+        self.key.clone()
+    }
+
+    fn code(&self) -> Code {
+        Code::Unidentified
+    }
+
+    fn location(&self) -> Location {
+        Location::Standard
+    }
+
+    fn is_auto_repeating(&self) -> bool {
+        false
+    }
+
+    fn is_composing(&self) -> bool {
+        false
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+/// A primary-button click at the origin, carried by synthetic mouse and
+/// pointer events.
+#[derive(Clone, Debug, Default)]
+pub struct TestMouseData {
+    pub modifiers: Modifiers,
+}
+
+impl InteractionLocation for TestMouseData {
+    fn client_coordinates(&self) -> ClientPoint {
+        ClientPoint::zero()
+    }
+
+    fn screen_coordinates(&self) -> ScreenPoint {
+        ScreenPoint::zero()
+    }
+
+    fn page_coordinates(&self) -> PagePoint {
+        PagePoint::zero()
+    }
+}
+
+impl InteractionElementOffset for TestMouseData {
+    fn element_coordinates(&self) -> ElementPoint {
+        ElementPoint::zero()
+    }
+}
+
+impl ModifiersInteraction for TestMouseData {
+    fn modifiers(&self) -> Modifiers {
+        self.modifiers
+    }
+}
+
+impl PointerInteraction for TestMouseData {
+    fn trigger_button(&self) -> Option<MouseButton> {
+        Some(MouseButton::Primary)
+    }
+
+    fn held_buttons(&self) -> MouseButtonSet {
+        MouseButtonSet::empty()
+    }
+}
+
+impl HasMouseData for TestMouseData {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl HasPointerData for TestMouseData {
+    fn pointer_id(&self) -> i32 {
+        1
+    }
+
+    fn width(&self) -> f64 {
+        1.0
+    }
+
+    fn height(&self) -> f64 {
+        1.0
+    }
+
+    fn pressure(&self) -> f32 {
+        0.5
+    }
+
+    fn tangential_pressure(&self) -> f32 {
+        0.0
+    }
+
+    fn tilt_x(&self) -> i32 {
+        0
+    }
+
+    fn tilt_y(&self) -> i32 {
+        0
+    }
+
+    fn twist(&self) -> i32 {
+        0
+    }
+
+    fn pointer_type(&self) -> String {
+        "mouse".to_string()
+    }
+
+    fn is_primary(&self) -> bool {
+        true
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+/// Scroll position carried by a synthetic `scroll` event.
+#[derive(Clone, Debug, Default)]
+pub struct TestScrollData {
+    pub scroll_top: f64,
+}
+
+impl HasScrollData for TestScrollData {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn scroll_top(&self) -> f64 {
+        self.scroll_top
+    }
+
+    fn scroll_left(&self) -> f64 {
+        0.0
+    }
+
+    fn scroll_width(&self) -> i32 {
+        0
+    }
+
+    fn scroll_height(&self) -> i32 {
+        0
+    }
+
+    fn client_width(&self) -> i32 {
+        0
+    }
+
+    fn client_height(&self) -> i32 {
+        0
+    }
+}
+
+/// Payload for events that carry no data, such as `cancel` or `toggle`.
+#[derive(Clone, Debug, Default)]
+pub struct TestEmptyData;
+
+impl HasCancelData for TestEmptyData {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl HasToggleData for TestEmptyData {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+/// Converter that only understands the test data types in this module.
+pub struct TestEventConverter;
+
+impl HtmlEventConverter for TestEventConverter {
+    fn convert_animation_data(&self, _: &PlatformEventData) -> AnimationData {
+        unimplemented!("animation events are not simulated")
+    }
+    fn convert_cancel_data(&self, _: &PlatformEventData) -> CancelData {
+        CancelData::new(TestEmptyData)
+    }
+    fn convert_clipboard_data(&self, _: &PlatformEventData) -> ClipboardData {
+        unimplemented!("clipboard events are not simulated")
+    }
+    fn convert_composition_data(&self, _: &PlatformEventData) -> CompositionData {
+        unimplemented!("composition events are not simulated")
+    }
+    fn convert_drag_data(&self, _: &PlatformEventData) -> DragData {
+        unimplemented!("drag events are not simulated")
+    }
+    fn convert_focus_data(&self, _: &PlatformEventData) -> FocusData {
+        unimplemented!("focus events are not simulated")
+    }
+    fn convert_form_data(&self, event: &PlatformEventData) -> FormData {
+        FormData::new(event.downcast::<TestFormData>().cloned().unwrap_or_default())
+    }
+    fn convert_image_data(&self, _: &PlatformEventData) -> ImageData {
+        unimplemented!("image events are not simulated")
+    }
+    fn convert_keyboard_data(&self, event: &PlatformEventData) -> KeyboardData {
+        let data = event.downcast::<TestKeyboardData>().expect("keyboard events need TestKeyboardData");
+        KeyboardData::new(data.clone())
+    }
+    fn convert_media_data(&self, _: &PlatformEventData) -> MediaData {
+        unimplemented!("media events are not simulated")
+    }
+    fn convert_mounted_data(&self, _: &PlatformEventData) -> MountedData {
+        unimplemented!("mounted events are not simulated")
+    }
+    fn convert_mouse_data(&self, event: &PlatformEventData) -> MouseData {
+        MouseData::new(event.downcast::<TestMouseData>().cloned().unwrap_or_default())
+    }
+    fn convert_pointer_data(&self, event: &PlatformEventData) -> PointerData {
+        PointerData::new(event.downcast::<TestMouseData>().cloned().unwrap_or_default())
+    }
+    fn convert_resize_data(&self, _: &PlatformEventData) -> ResizeData {
+        unimplemented!("resize events are not simulated")
+    }
+    fn convert_scroll_data(&self, event: &PlatformEventData) -> ScrollData {
+        ScrollData::new(event.downcast::<TestScrollData>().cloned().unwrap_or_default())
+    }
+    fn convert_selection_data(&self, _: &PlatformEventData) -> SelectionData {
+        unimplemented!("selection events are not simulated")
+    }
+    fn convert_toggle_data(&self, _: &PlatformEventData) -> ToggleData {
+        ToggleData::new(TestEmptyData)
+    }
+    fn convert_touch_data(&self, _: &PlatformEventData) -> TouchData {
+        unimplemented!("touch events are not simulated")
+    }
+    fn convert_transition_data(&self, _: &PlatformEventData) -> TransitionData {
+        unimplemented!("transition events are not simulated")
+    }
+    fn convert_visible_data(&self, _: &PlatformEventData) -> VisibleData {
+        unimplemented!("visible events are not simulated")
+    }
+    fn convert_wheel_data(&self, _: &PlatformEventData) -> WheelData {
+        unimplemented!("wheel events are not simulated")
+    }
+}
+
+/// Builds the dom for `app` and returns it with every listener that was
+/// attached during the first render, in creation order.
+pub fn rebuild(app: fn() -> Element) -> (VirtualDom, Vec<(String, ElementId)>) {
+    set_event_converter(Box::new(TestEventConverter));
+
+    let mut dom = VirtualDom::new(app);
+    let listeners = dom
+        .rebuild_to_vec()
+        .edits
+        .into_iter()
+        .filter_map(|edit| match edit {
+            Mutation::NewEventListener { name, id } => Some((name, id)),
+            _ => None,
+        })
+        .collect();
+
+    (dom, listeners)
+}
+
+/// Finds the `n`th listener for `event` returned by [`rebuild`].
+pub fn listener(listeners: &[(String, ElementId)], event: &str, n: usize) -> ElementId {
+    listeners
+        .iter()
+        .filter(|(name, _)| name == event)
+        .nth(n)
+        .map(|(_, id)| *id)
+        .unwrap_or_else(|| panic!("no listener #{n} for {event}"))
+}
+
+/// Dispatches `data` as `event` on `id` and applies the resulting re-render.
+pub fn fire(dom: &mut VirtualDom, event: &str, id: ElementId, data: impl std::any::Any) {
+    let data = PlatformEventData::new(Box::new(data));
+    let data: Rc<dyn std::any::Any> = Rc::new(data);
+    dom.runtime().handle_event(event, Event::new(data, true), id);
+    dom.render_immediate_to_vec();
+}
+
+struct ThreadWaker(Thread);
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+/// Drives `future` to completion on the current thread, for tests that wait
+/// on timers spawned by a component.
+pub fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = std::pin::pin!(future);
+    let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+    let mut cx = Context::from_waker(&waker);
+    loop {
+        match future.as_mut().poll(&mut cx) {
+            Poll::Ready(output) => return output,
+            Poll::Pending => thread::park(),
+        }
+    }
+}
+
+/// Lets the tasks of `dom` run for `ms` milliseconds of real time, applying
+/// each re-render, for tests of timers that keep going.
+pub fn run_for(dom: &mut VirtualDom, ms: u64) {
+    let deadline = Instant::now() + Duration::from_millis(ms);
+    loop {
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        if remaining.is_zero() {
+            return;
+        }
+        {
+            let mut timeout = futures_timer::Delay::new(remaining);
+            let mut work = std::pin::pin!(dom.wait_for_work());
+            block_on(std::future::poll_fn(|cx| {
+                if Pin::new(&mut timeout).poll(cx).is_ready() {
+                    return Poll::Ready(());
+                }
+                work.as_mut().poll(cx)
+            }));
+        }
+        dom.render_immediate_to_vec();
+    }
+}
+
+// Browser tests for the `web` feature run with the web renderer, e.g.
+// `wasm-pack test --headless --firefox -- --features web`
+#[cfg(all(feature = "web", target_arch = "wasm32"))]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+/// Renders `app` with the web renderer into a fresh element at the end of
+/// `document.body` and waits for the first render.
+#[cfg(all(feature = "web", target_arch = "wasm32"))]
+pub async fn mount(app: fn() -> Element) -> web_sys::Element {
+    let document = web_sys::window().unwrap().document().unwrap();
+    let root = document.create_element("div").unwrap();
+    document.body().unwrap().append_child(&root).unwrap();
+
+    let config = dioxus_web::Config::new().rootelement(root.clone());
+    dioxus_web::launch::launch_virtual_dom(VirtualDom::new(app), config);
+    settle().await;
+    root
+}
+
+/// Gives the web renderer a moment to apply pending updates and fire
+/// `onmounted`.
+#[cfg(all(feature = "web", target_arch = "wasm32"))]
+pub async fn settle() {
+    futures_timer::Delay::new(std::time::Duration::from_millis(50)).await;
+}
+
+/// Fires a bubbling `input` event on `target`, as typing would.
+#[cfg(all(feature = "web", target_arch = "wasm32"))]
+pub fn dispatch_input(target: &web_sys::EventTarget) {
+    let init = web_sys::EventInit::new();
+    init.set_bubbles(true);
+    let event = web_sys::Event::new_with_event_init_dict("input", &init).unwrap();
+    target.dispatch_event(&event).unwrap();
+}
+
+/// Fires a bubbling `keydown` for `key` on `target`, with Ctrl held when `ctrl` is set.
+#[cfg(all(feature = "web", target_arch = "wasm32"))]
+pub fn dispatch_key(target: &web_sys::EventTarget, key: &str, ctrl: bool) {
+    let init = web_sys::KeyboardEventInit::new();
+    init.set_bubbles(true);
+    init.set_key(key);
+    init.set_ctrl_key(ctrl);
+    let event = web_sys::KeyboardEvent::new_with_keyboard_event_init_dict("keydown", &init).unwrap();
+    target.dispatch_event(&event).unwrap();
+}
@@ -4,6 +4,8 @@ use std::fmt::Display;
 use dioxus::prelude::*;
 
 #[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
 pub enum TextAreaSize {
     #[default]
     Default,
@@ -41,17 +43,57 @@ pub struct Props {
     pub required: Option<bool>,
     pub disabled: Option<bool>,
     pub readonly: Option<bool>,
+    /// Maximum number of characters, also shown in the counter
+    pub max_length: Option<usize>,
+    /// Show a character counter such as `120/280` below the textarea
+    pub show_counter: Option<bool>,
+    /// Grow the textarea to fit its content while typing (needs the `web` feature)
+    pub auto_grow: Option<bool>,
 }
 
 #[component]
 pub fn TextArea(props: Props) -> Element {
     let input_size = props.area_size.unwrap_or_default();
-    let class = format!("{} {}", props.class.unwrap_or_default(), input_size);
+    // Growing needs the browser; without it the textarea keeps its scrollbar
+    let auto_grow = cfg!(feature = "web") && props.auto_grow.unwrap_or(false);
+    let grow_class = if auto_grow { " resize-none overflow-hidden" } else { "" };
+    let class = format!("{} {}{}", props.class.unwrap_or_default(), input_size, grow_class);
     let value = props.value.unwrap_or_default();
     let placeholder = props.placeholder.unwrap_or_default();
     let label_class = props.label_class.unwrap_or_default();
 
     let disabled = props.disabled.unwrap_or(false);
+    let show_counter = props.show_counter.unwrap_or(false);
+
+    // Characters typed so far, kept up to date while the counter is shown
+    let mut length = use_signal(|| value.chars().count());
+    #[cfg(feature = "web")]
+    let grower = use_hook(|| std::rc::Rc::new(dom::AutoGrow::default()));
+    let mut listeners = vec![];
+    if show_counter || auto_grow {
+        #[cfg(feature = "web")]
+        let grower = grower.clone();
+        listeners.push(dioxus_elements::events::oninput(move |event: FormEvent| {
+            if show_counter {
+                length.set(event.value().chars().count());
+            }
+            #[cfg(feature = "web")]
+            if auto_grow {
+                grower.grow();
+            }
+        }));
+    }
+    // Sizes prefilled content as soon as the textarea is in the page
+    #[cfg(feature = "web")]
+    if auto_grow {
+        listeners.push(dioxus_elements::events::onmounted(move |event: MountedEvent| {
+            grower.mount(&event)
+        }));
+    }
+    let counter = match props.max_length {
+        Some(max) => format!("{}/{}", length(), max),
+        None => length().to_string(),
+    };
 
     rsx!(
         match props.label {
@@ -70,8 +112,15 @@ pub fn TextArea(props: Props) -> Element {
             disabled,
             readonly: props.readonly,
             rows: props.rows,
+            maxlength: props.max_length.map(|max| max.to_string()),
+            ..listeners,
             {props.children}
         }
+        if show_counter {
+            label {
+                span { class: "label-text-alt", "{counter}" }
+            }
+        }
         match props.help_text {
             Some(l) => rsx! {
                 span { class: "note mb-3", "{l}" }
@@ -81,6 +130,53 @@ pub fn TextArea(props: Props) -> Element {
     )
 }
 
+#[cfg(feature = "web")]
+mod dom {
+    use std::cell::RefCell;
+
+    use dioxus::prelude::*;
+    use wasm_bindgen::JsCast;
+    use web_sys::HtmlElement;
+
+    /// Height that shows `scroll_height` pixels of content without a scrollbar.
+    pub(super) fn fit_height(scroll_height: i32) -> String {
+        format!("{}px", scroll_height.max(0))
+    }
+
+    /// The component's own textarea, once mounted, resized to fit its content.
+    #[derive(Default)]
+    pub(super) struct AutoGrow(RefCell<Option<HtmlElement>>);
+
+    impl AutoGrow {
+        pub(super) fn mount(&self, event: &MountedEvent) {
+            let element = event
+                .data()
+                .downcast::<web_sys::Element>()
+                .and_then(|element| element.clone().dyn_into::<HtmlElement>().ok());
+            self.0.replace(element);
+            self.grow();
+        }
+
+        pub(super) fn grow(&self) {
+            let Some(textarea) = &*self.0.borrow() else {
+                return;
+            };
+            // Collapse first so the scroll height can shrink when text is deleted
+            let style = textarea.style();
+            let _ = style.set_property("height", "auto");
+            let _ = style.set_property("height", &fit_height(textarea.scroll_height()));
+        }
+    }
+
+    #[test]
+    fn test_fit_height_follows_rows() {
+        let line_height = 24;
+        assert_eq!(fit_height(line_height), "24px");
+        assert_eq!(fit_height(line_height * 3), "72px");
+        assert_eq!(fit_height(-1), "0px");
+    }
+}
+
 #[test]
 fn test_text_area() {
     let props = Props {
@@ -98,10 +194,85 @@ fn test_text_area() {
         required: Some(true),
         disabled: Some(false),
         readonly: Some(false),
+        max_length: None,
+        show_counter: None,
+        auto_grow: None,
     };
 
     let expected = r#"<label class="label_class">label</label><textarea id="id" class="textarea textarea-bordered textarea-sm class textarea-sm" value="value" name="name" placeholder="placeholder" required=true rows="rows">Hello</textarea><span class="note mb-3">help_text</span>"#;
-    let result = dioxus_ssr::render_element(TextArea(props));
+    // TextArea tracks its length in a hook, so it has to render inside a runtime
+    let mut dom = VirtualDom::new_with_props(TextArea, props);
+    dom.rebuild_in_place();
+    let result = dioxus_ssr::render(&dom);
     // println!("{}", result);
     assert_eq!(expected, result);
 }
+
+#[test]
+fn test_text_area_counter() {
+    use crate::test_utils::{fire, listener, rebuild, TestFormData};
+
+    fn app() -> Element {
+        rsx!(
+            TextArea { name: "comment", max_length: 280, show_counter: true }
+        )
+    }
+
+    let (mut dom, listeners) = rebuild(app);
+    let result = dioxus_ssr::render(&dom);
+    assert!(result.contains(r#"maxlength="280""#));
+    assert!(result.contains(r#"<span class="label-text-alt">0/280</span>"#));
+
+    fire(
+        &mut dom,
+        "input",
+        listener(&listeners, "input", 0),
+        TestFormData { value: "Looks great!".to_string() },
+    );
+    assert!(dioxus_ssr::render(&dom).contains(r#"<span class="label-text-alt">12/280</span>"#));
+}
+
+// Without the browser nothing grows the textarea, so it keeps its scrollbar
+#[cfg(not(feature = "web"))]
+#[test]
+fn test_text_area_auto_grow_needs_web() {
+    fn app() -> Element {
+        rsx!(
+            TextArea { name: "notes", auto_grow: true }
+        )
+    }
+
+    let (dom, _) = crate::test_utils::rebuild(app);
+    let result = dioxus_ssr::render(&dom);
+    assert!(!result.contains("resize-none"));
+    assert!(!result.contains("overflow-hidden"));
+}
+
+#[cfg(all(feature = "web", target_arch = "wasm32"))]
+#[wasm_bindgen_test::wasm_bindgen_test]
+async fn test_text_area_auto_grow_updates_height() {
+    use crate::test_utils::{dispatch_input, mount, settle};
+    use wasm_bindgen::JsCast;
+
+    fn app() -> Element {
+        rsx!(
+            TextArea { name: "notes", rows: "1", auto_grow: true, value: "one\ntwo" }
+        )
+    }
+
+    let root = mount(app).await;
+    let textarea: web_sys::HtmlTextAreaElement =
+        root.query_selector("textarea").unwrap().unwrap().dyn_into().unwrap();
+    let height = || textarea.style().get_property_value("height").unwrap();
+
+    // Prefilled content is sized on mount
+    let mounted = height();
+    assert_eq!(mounted, format!("{}px", textarea.scroll_height()));
+
+    textarea.set_value("one\ntwo\nthree\nfour\nfive\nsix");
+    dispatch_input(&textarea);
+    settle().await;
+    let grown = height();
+    assert_ne!(grown, mounted);
+    assert_eq!(grown, format!("{}px", textarea.scroll_height()));
+}
@@ -2,6 +2,8 @@
 use std::fmt::Display;
 use dioxus::prelude::*;
 
+use crate::toggle::Toggle;
+
 /// A Theme component for applying daisyUI themes.
 ///
 /// # Examples
@@ -21,6 +23,8 @@ use dioxus::prelude::*;
 
 /// Theme names supported by daisyUI
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
 pub enum ThemeName {
     /// Light theme
     Light,
@@ -118,6 +122,97 @@ impl Display for ThemeName {
     }
 }
 
+impl ThemeName {
+    /// Every theme, in daisyUI's order, e.g. for building a theme picker
+    pub fn all() -> &'static [ThemeName] {
+        &[
+            ThemeName::Light,
+            ThemeName::Dark,
+            ThemeName::Cupcake,
+            ThemeName::Bumblebee,
+            ThemeName::Emerald,
+            ThemeName::Corporate,
+            ThemeName::Synthwave,
+            ThemeName::Retro,
+            ThemeName::Cyberpunk,
+            ThemeName::Valentine,
+            ThemeName::Halloween,
+            ThemeName::Garden,
+            ThemeName::Forest,
+            ThemeName::Aqua,
+            ThemeName::Lofi,
+            ThemeName::Pastel,
+            ThemeName::Fantasy,
+            ThemeName::Wireframe,
+            ThemeName::Black,
+            ThemeName::Luxury,
+            ThemeName::Dracula,
+            ThemeName::Cmyk,
+            ThemeName::Autumn,
+            ThemeName::Business,
+            ThemeName::Acid,
+            ThemeName::Lemonade,
+            ThemeName::Night,
+            ThemeName::Coffee,
+            ThemeName::Winter,
+        ]
+    }
+}
+
+/// Error returned when parsing a string that is not a daisyUI theme name
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseThemeNameError {
+    name: String,
+}
+
+impl Display for ParseThemeNameError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "unknown theme name: {:?}", self.name)
+    }
+}
+
+impl std::error::Error for ParseThemeNameError {}
+
+impl std::str::FromStr for ThemeName {
+    type Err = ParseThemeNameError;
+
+    /// Parses the names produced by `Display`, such as `"dracula"`, ignoring case
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let name = s.trim();
+        ThemeName::all()
+            .iter()
+            .copied()
+            .find(|theme| theme.to_string().eq_ignore_ascii_case(name))
+            .ok_or_else(|| ParseThemeNameError {
+                name: s.to_string(),
+            })
+    }
+}
+
+impl TryFrom<&str> for ThemeName {
+    type Error = ParseThemeNameError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+/// Element that carries the theme. `html` and `body` belong to the host page
+/// rather than the component tree, so they can't be chosen here; to theme the
+/// whole document set `data-theme` on `<html>` in the page template.
+#[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum ThemeTag {
+    #[default]
+    /// A plain `div`
+    Div,
+    /// A `section`
+    Section,
+    /// The page's `main` element
+    Main,
+}
+
 #[derive(Props, Clone, PartialEq)]
 pub struct ThemeProps {
     /// The content to display with theme applied
@@ -128,28 +223,210 @@ pub struct ThemeProps {
     id: Option<String>,
     /// Additional CSS classes to apply
     class: Option<String>,
+    /// Element to render (default: div)
+    tag: Option<ThemeTag>,
+    /// Make the wrapper `display: contents`, so it doesn't take part in the
+    /// layout; `class` and `id` are still applied to it
+    inline: Option<bool>,
 }
 
 #[component]
 pub fn Theme(props: ThemeProps) -> Element {
     let class = props.class.unwrap_or_default();
-    let theme_class = format!("data-theme={}", props.name.to_string());
+    let tag = props.tag.unwrap_or_default();
 
     // Build CSS classes
     let mut classes = vec![];
+
+    if props.inline.unwrap_or(false) {
+        classes.push("contents".to_string());
+    }
     
     if !class.is_empty() {
         classes.push(class);
     }
 
+    let class_string = classes.join(" ");
+    let id = props.id;
+
+    match tag {
+        ThemeTag::Div => rsx!(
+            div {
+                class: "{class_string}",
+                id,
+                "data-theme": "{props.name}",
+                {props.children}
+            }
+        ),
+        ThemeTag::Section => rsx!(
+            section {
+                class: "{class_string}",
+                id,
+                "data-theme": "{props.name}",
+                {props.children}
+            }
+        ),
+        ThemeTag::Main => rsx!(
+            main {
+                class: "{class_string}",
+                id,
+                "data-theme": "{props.name}",
+                {props.children}
+            }
+        ),
+    }
+}
+
+/// Returns the shared theme signal, providing it (starting at light) if no
+/// ancestor has done so yet. Call it near the root so every consumer shares
+/// the same signal, e.g. `Theme { name: theme(), ... }`.
+pub fn use_theme() -> Signal<ThemeName> {
+    use_theme_or(ThemeName::Light)
+}
+
+/// Like `use_theme`, starting at `initial` when the signal is provided here
+fn use_theme_or(initial: ThemeName) -> Signal<ThemeName> {
+    use_hook(|| {
+        try_consume_context::<Signal<ThemeName>>()
+            .unwrap_or_else(|| provide_context(Signal::new(initial)))
+    })
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct ThemeToggleProps {
+    /// Theme used when the toggle is off (defaults to light)
+    light: Option<ThemeName>,
+    /// Theme used when the toggle is on (defaults to dark)
+    dark: Option<ThemeName>,
+    /// Optional ID for the checkbox
+    id: Option<String>,
+    /// Additional CSS classes to apply
+    class: Option<String>,
+}
+
+/// A light/dark switch rendered as a `Toggle` with moon and sun icons,
+/// flipping the theme returned by `use_theme`. When nothing above provides
+/// the theme yet, it starts at `light`. With the `web` feature a flip also
+/// sets `data-theme` on `<html>`, so the page follows without a `Theme`.
+#[component]
+pub fn ThemeToggle(props: ThemeToggleProps) -> Element {
+    let light = props.light.unwrap_or(ThemeName::Light);
+    let dark = props.dark.unwrap_or(ThemeName::Dark);
+    let mut theme = use_theme_or(light);
+
+    rsx!(
+        Toggle {
+            id: props.id,
+            class: props.class,
+            checked: theme() == dark,
+            aria_label: "Toggle dark mode",
+            onchange: move |_| {
+                let next = if theme() == dark { light } else { dark };
+                theme.set(next);
+                #[cfg(feature = "web")]
+                dom::set_document_theme(next);
+            },
+            icon_on: rsx!(
+                svg {
+                    "aria-label": "moon",
+                    xmlns: "http://www.w3.org/2000/svg",
+                    view_box: "0 0 24 24",
+                    g {
+                        stroke_linejoin: "round",
+                        stroke_linecap: "round",
+                        stroke_width: "2",
+                        fill: "none",
+                        stroke: "currentColor",
+                        path { d: "M12 3a6 6 0 0 0 9 9 9 9 0 1 1-9-9Z" }
+                    }
+                }
+            ),
+            icon_off: rsx!(
+                svg {
+                    "aria-label": "sun",
+                    xmlns: "http://www.w3.org/2000/svg",
+                    view_box: "0 0 24 24",
+                    g {
+                        stroke_linejoin: "round",
+                        stroke_linecap: "round",
+                        stroke_width: "2",
+                        fill: "none",
+                        stroke: "currentColor",
+                        circle { cx: "12", cy: "12", r: "4" }
+                        path { d: "M12 2v2M12 20v2M4.93 4.93l1.41 1.41M17.66 17.66l1.41 1.41M2 12h2M20 12h2M6.34 17.66l-1.41 1.41M19.07 4.93l-1.41 1.41" }
+                    }
+                }
+            ),
+        }
+    )
+}
+
+#[cfg(feature = "web")]
+mod dom {
+    use super::ThemeName;
+
+    /// Sets `data-theme` on the document's `<html>` element.
+    pub(super) fn set_document_theme(theme: ThemeName) {
+        let root = web_sys::window()
+            .and_then(|window| window.document())
+            .and_then(|document| document.document_element());
+        if let Some(root) = root {
+            let _ = root.set_attribute("data-theme", &theme.to_string());
+        }
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct ThemeControllerProps {
+    /// Theme applied while the input is checked, e.g. `dark`
+    value: String,
+    /// Whether the input starts checked
+    checked: Option<bool>,
+    /// Render a radio button instead of a checkbox, for picking one of several themes
+    radio: Option<bool>,
+    /// Group name, shared by the radio buttons of one picker
+    name: Option<String>,
+    /// Accessible label for the input
+    label: Option<String>,
+    /// Optional ID for the input
+    id: Option<String>,
+    /// Additional CSS classes to apply, e.g. `toggle` or `btn`
+    class: Option<String>,
+}
+
+/// daisyUI's CSS-only theme switch: while checked, the input's `value`
+/// becomes the page theme.
+///
+/// ```text
+/// ThemeController { value: "dark", class: "toggle" }
+///
+/// for theme in ThemeName::all() {
+///     ThemeController { value: theme.to_string(), radio: true, name: "theme" }
+/// }
+/// ```
+#[component]
+pub fn ThemeController(props: ThemeControllerProps) -> Element {
+    let class = props.class.unwrap_or_default();
+    let input_type = if props.radio.unwrap_or(false) { "radio" } else { "checkbox" };
+
+    // Build CSS classes
+    let mut classes = vec!["theme-controller".to_string()];
+
+    if !class.is_empty() {
+        classes.push(class);
+    }
+
     let class_string = classes.join(" ");
 
     rsx!(
-        div {
+        input {
             class: "{class_string}",
             id: props.id,
-            {theme_class},
-            {props.children}
+            r#type: "{input_type}",
+            name: props.name,
+            value: "{props.value}",
+            checked: props.checked.filter(|&x| x),
+            aria_label: props.label,
         }
     )
 }
@@ -161,10 +438,12 @@ fn test_theme_light() {
         name: ThemeName::Light,
         id: None,
         class: None,
+        tag: None,
+        inline: None,
     };
 
     let result = dioxus_ssr::render_element(Theme(props));
-    assert!(result.contains(r#"data-theme=light"#));
+    assert!(result.contains(r#"data-theme="light""#));
 }
 
 #[test]
@@ -174,10 +453,12 @@ fn test_theme_dark() {
         name: ThemeName::Dark,
         id: None,
         class: None,
+        tag: None,
+        inline: None,
     };
 
     let result = dioxus_ssr::render_element(Theme(props));
-    assert!(result.contains(r#"data-theme=dark"#));
+    assert!(result.contains(r#"data-theme="dark""#));
 }
 
 #[test]
@@ -187,10 +468,12 @@ fn test_theme_custom_class() {
         name: ThemeName::Emerald,
         id: None,
         class: Some("custom-class".to_string()),
+        tag: None,
+        inline: None,
     };
 
     let result = dioxus_ssr::render_element(Theme(props));
-    assert!(result.contains(r#"data-theme=emerald"#));
+    assert!(result.contains(r#"data-theme="emerald""#));
     assert!(result.contains(r#"class="custom-class""#));
 }
 
@@ -201,11 +484,13 @@ fn test_theme_with_id() {
         name: ThemeName::Dracula,
         id: Some("test-theme".to_string()),
         class: None,
+        tag: None,
+        inline: None,
     };
 
     let result = dioxus_ssr::render_element(Theme(props));
     assert!(result.contains(r#"id="test-theme""#));
-    assert!(result.contains(r#"data-theme=dracula"#));
+    assert!(result.contains(r#"data-theme="dracula""#));
 }
 
 #[test]
@@ -229,9 +514,153 @@ fn test_theme_various_themes() {
             name: theme,
             id: None,
             class: None,
+            tag: None,
+            inline: None,
         };
 
         let result = dioxus_ssr::render_element(Theme(props));
-        assert!(result.contains(&format!("data-theme={}", theme.to_string())));
+        assert!(result.contains(&format!(r#"data-theme="{}""#, theme)));
+    }
+}
+
+// With `web` on, a flip also writes to the browser's document
+#[cfg(not(feature = "web"))]
+#[test]
+fn test_theme_toggle_flips_data_theme() {
+    use crate::test_utils::{fire, listener, rebuild, TestFormData};
+
+    fn app() -> Element {
+        let theme = use_context_provider(|| Signal::new(ThemeName::Cupcake));
+        rsx!(
+            Theme {
+                name: theme(),
+                ThemeToggle { light: ThemeName::Cupcake, dark: ThemeName::Dracula }
+            }
+        )
     }
+
+    let (mut dom, listeners) = rebuild(app);
+    let toggle = listener(&listeners, "change", 0);
+    let result = dioxus_ssr::render(&dom);
+    assert!(result.starts_with(r#"<div class="" data-theme="cupcake"><label class="toggle">"#));
+    assert!(result.contains(r#"<input type="checkbox" aria-label="Toggle dark mode"/>"#));
+
+    // Light to dark
+    fire(&mut dom, "change", toggle, TestFormData::default());
+    let result = dioxus_ssr::render(&dom);
+    assert!(result.contains(r#"data-theme="dracula""#));
+    assert!(result.contains(r#"<input type="checkbox" checked=true aria-label="Toggle dark mode"/>"#));
+
+    // And back
+    fire(&mut dom, "change", toggle, TestFormData::default());
+    let result = dioxus_ssr::render(&dom);
+    assert!(result.contains(r#"data-theme="cupcake""#));
+    assert!(result.contains(r#"<input type="checkbox" aria-label="Toggle dark mode"/>"#));
+}
+
+#[cfg(all(feature = "web", target_arch = "wasm32"))]
+#[wasm_bindgen_test::wasm_bindgen_test]
+async fn test_theme_toggle_sets_document_theme() {
+    use wasm_bindgen::JsCast;
+
+    use crate::test_utils::{mount, settle};
+
+    fn app() -> Element {
+        rsx!(ThemeToggle { light: ThemeName::Cupcake, dark: ThemeName::Dracula })
+    }
+
+    let root = mount(app).await;
+    let input = root.query_selector("input").unwrap().unwrap();
+    input.unchecked_ref::<web_sys::HtmlElement>().click();
+    settle().await;
+
+    let html = web_sys::window().unwrap().document().unwrap().document_element().unwrap();
+    assert_eq!(html.get_attribute("data-theme").as_deref(), Some("dracula"));
+}
+
+#[test]
+fn test_theme_name_round_trip() {
+    assert_eq!(ThemeName::all().len(), 29);
+    for &theme in ThemeName::all() {
+        assert_eq!(theme.to_string().parse::<ThemeName>(), Ok(theme));
+        assert_eq!(ThemeName::try_from(theme.to_string().as_str()), Ok(theme));
+    }
+}
+
+#[test]
+fn test_theme_name_parse_errors() {
+    assert_eq!(" Dracula ".parse::<ThemeName>(), Ok(ThemeName::Dracula));
+
+    let error = "solarized".parse::<ThemeName>().unwrap_err();
+    assert_eq!(error.to_string(), "unknown theme name: \"solarized\"");
+}
+
+#[test]
+fn test_theme_controller_checkbox() {
+    let props = ThemeControllerProps {
+        value: "dark".to_string(),
+        checked: Some(true),
+        radio: None,
+        name: None,
+        label: None,
+        id: None,
+        class: Some("toggle".to_string()),
+    };
+
+    let result = dioxus_ssr::render_element(ThemeController(props));
+    assert!(result.contains(r#"class="theme-controller toggle""#));
+    assert!(result.contains(r#"type="checkbox""#));
+    assert!(result.contains(r#"value="dark""#));
+    assert!(result.contains("checked"));
+}
+
+#[test]
+fn test_theme_controller_radio() {
+    let props = ThemeControllerProps {
+        value: ThemeName::Retro.to_string(),
+        checked: None,
+        radio: Some(true),
+        name: Some("theme".to_string()),
+        label: Some("Retro".to_string()),
+        id: None,
+        class: None,
+    };
+
+    let result = dioxus_ssr::render_element(ThemeController(props));
+    assert!(result.contains(
+        r#"<input class="theme-controller" type="radio" name="theme" value="retro" aria-label="Retro"/>"#
+    ));
+}
+
+#[test]
+fn test_theme_section_tag() {
+    let props = ThemeProps {
+        children: rsx!(div { "Content" }),
+        name: ThemeName::Forest,
+        id: Some("themed".to_string()),
+        class: Some("p-4".to_string()),
+        tag: Some(ThemeTag::Section),
+        inline: None,
+    };
+
+    let result = dioxus_ssr::render_element(Theme(props));
+    assert!(result.starts_with(r#"<section class="p-4" id="themed" data-theme="forest"><div>Content</div></section>"#));
+}
+
+#[test]
+fn test_theme_main_tag_inline() {
+    let props = ThemeProps {
+        children: rsx!(div { "Content" }),
+        name: ThemeName::Night,
+        id: Some("themed".to_string()),
+        class: Some("p-4".to_string()),
+        tag: Some(ThemeTag::Main),
+        inline: Some(true),
+    };
+
+    let result = dioxus_ssr::render_element(Theme(props));
+    assert_eq!(
+        result,
+        r#"<main class="contents p-4" id="themed" data-theme="night"><div>Content</div></main>"#
+    );
 }
@@ -2,6 +2,10 @@
 use std::fmt::Display;
 use dioxus::prelude::*;
 
+use crate::motion::reduced_motion;
+use crate::portal::Portal;
+use crate::progress::{Progress, ProgressColorScheme};
+
 /// A Toast component for displaying notifications.
 ///
 /// # Examples
@@ -16,9 +20,22 @@ use dioxus::prelude::*;
 ///     children: rsx!("Operation completed successfully!")
 /// }
 /// ```
+///
+/// Auto-dismissing with a countdown bar:
+///
+/// ```text
+/// Toast {
+///     r#type: ToastType::Info,
+///     duration_ms: 5000,
+///     ondismiss: move |_| toasts.write().pop(),
+///     children: rsx!("Saved")
+/// }
+/// ```
 
 /// Toast type variants
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
 pub enum ToastType {
     /// Success toast
     Success,
@@ -51,6 +68,11 @@ pub struct ToastProps {
     id: Option<String>,
     /// Additional CSS classes to apply to toast
     class: Option<String>,
+    /// Time in milliseconds before the toast dismisses itself, shown as a shrinking
+    /// progress bar. Changing it starts the countdown over
+    duration_ms: Option<u64>,
+    /// Called once `duration_ms` has elapsed
+    ondismiss: Option<EventHandler<()>>,
 }
 
 #[component]
@@ -72,6 +94,119 @@ pub fn Toast(props: ToastProps) -> Element {
             class: "{class_string}",
             id: props.id,
             {props.children}
+            if let Some(duration_ms) = props.duration_ms {
+                ToastCountdown { r#type: props.r#type, duration_ms, ondismiss: props.ondismiss }
+            }
+        }
+    )
+}
+
+/// Interval between countdown bar updates in milliseconds
+const COUNTDOWN_TICK_MS: u64 = 100;
+
+#[derive(Props, Clone, PartialEq)]
+struct ToastCountdownProps {
+    r#type: ToastType,
+    duration_ms: u64,
+    ondismiss: Option<EventHandler<()>>,
+}
+
+/// Progress bar that empties over `duration_ms`, then fires `ondismiss`. The
+/// bar is hidden under reduced motion, and restarts when `duration_ms` changes.
+#[component]
+fn ToastCountdown(props: ToastCountdownProps) -> Element {
+    let duration_ms = props.duration_ms;
+    let ondismiss = props.ondismiss;
+    let color_scheme = match props.r#type {
+        ToastType::Success => ProgressColorScheme::Success,
+        ToastType::Info => ProgressColorScheme::Info,
+        ToastType::Warning => ProgressColorScheme::Warning,
+        ToastType::Error => ProgressColorScheme::Error,
+    };
+
+    // A new duration starts the countdown over. Peeking keeps the ticks
+    // from restarting it as well
+    let mut remaining_ms = use_signal(|| duration_ms);
+    use_resource(use_reactive(&duration_ms, move |duration_ms| async move {
+        remaining_ms.set(duration_ms);
+        while *remaining_ms.peek() > 0 {
+            futures_timer::Delay::new(std::time::Duration::from_millis(COUNTDOWN_TICK_MS)).await;
+            let next = remaining_ms.peek().saturating_sub(COUNTDOWN_TICK_MS);
+            remaining_ms.set(next);
+        }
+        if let Some(ondismiss) = ondismiss {
+            ondismiss.call(());
+        }
+    }));
+
+    // The toast still dismisses itself, just without the shrinking bar
+    if reduced_motion() {
+        return rsx!();
+    }
+
+    rsx!(
+        Progress {
+            class: "h-1 w-full",
+            color_scheme,
+            value: remaining_ms() as f64,
+            max: duration_ms as f64,
+        }
+    )
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct ToastContainerProps {
+    /// Toasts to stack, oldest first
+    toasts: Vec<Element>,
+    /// Optional ID for the container element
+    id: Option<String>,
+    /// Additional CSS classes to apply to the container, e.g. `toast-end`
+    class: Option<String>,
+    /// Show only the newest N toasts, with a "+k more" indicator for the rest
+    max_visible: Option<usize>,
+}
+
+/// A stack of toasts pinned to the edge of the screen, mounted at the body
+/// through a `Portal`.
+///
+/// ```text
+/// ToastContainer {
+///     class: "toast-end",
+///     max_visible: 3,
+///     toasts: vec![
+///         rsx!(Toast { r#type: ToastType::Info, "Uploaded" }),
+///         rsx!(Toast { r#type: ToastType::Success, "Saved" }),
+///     ]
+/// }
+/// ```
+#[component]
+pub fn ToastContainer(props: ToastContainerProps) -> Element {
+    let class = props.class.unwrap_or_default();
+    let total = props.toasts.len();
+    let visible = props.max_visible.unwrap_or(total).min(total);
+    let hidden = total - visible;
+
+    // Build CSS classes
+    let mut classes = vec!["toast".to_string()];
+
+    if !class.is_empty() {
+        classes.push(class);
+    }
+
+    let class_string = classes.join(" ");
+
+    rsx!(
+        Portal {
+            div {
+                class: "{class_string}",
+                id: props.id,
+                if hidden > 0 {
+                    div { class: "badge badge-neutral self-center", "+{hidden} more" }
+                }
+                for toast in props.toasts.into_iter().skip(hidden) {
+                    {toast}
+                }
+            }
         }
     )
 }
@@ -83,6 +218,8 @@ fn test_toast_success() {
         r#type: ToastType::Success,
         id: None,
         class: None,
+        duration_ms: None,
+        ondismiss: None,
     };
 
     let result = dioxus_ssr::render_element(Toast(props));
@@ -96,6 +233,8 @@ fn test_toast_info() {
         r#type: ToastType::Info,
         id: None,
         class: None,
+        duration_ms: None,
+        ondismiss: None,
     };
 
     let result = dioxus_ssr::render_element(Toast(props));
@@ -109,6 +248,8 @@ fn test_toast_warning() {
         r#type: ToastType::Warning,
         id: None,
         class: None,
+        duration_ms: None,
+        ondismiss: None,
     };
 
     let result = dioxus_ssr::render_element(Toast(props));
@@ -122,6 +263,8 @@ fn test_toast_error() {
         r#type: ToastType::Error,
         id: None,
         class: None,
+        duration_ms: None,
+        ondismiss: None,
     };
 
     let result = dioxus_ssr::render_element(Toast(props));
@@ -135,6 +278,8 @@ fn test_toast_custom_class() {
         r#type: ToastType::Success,
         id: None,
         class: Some("custom-class".to_string()),
+        duration_ms: None,
+        ondismiss: None,
     };
 
     let result = dioxus_ssr::render_element(Toast(props));
@@ -148,8 +293,79 @@ fn test_toast_with_id() {
         r#type: ToastType::Info,
         id: Some("test-toast".to_string()),
         class: None,
+        duration_ms: None,
+        ondismiss: None,
     };
 
     let result = dioxus_ssr::render_element(Toast(props));
     assert!(result.contains(r#"id="test-toast""#));
 }
+
+#[test]
+fn test_toast_duration_progress() {
+    let props = ToastProps {
+        children: rsx!("Saved"),
+        r#type: ToastType::Info,
+        id: None,
+        class: None,
+        duration_ms: Some(3000),
+        ondismiss: None,
+    };
+
+    let result = dioxus_ssr::render_element(Toast(props));
+    assert!(result.contains(r#"class="progress progress-info h-1 w-full""#));
+    assert!(result.contains(r#"value="3000""#));
+    assert!(result.contains(r#"max="3000""#));
+}
+
+#[test]
+fn test_toast_duration_change_restarts_countdown() {
+    use crate::test_utils::{fire, listener, rebuild, run_for, TestMouseData};
+
+    fn app() -> Element {
+        let mut duration_ms = use_signal(|| 200);
+        let mut dismissed = use_signal(|| false);
+        rsx!(
+            button { onclick: move |_| duration_ms.set(400), "Extend" }
+            Toast {
+                r#type: ToastType::Info,
+                duration_ms: duration_ms(),
+                ondismiss: move |_| dismissed.set(true),
+                "Saved"
+            }
+            "Dismissed: {dismissed}"
+        )
+    }
+
+    let (mut dom, listeners) = rebuild(app);
+    run_for(&mut dom, 120);
+    fire(&mut dom, "click", listener(&listeners, "click", 0), TestMouseData::default());
+    run_for(&mut dom, 10);
+    assert!(dioxus_ssr::render(&dom).contains(r#"max="400""#));
+
+    // Past the old duration but not the new one
+    run_for(&mut dom, 200);
+    assert!(dioxus_ssr::render(&dom).contains("Dismissed: false"));
+
+    run_for(&mut dom, 300);
+    assert!(dioxus_ssr::render(&dom).contains("Dismissed: true"));
+}
+
+#[test]
+fn test_toast_container_max_visible() {
+    let toasts = (1..=5)
+        .map(|n| rsx!(Toast { r#type: ToastType::Info, "Message {n}" }))
+        .collect();
+    let props = ToastContainerProps {
+        toasts,
+        id: None,
+        class: None,
+        max_visible: Some(3),
+    };
+
+    let result = dioxus_ssr::render_element(ToastContainer(props));
+    assert_eq!(result.matches(r#"class="alert alert-info""#).count(), 3);
+    assert!(result.contains("+2 more"));
+    assert!(!result.contains("Message 2"));
+    assert!(result.contains("Message 5"));
+}
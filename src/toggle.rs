@@ -20,6 +20,8 @@ use dioxus::prelude::*;
 
 /// Color scheme options for Toggle component
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
 pub enum ToggleColorScheme {
     /// Primary color
     Primary,
@@ -35,6 +37,8 @@ pub enum ToggleColorScheme {
     Error,
     /// Info color
     Info,
+    /// Neutral color
+    Neutral,
 }
 
 impl Display for ToggleColorScheme {
@@ -47,12 +51,15 @@ impl Display for ToggleColorScheme {
             ToggleColorScheme::Warning => write!(f, "toggle-warning"),
             ToggleColorScheme::Error => write!(f, "toggle-error"),
             ToggleColorScheme::Info => write!(f, "toggle-info"),
+            ToggleColorScheme::Neutral => write!(f, "toggle-neutral"),
         }
     }
 }
 
 /// Size options for Toggle component
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
 pub enum ToggleSize {
     /// Default size
     Default,
@@ -91,6 +98,16 @@ pub struct ToggleProps {
     disabled: Option<bool>,
     /// Optional name attribute
     name: Option<String>,
+    /// Icon shown inside the knob when the toggle is checked
+    icon_on: Option<Element>,
+    /// Icon shown inside the knob when the toggle is unchecked
+    icon_off: Option<Element>,
+    /// Called when the toggle is switched, with the new state in `event.checked()`
+    onchange: Option<EventHandler<FormEvent>>,
+    /// Called when the toggle is clicked
+    onclick: Option<EventHandler<MouseEvent>>,
+    /// Accessible name, for a toggle without a visible label
+    aria_label: Option<String>,
 }
 
 #[component]
@@ -121,14 +138,109 @@ pub fn Toggle(props: ToggleProps) -> Element {
 
     let class_string = classes.join(" ");
 
+    let mut listeners = vec![];
+    if let Some(handler) = props.onchange {
+        listeners.push(dioxus_elements::events::onchange(move |event| handler.call(event)));
+    }
+    if let Some(handler) = props.onclick {
+        listeners.push(dioxus_elements::events::onclick(move |event| handler.call(event)));
+    }
+
+    // With icons the toggle classes move to a wrapping label
+    if props.icon_on.is_some() || props.icon_off.is_some() {
+        rsx!(
+            label {
+                class: "{class_string}",
+                input {
+                    id: props.id,
+                    r#type: "checkbox",
+                    checked: checked,
+                    disabled: disabled,
+                    name: props.name,
+                    aria_label: props.aria_label,
+                    ..listeners,
+                }
+                {props.icon_on}
+                {props.icon_off}
+            }
+        )
+    } else {
+        rsx!(
+            input {
+                class: "{class_string}",
+                id: props.id,
+                r#type: "checkbox",
+                checked: checked,
+                disabled: disabled,
+                name: props.name,
+                aria_label: props.aria_label,
+                ..listeners,
+            }
+        )
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct ToggleWithLabelProps {
+    /// Text before the toggle, e.g. `Off`
+    label_left: Option<String>,
+    /// Text after the toggle, e.g. `On`
+    label_right: Option<String>,
+    /// Optional ID for the toggle input
+    id: Option<String>,
+    /// Additional CSS classes to apply to the label
+    class: Option<String>,
+    /// Color scheme of toggle
+    color_scheme: Option<ToggleColorScheme>,
+    /// Size of toggle
+    size: Option<ToggleSize>,
+    /// Whether toggle is checked
+    checked: Option<bool>,
+    /// Whether toggle is disabled
+    disabled: Option<bool>,
+    /// Optional name attribute
+    name: Option<String>,
+    /// Called when the toggle is switched
+    onchange: Option<EventHandler<FormEvent>>,
+}
+
+/// A Toggle between two texts, e.g. "Off [toggle] On". Clicking either text
+/// switches the toggle.
+///
+/// ```text
+/// ToggleWithLabel { label_left: "Monthly", label_right: "Yearly", name: "billing" }
+/// ```
+#[component]
+pub fn ToggleWithLabel(props: ToggleWithLabelProps) -> Element {
+    let class = props.class.unwrap_or_default();
+
+    // Build CSS classes
+    let mut classes = vec!["label cursor-pointer gap-2".to_string()];
+
+    if !class.is_empty() {
+        classes.push(class);
+    }
+
+    let class_string = classes.join(" ");
+
     rsx!(
-        input {
+        label {
             class: "{class_string}",
-            id: props.id,
-            r#type: "checkbox",
-            checked: checked,
-            disabled: disabled,
-            name: props.name,
+            if let Some(label) = props.label_left {
+                span { class: "label-text", "{label}" }
+            }
+            Toggle {
+                id: props.id,
+                color_scheme: props.color_scheme,
+                size: props.size,
+                checked: props.checked,
+                disabled: props.disabled,
+                name: props.name,
+                onchange: props.onchange,
+            }
+            if let Some(label) = props.label_right {
+                span { class: "label-text", "{label}" }
+            }
         }
     )
 }
@@ -143,6 +255,11 @@ fn test_toggle_basic() {
         checked: None,
         disabled: None,
         name: None,
+        icon_on: None,
+        icon_off: None,
+        onchange: None,
+        onclick: None,
+        aria_label: None,
     };
 
     let result = dioxus_ssr::render_element(Toggle(props));
@@ -159,6 +276,11 @@ fn test_toggle_checked() {
         checked: Some(true),
         disabled: None,
         name: None,
+        icon_on: None,
+        icon_off: None,
+        onchange: None,
+        onclick: None,
+        aria_label: None,
     };
 
     let result = dioxus_ssr::render_element(Toggle(props));
@@ -175,6 +297,11 @@ fn test_toggle_disabled() {
         checked: None,
         disabled: Some(true),
         name: None,
+        icon_on: None,
+        icon_off: None,
+        onchange: None,
+        onclick: None,
+        aria_label: None,
     };
 
     let result = dioxus_ssr::render_element(Toggle(props));
@@ -191,6 +318,7 @@ fn test_toggle_color_schemes() {
         ToggleColorScheme::Warning,
         ToggleColorScheme::Error,
         ToggleColorScheme::Info,
+        ToggleColorScheme::Neutral,
     ];
 
     for color in color_schemes {
@@ -202,6 +330,11 @@ fn test_toggle_color_schemes() {
             checked: None,
             disabled: None,
             name: None,
+            icon_on: None,
+            icon_off: None,
+            onchange: None,
+            onclick: None,
+            aria_label: None,
         };
 
         let result = dioxus_ssr::render_element(Toggle(props));
@@ -227,6 +360,11 @@ fn test_toggle_sizes() {
             checked: None,
             disabled: None,
             name: None,
+            icon_on: None,
+            icon_off: None,
+            onchange: None,
+            onclick: None,
+            aria_label: None,
         };
 
         let result = dioxus_ssr::render_element(Toggle(props));
@@ -249,6 +387,11 @@ fn test_toggle_custom_class() {
         checked: None,
         disabled: None,
         name: None,
+        icon_on: None,
+        icon_off: None,
+        onchange: None,
+        onclick: None,
+        aria_label: None,
     };
 
     let result = dioxus_ssr::render_element(Toggle(props));
@@ -265,6 +408,11 @@ fn test_toggle_with_id() {
         checked: None,
         disabled: None,
         name: None,
+        icon_on: None,
+        icon_off: None,
+        onchange: None,
+        onclick: None,
+        aria_label: None,
     };
 
     let result = dioxus_ssr::render_element(Toggle(props));
@@ -281,8 +429,86 @@ fn test_toggle_with_name() {
         checked: None,
         disabled: None,
         name: Some("toggle-name".to_string()),
+        icon_on: None,
+        icon_off: None,
+        onchange: None,
+        onclick: None,
+        aria_label: None,
     };
 
     let result = dioxus_ssr::render_element(Toggle(props));
     assert!(result.contains(r#"name="toggle-name""#));
 }
+
+#[test]
+fn test_toggle_with_icons() {
+    let props = ToggleProps {
+        id: None,
+        class: None,
+        color_scheme: None,
+        size: None,
+        checked: None,
+        disabled: None,
+        name: None,
+        icon_on: Some(rsx!(svg { "aria-label": "enabled" })),
+        icon_off: Some(rsx!(svg { "aria-label": "disabled" })),
+        onchange: None,
+        onclick: None,
+        aria_label: None,
+    };
+
+    let result = dioxus_ssr::render_element(Toggle(props));
+    assert!(result.starts_with(r#"<label class="toggle"><input type="checkbox"/>"#));
+    assert!(result.contains(r#"<svg aria-label="enabled"></svg><svg aria-label="disabled"></svg></label>"#));
+}
+
+#[test]
+fn test_toggle_aria_label() {
+    let result = dioxus_ssr::render_element(rsx!(
+        Toggle { aria_label: "Enable notifications" }
+    ));
+    assert!(result.contains(r#"aria-label="Enable notifications""#));
+}
+
+#[test]
+fn test_toggle_onchange() {
+    use crate::test_utils::{fire, listener, rebuild, TestFormData, TestMouseData};
+
+    fn app() -> Element {
+        let mut changes = use_signal(|| 0);
+        let mut clicks = use_signal(|| 0);
+        rsx!(
+            Toggle {
+                aria_label: "Dark mode",
+                onchange: move |_| changes += 1,
+                onclick: move |_| clicks += 1,
+            }
+            "Changes {changes} clicks {clicks}"
+        )
+    }
+
+    let (mut dom, listeners) = rebuild(app);
+    assert!(dioxus_ssr::render(&dom).contains(r#"aria-label="Dark mode""#));
+
+    fire(&mut dom, "click", listener(&listeners, "click", 0), TestMouseData::default());
+    fire(&mut dom, "change", listener(&listeners, "change", 0), TestFormData::default());
+    assert!(dioxus_ssr::render(&dom).contains("Changes 1 clicks 1"));
+}
+
+#[test]
+fn test_toggle_with_label() {
+    let result = dioxus_ssr::render_element(rsx!(
+        ToggleWithLabel {
+            label_left: "Off",
+            label_right: "On",
+            color_scheme: ToggleColorScheme::Success,
+            size: ToggleSize::Small,
+            checked: true,
+            name: "notifications",
+        }
+    ));
+    assert_eq!(
+        result,
+        r#"<label class="label cursor-pointer gap-2"><span class="label-text">Off</span><input class="toggle toggle-success toggle-sm" type="checkbox" checked=true name="notifications"/><span class="label-text">On</span></label>"#
+    );
+}